@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Context, Result};
+use clap::Args;
+
+use crate::config::CliConfig;
+use crate::print_json;
+
+#[derive(Debug, Args)]
+pub struct ApiArgs {
+    /// Endpoint path, for example `/v1/blocks/latest`
+    path: String,
+
+    /// Query parameter, repeatable
+    #[arg(long = "query", value_name = "KEY=VALUE", value_parser = parse_query)]
+    query: Vec<(String, String)>,
+
+    /// JSON request body, or `@FILE` to read it from a file. Sends a POST instead of a GET
+    #[arg(long, value_name = "JSON|@FILE", value_parser = parse_body)]
+    body: Option<serde_json::Value>,
+}
+
+pub async fn run(config: &CliConfig, args: ApiArgs) -> Result<()> {
+    let api = config.api();
+    let response = match &args.body {
+        Some(body) => api.post_json(&args.path, &args.query, body).await?,
+        None => api.get_json(&args.path, &args.query).await?,
+    };
+    print_json(&response)
+}
+
+fn parse_query(value: &str) -> Result<(String, String)> {
+    let (key, value) = value
+        .split_once('=')
+        .ok_or_else(|| anyhow!("expected KEY=VALUE, got `{value}`"))?;
+    Ok((key.to_string(), value.to_string()))
+}
+
+fn parse_body(value: &str) -> Result<serde_json::Value> {
+    match value.strip_prefix('@') {
+        Some(path) => {
+            let json = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read body {path}"))?;
+            serde_json::from_str(&json).with_context(|| format!("invalid JSON body in {path}"))
+        }
+        None => serde_json::from_str(value).context("invalid JSON body"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_inline_and_file_bodies() {
+        let body = parse_body(r#"{"block_number":1}"#).unwrap();
+        assert_eq!(body, serde_json::json!({ "block_number": 1 }));
+
+        let path =
+            std::env::temp_dir().join(format!("bankai-api-body-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"block_number":2}"#).unwrap();
+        let body = parse_body(&format!("@{}", path.display())).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(body, serde_json::json!({ "block_number": 2 }));
+
+        assert!(parse_body("{").is_err());
+        assert!(parse_body("@/nonexistent/body.json").is_err());
+    }
+}
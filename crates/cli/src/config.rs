@@ -0,0 +1,162 @@
+//! RPC and trust configuration, read from a JSON file and the environment.
+//!
+//! Environment variables override the file:
+//!
+//! | variable                 | field                                   |
+//! |--------------------------|-----------------------------------------|
+//! | `BANKAI_NETWORK`         | `network`                               |
+//! | `BANKAI_API_URL`         | `api_url`                               |
+//! | `BANKAI_EXECUTION_RPC`   | `execution_rpc`                         |
+//! | `BANKAI_BEACON_RPC`      | `beacon_rpc`                            |
+//! | `BANKAI_OP_RPC_<CHAIN>`  | `op_stack_rpcs.<chain>`, name lowercased |
+//! | `BANKAI_PROGRAM_HASHES`  | `program_hashes`, comma separated       |
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use alloy_primitives::FixedBytes;
+use anyhow::{Context, Result};
+use bankai_sdk::{ApiClient, Bankai, Network};
+use serde::Deserialize;
+
+const OP_RPC_PREFIX: &str = "BANKAI_OP_RPC_";
+
+/// Resolved CLI configuration.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CliConfig {
+    pub network: Option<Network>,
+    pub api_url: Option<String>,
+    pub execution_rpc: Option<String>,
+    pub beacon_rpc: Option<String>,
+    pub op_stack_rpcs: BTreeMap<String, String>,
+    pub program_hashes: Vec<FixedBytes<32>>,
+}
+
+impl CliConfig {
+    /// Reads `path` if given, then applies the process environment.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let mut config = match path {
+            Some(path) => {
+                let json = std::fs::read_to_string(path)
+                    .with_context(|| format!("failed to read config {}", path.display()))?;
+                serde_json::from_str(&json)
+                    .with_context(|| format!("invalid config {}", path.display()))?
+            }
+            None => Self::default(),
+        };
+        config.apply_env(std::env::vars())?;
+        Ok(config)
+    }
+
+    /// Overrides fields with the `BANKAI_*` variables in `vars`.
+    fn apply_env(&mut self, vars: impl IntoIterator<Item = (String, String)>) -> Result<()> {
+        for (key, value) in vars {
+            match key.as_str() {
+                "BANKAI_NETWORK" => self.network = Some(parse_value(&value)?),
+                "BANKAI_API_URL" => self.api_url = Some(value),
+                "BANKAI_EXECUTION_RPC" => self.execution_rpc = Some(value),
+                "BANKAI_BEACON_RPC" => self.beacon_rpc = Some(value),
+                "BANKAI_PROGRAM_HASHES" => {
+                    self.program_hashes = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|hash| !hash.is_empty())
+                        .map(|hash| hash.parse().context("invalid BANKAI_PROGRAM_HASHES"))
+                        .collect::<Result<_>>()?;
+                }
+                _ => {
+                    if let Some(chain) = key.strip_prefix(OP_RPC_PREFIX) {
+                        self.op_stack_rpcs.insert(chain.to_lowercase(), value);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the configured network, defaulting to Sepolia.
+    pub fn network(&self) -> Network {
+        self.network.unwrap_or(Network::Sepolia)
+    }
+
+    /// Returns the configured API base URL, defaulting to the network's.
+    pub fn api_url(&self) -> String {
+        self.api_url
+            .clone()
+            .unwrap_or_else(|| self.network().api_url().to_string())
+    }
+
+    /// Builds an API client for [`CliConfig::api_url`].
+    pub fn api(&self) -> ApiClient {
+        ApiClient::new_with_base_url(self.api_url())
+    }
+
+    /// Builds an SDK instance with every configured RPC.
+    pub fn bankai(&self) -> Bankai {
+        let op_rpcs = (!self.op_stack_rpcs.is_empty()).then(|| self.op_stack_rpcs.clone());
+        Bankai::new_with_base_url(
+            self.network(),
+            self.api_url(),
+            self.execution_rpc.clone(),
+            self.beacon_rpc.clone(),
+            op_rpcs,
+        )
+    }
+}
+
+/// Parses a unit enum from its serde name, for example `sepolia` or `keccak`.
+pub fn parse_value<T: serde::de::DeserializeOwned>(value: &str) -> Result<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .with_context(|| format!("unknown value `{value}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn environment_overrides_file() {
+        let mut config: CliConfig = serde_json::from_str(
+            r#"{"network":"local","execution_rpc":"http://file","op_stack_rpcs":{"base":"http://base"}}"#,
+        )
+        .unwrap();
+        config
+            .apply_env(vars(&[
+                ("BANKAI_NETWORK", "sepolia"),
+                ("BANKAI_EXECUTION_RPC", "http://env"),
+                ("BANKAI_OP_RPC_OPTIMISM", "http://optimism"),
+                ("PATH", "/usr/bin"),
+            ]))
+            .unwrap();
+
+        assert_eq!(config.network(), Network::Sepolia);
+        assert_eq!(config.execution_rpc.as_deref(), Some("http://env"));
+        assert_eq!(config.op_stack_rpcs.len(), 2);
+        assert_eq!(config.op_stack_rpcs["optimism"], "http://optimism");
+    }
+
+    #[test]
+    fn parses_program_hash_list() {
+        let mut config = CliConfig::default();
+        let hash = format!("0x{}", "11".repeat(32));
+        config
+            .apply_env(vars(&[(
+                "BANKAI_PROGRAM_HASHES",
+                &format!("{hash}, {hash},"),
+            )]))
+            .unwrap();
+
+        assert_eq!(config.program_hashes, vec![FixedBytes::from([0x11; 32]); 2]);
+        assert!(config
+            .apply_env(vars(&[("BANKAI_NETWORK", "mainnet")]))
+            .is_err());
+    }
+}
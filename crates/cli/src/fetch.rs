@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+
+use alloy_primitives::FixedBytes;
+use anyhow::{bail, Context, Result};
+use bankai_sdk::{Finality, HashingFunction};
+use bankai_types::inputs::file::BundleCompression;
+use clap::Args;
+
+use crate::config::{parse_value, CliConfig};
+use crate::print_json;
+use crate::request::{
+    AccountRequest, BatchRequest, HeaderRange, OnChain, StorageRequest, ValidatorRequest,
+};
+
+#[derive(Debug, Args)]
+pub struct FetchArgs {
+    /// Bundle file to write
+    #[arg(long, short)]
+    out: PathBuf,
+
+    /// JSON request file, merged with the request flags
+    #[arg(long)]
+    request: Option<PathBuf>,
+
+    /// Bankai block to anchor to, defaults to the latest completed block
+    #[arg(long)]
+    bankai_block: Option<u64>,
+
+    /// Hashing function of the MMR proofs, `keccak` or `poseidon`
+    #[arg(long, value_parser = parse_value::<HashingFunction>)]
+    hashing: Option<HashingFunction>,
+
+    /// Minimum finality of requested headers, `finalized`, `justified` or `latest`
+    #[arg(long, value_parser = parse_value::<Finality>)]
+    finality: Option<Finality>,
+
+    /// Store MPT nodes shared by state proofs once
+    #[arg(long)]
+    pool_nodes: bool,
+
+    /// Prove the headers of each chain with one multi-leaf MMR proof
+    #[arg(long)]
+    batch_header_proofs: bool,
+
+    /// Concurrent calls per RPC endpoint while fetching
+    #[arg(long)]
+    rpc_concurrency: Option<usize>,
+
+    /// Gzip the bundle payload
+    #[arg(long)]
+    gzip: bool,
+
+    #[command(flatten)]
+    items: ItemArgs,
+}
+
+/// Request flags. Every flag is repeatable.
+#[derive(Debug, Args)]
+struct ItemArgs {
+    #[arg(long, value_name = "BLOCK")]
+    execution_header: Vec<u64>,
+    #[arg(long, value_name = "SLOT")]
+    beacon_header: Vec<u64>,
+    #[arg(long, value_name = "START:END")]
+    execution_header_range: Vec<HeaderRange>,
+    #[arg(long, value_name = "START:END")]
+    beacon_header_range: Vec<HeaderRange>,
+    #[arg(long, value_name = "SLOT")]
+    execution_payload: Vec<u64>,
+    #[arg(long, value_name = "SLOT:INDEX")]
+    validator: Vec<ValidatorRequest>,
+    #[arg(long, value_name = "BLOCK:ADDRESS")]
+    account: Vec<AccountRequest>,
+    #[arg(long, value_name = "BLOCK:ADDRESS:SLOT[,SLOT...]")]
+    storage: Vec<StorageRequest>,
+    #[arg(long, value_name = "BLOCK:ADDRESS")]
+    code: Vec<AccountRequest>,
+    #[arg(long, value_name = "HASH")]
+    tx: Vec<FixedBytes<32>>,
+    #[arg(long, value_name = "HASH")]
+    receipt: Vec<FixedBytes<32>>,
+    #[arg(long, value_name = "CHAIN:BLOCK")]
+    op_header: Vec<OnChain<u64>>,
+    #[arg(long, value_name = "CHAIN:START:END")]
+    op_header_range: Vec<OnChain<HeaderRange>>,
+    #[arg(long, value_name = "CHAIN:BLOCK:ADDRESS")]
+    op_account: Vec<OnChain<AccountRequest>>,
+    #[arg(long, value_name = "CHAIN:BLOCK:ADDRESS:SLOT[,SLOT...]")]
+    op_storage: Vec<OnChain<StorageRequest>>,
+    #[arg(long, value_name = "CHAIN:BLOCK:ADDRESS")]
+    op_code: Vec<OnChain<AccountRequest>>,
+    #[arg(long, value_name = "CHAIN:HASH")]
+    op_tx: Vec<OnChain<FixedBytes<32>>>,
+    #[arg(long, value_name = "CHAIN:HASH")]
+    op_receipt: Vec<OnChain<FixedBytes<32>>>,
+}
+
+impl ItemArgs {
+    fn merge_into(self, request: &mut BatchRequest) {
+        let eth = &mut request.ethereum;
+        eth.execution_headers.extend(self.execution_header);
+        eth.beacon_headers.extend(self.beacon_header);
+        eth.execution_header_ranges
+            .extend(self.execution_header_range);
+        eth.beacon_header_ranges.extend(self.beacon_header_range);
+        eth.execution_payloads.extend(self.execution_payload);
+        eth.validators.extend(self.validator);
+        eth.accounts.extend(self.account);
+        eth.storage_slots.extend(self.storage);
+        eth.code.extend(self.code);
+        eth.txs.extend(self.tx);
+        eth.receipts.extend(self.receipt);
+
+        for item in self.op_header {
+            request.op_chain(&item.chain).headers.push(item.value);
+        }
+        for item in self.op_header_range {
+            request.op_chain(&item.chain).header_ranges.push(item.value);
+        }
+        for item in self.op_account {
+            request.op_chain(&item.chain).accounts.push(item.value);
+        }
+        for item in self.op_storage {
+            request.op_chain(&item.chain).storage_slots.push(item.value);
+        }
+        for item in self.op_code {
+            request.op_chain(&item.chain).code.push(item.value);
+        }
+        for item in self.op_tx {
+            request.op_chain(&item.chain).txs.push(item.value);
+        }
+        for item in self.op_receipt {
+            request.op_chain(&item.chain).receipts.push(item.value);
+        }
+    }
+}
+
+pub async fn run(config: &CliConfig, args: FetchArgs) -> Result<()> {
+    let mut request = match &args.request {
+        Some(path) => BatchRequest::from_file(path)?,
+        None => BatchRequest::default(),
+    };
+    args.items.merge_into(&mut request);
+    request.bankai_block = args.bankai_block.or(request.bankai_block);
+    request.hashing = args.hashing.or(request.hashing);
+    request.finality = args.finality.or(request.finality);
+    request.pool_nodes |= args.pool_nodes;
+    request.batch_header_proofs |= args.batch_header_proofs;
+    request.rpc_concurrency = args.rpc_concurrency.or(request.rpc_concurrency);
+    if request.is_empty() {
+        bail!("no proofs requested, pass request flags or --request");
+    }
+
+    let bankai = config.bankai();
+    let batch = bankai
+        .init_batch(
+            request.bankai_block,
+            request.hashing.unwrap_or(HashingFunction::Keccak),
+        )
+        .await?;
+    let bundle = request.apply(batch).execute().await?;
+
+    let compression = if args.gzip {
+        BundleCompression::Gzip
+    } else {
+        BundleCompression::None
+    };
+    let file = File::create(&args.out)
+        .with_context(|| format!("failed to create {}", args.out.display()))?;
+    let mut writer = BufWriter::new(file);
+    let metadata = bundle.write_to(&mut writer, config.network(), compression)?;
+    writer.flush()?;
+
+    print_json(&metadata)
+}
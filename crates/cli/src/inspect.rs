@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Args;
+use serde_json::{json, Map, Value};
+
+use crate::print_json;
+use crate::verify::read_bundle;
+
+#[derive(Debug, Args)]
+pub struct InspectArgs {
+    /// Bundle file to inspect
+    bundle: PathBuf,
+}
+
+/// Prints the bundle metadata and the count and size of every proof list.
+///
+/// Sizes are uncompressed JSON bytes, as stored in the bundle payload.
+pub fn run(args: InspectArgs) -> Result<()> {
+    let file_bytes = std::fs::metadata(&args.bundle)?.len();
+    let (metadata, bundle) = read_bundle(&args.bundle)?;
+    let payload = serde_json::to_value(&bundle)?;
+
+    print_json(&json!({
+        "file_bytes": file_bytes,
+        "schema_version": metadata.schema_version,
+        "metadata": metadata,
+        "bankai_block_version": bundle.block.version,
+        "payload_bytes": json_len(&payload),
+        "block_proof_bytes": json_len(&payload["block_proof"]),
+        "evm_proofs": proof_lists(&payload["evm_proofs"]),
+        "op_stack_proofs": proof_lists(&payload["op_stack_proofs"]),
+    }))
+}
+
+/// Summarizes the non-empty lists of a serialized `EvmProofs` or `OpStackProofs`.
+fn proof_lists(proofs: &Value) -> Value {
+    let Some(lists) = proofs.as_object() else {
+        return Value::Null;
+    };
+    let summary: Map<String, Value> = lists
+        .iter()
+        .filter_map(|(name, list)| {
+            let count = list.as_array()?.len();
+            (count > 0).then(|| {
+                (
+                    name.clone(),
+                    json!({ "count": count, "bytes": json_len(list) }),
+                )
+            })
+        })
+        .collect();
+    Value::Object(summary)
+}
+
+fn json_len(value: &Value) -> usize {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarizes_non_empty_lists() {
+        let proofs = json!({
+            "account_proof": [{"address": "0x11"}, {"address": "0x22"}],
+            "tx_proof": [],
+            "node_pool": ["0xaa"],
+        });
+
+        assert_eq!(
+            proof_lists(&proofs),
+            json!({
+                "account_proof": {"count": 2, "bytes": 39},
+                "node_pool": {"count": 1, "bytes": 8},
+            })
+        );
+        assert_eq!(proof_lists(&Value::Null), Value::Null);
+    }
+}
@@ -0,0 +1,82 @@
+//! `bankai` command-line tool.
+//!
+//! Fetches proof bundles into files, verifies and inspects them, and calls the Bankai API.
+//! RPC endpoints and trusted program hashes are read from `--config` and the environment,
+//! see [`config`].
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use bankai_sdk::Network;
+use clap::{Parser, Subcommand};
+
+mod api;
+mod config;
+mod fetch;
+mod inspect;
+mod request;
+mod verify;
+
+use crate::config::{parse_value, CliConfig};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "bankai",
+    version,
+    about = "Fetch, verify and inspect Bankai proof bundles"
+)]
+struct Cli {
+    /// JSON config file with RPC endpoints and trusted program hashes
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Bankai network, overrides the config
+    #[arg(long, global = true, value_parser = parse_value::<Network>)]
+    network: Option<Network>,
+
+    /// Bankai API base URL, overrides the config
+    #[arg(long, global = true)]
+    api_url: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Fetch a proof bundle and write it to a file
+    Fetch(fetch::FetchArgs),
+    /// Verify a bundle file and print the verified results as JSON
+    Verify(verify::VerifyArgs),
+    /// Summarize the contents and sizes of a bundle file
+    Inspect(inspect::InspectArgs),
+    /// Call a Bankai API endpoint and print the JSON response
+    Api(api::ApiArgs),
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    let cli = Cli::parse();
+
+    let mut config = CliConfig::load(cli.config.as_deref())?;
+    if cli.network.is_some() {
+        config.network = cli.network;
+    }
+    if cli.api_url.is_some() {
+        config.api_url = cli.api_url;
+    }
+
+    match cli.command {
+        Command::Fetch(args) => fetch::run(&config, args).await,
+        Command::Verify(args) => verify::run(&config, args),
+        Command::Inspect(args) => inspect::run(args),
+        Command::Api(args) => api::run(&config, args).await,
+    }
+}
+
+/// Prints `value` to stdout as pretty JSON.
+fn print_json<T: serde::Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
@@ -0,0 +1,387 @@
+//! Batch requests read from a JSON file or assembled from `fetch` flags.
+//!
+//! ```json
+//! {
+//!   "bankai_block": 1200,
+//!   "hashing": "keccak",
+//!   "finality": "finalized",
+//!   "ethereum": {
+//!     "execution_headers": [9231247],
+//!     "accounts": [{ "block_number": 9231247, "address": "0x..." }]
+//!   },
+//!   "op_stack": {
+//!     "base": { "headers": [31000000], "txs": ["0x..."] }
+//!   }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use alloy_primitives::{Address, FixedBytes, U256};
+use anyhow::{anyhow, Context, Error, Result};
+use bankai_sdk::batch::{LogFilter, ProofBatchBuilder};
+use bankai_sdk::{Finality, HashingFunction};
+use serde::Deserialize;
+
+/// Every proof requested from one Bankai block.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BatchRequest {
+    /// Bankai block to anchor to, or the latest completed block.
+    pub bankai_block: Option<u64>,
+    pub hashing: Option<HashingFunction>,
+    pub finality: Option<Finality>,
+    pub pool_nodes: bool,
+    pub batch_header_proofs: bool,
+    /// Concurrent calls per RPC endpoint, or the SDK default.
+    pub rpc_concurrency: Option<usize>,
+    pub ethereum: EthereumRequest,
+    /// OP Stack requests keyed by chain name.
+    pub op_stack: BTreeMap<String, OpStackRequest>,
+}
+
+/// Ethereum proofs of a [`BatchRequest`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EthereumRequest {
+    pub execution_headers: Vec<u64>,
+    pub beacon_headers: Vec<u64>,
+    pub execution_header_ranges: Vec<HeaderRange>,
+    pub beacon_header_ranges: Vec<HeaderRange>,
+    pub execution_payloads: Vec<u64>,
+    pub validators: Vec<ValidatorRequest>,
+    pub accounts: Vec<AccountRequest>,
+    pub storage_slots: Vec<StorageRequest>,
+    pub code: Vec<AccountRequest>,
+    pub txs: Vec<FixedBytes<32>>,
+    pub receipts: Vec<FixedBytes<32>>,
+    pub logs: Vec<LogsRequest>,
+}
+
+/// OP Stack proofs of one chain of a [`BatchRequest`].
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OpStackRequest {
+    pub headers: Vec<u64>,
+    pub header_ranges: Vec<HeaderRange>,
+    pub accounts: Vec<AccountRequest>,
+    pub storage_slots: Vec<StorageRequest>,
+    pub code: Vec<AccountRequest>,
+    pub txs: Vec<FixedBytes<32>>,
+    pub receipts: Vec<FixedBytes<32>>,
+    pub logs: Vec<LogsRequest>,
+}
+
+/// Inclusive range of blocks or slots, written `START:END` on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HeaderRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// A validator at a beacon slot, written `SLOT:INDEX` on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorRequest {
+    pub slot: u64,
+    pub validator_index: u64,
+}
+
+/// An account at a block, written `BLOCK:ADDRESS` on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AccountRequest {
+    pub block_number: u64,
+    pub address: Address,
+}
+
+/// Storage slots of an account, written `BLOCK:ADDRESS:SLOT[,SLOT...]` on the command line.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StorageRequest {
+    pub block_number: u64,
+    pub address: Address,
+    pub slots: Vec<U256>,
+}
+
+/// Logs of a transaction, selected by `filter`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogsRequest {
+    pub tx_hash: FixedBytes<32>,
+    #[serde(default)]
+    pub filter: LogFilter,
+}
+
+/// An OP Stack request written `CHAIN:VALUE` on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OnChain<T> {
+    pub chain: String,
+    pub value: T,
+}
+
+impl BatchRequest {
+    /// Reads a request file.
+    pub fn from_file(path: &std::path::Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read request {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("invalid request {}", path.display()))
+    }
+
+    /// Returns the request for `chain`, adding it if missing.
+    pub fn op_chain(&mut self, chain: &str) -> &mut OpStackRequest {
+        self.op_stack.entry(chain.to_string()).or_default()
+    }
+
+    /// Returns `true` if no proof is requested.
+    pub fn is_empty(&self) -> bool {
+        let eth = &self.ethereum;
+        eth.execution_headers.is_empty()
+            && eth.beacon_headers.is_empty()
+            && eth.execution_header_ranges.is_empty()
+            && eth.beacon_header_ranges.is_empty()
+            && eth.execution_payloads.is_empty()
+            && eth.validators.is_empty()
+            && eth.accounts.is_empty()
+            && eth.storage_slots.is_empty()
+            && eth.code.is_empty()
+            && eth.txs.is_empty()
+            && eth.receipts.is_empty()
+            && eth.logs.is_empty()
+            && self.op_stack.values().all(OpStackRequest::is_empty)
+    }
+
+    /// Adds every request to `batch`.
+    pub fn apply<'a>(&self, mut batch: ProofBatchBuilder<'a>) -> ProofBatchBuilder<'a> {
+        if let Some(finality) = self.finality {
+            batch = batch.finality(finality);
+        }
+        if self.batch_header_proofs {
+            batch = batch.batch_header_proofs();
+        }
+        if self.pool_nodes {
+            batch = batch.pool_nodes();
+        }
+        if let Some(limit) = self.rpc_concurrency {
+            batch = batch.rpc_concurrency(limit);
+        }
+
+        let eth = &self.ethereum;
+        for &block_number in &eth.execution_headers {
+            batch = batch.ethereum_execution_header(block_number);
+        }
+        for &slot in &eth.beacon_headers {
+            batch = batch.ethereum_beacon_header(slot);
+        }
+        for range in &eth.execution_header_ranges {
+            batch = batch.ethereum_execution_header_range(range.start..=range.end);
+        }
+        for range in &eth.beacon_header_ranges {
+            batch = batch.ethereum_beacon_header_range(range.start..=range.end);
+        }
+        for &slot in &eth.execution_payloads {
+            batch = batch.ethereum_execution_payload(slot);
+        }
+        for validator in &eth.validators {
+            batch = batch.ethereum_validator(validator.slot, validator.validator_index);
+        }
+        for account in &eth.accounts {
+            batch = batch.ethereum_account(account.block_number, account.address);
+        }
+        for storage in &eth.storage_slots {
+            batch = batch.ethereum_storage_slot(
+                storage.block_number,
+                storage.address,
+                storage.slots.clone(),
+            );
+        }
+        for code in &eth.code {
+            batch = batch.ethereum_code(code.block_number, code.address);
+        }
+        for &tx_hash in &eth.txs {
+            batch = batch.ethereum_tx(tx_hash);
+        }
+        for &tx_hash in &eth.receipts {
+            batch = batch.ethereum_receipt(tx_hash);
+        }
+        for logs in &eth.logs {
+            batch = batch.ethereum_logs(logs.tx_hash, logs.filter.clone());
+        }
+
+        for (chain, op) in &self.op_stack {
+            for &block_number in &op.headers {
+                batch = batch.op_stack_header(chain, block_number);
+            }
+            for range in &op.header_ranges {
+                batch = batch.op_stack_header_range(chain, range.start..=range.end);
+            }
+            for account in &op.accounts {
+                batch = batch.op_stack_account(chain, account.block_number, account.address);
+            }
+            for storage in &op.storage_slots {
+                batch = batch.op_stack_storage_slot(
+                    chain,
+                    storage.block_number,
+                    storage.address,
+                    storage.slots.clone(),
+                );
+            }
+            for code in &op.code {
+                batch = batch.op_stack_code(chain, code.block_number, code.address);
+            }
+            for &tx_hash in &op.txs {
+                batch = batch.op_stack_tx(chain, tx_hash);
+            }
+            for &tx_hash in &op.receipts {
+                batch = batch.op_stack_receipt(chain, tx_hash);
+            }
+            for logs in &op.logs {
+                batch = batch.op_stack_logs(chain, logs.tx_hash, logs.filter.clone());
+            }
+        }
+        batch
+    }
+}
+
+impl OpStackRequest {
+    fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+            && self.header_ranges.is_empty()
+            && self.accounts.is_empty()
+            && self.storage_slots.is_empty()
+            && self.code.is_empty()
+            && self.txs.is_empty()
+            && self.receipts.is_empty()
+            && self.logs.is_empty()
+    }
+}
+
+impl FromStr for HeaderRange {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (start, end) = split_pair(value, "START:END")?;
+        Ok(Self {
+            start: start.parse()?,
+            end: end.parse()?,
+        })
+    }
+}
+
+impl FromStr for ValidatorRequest {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (slot, validator_index) = split_pair(value, "SLOT:INDEX")?;
+        Ok(Self {
+            slot: slot.parse()?,
+            validator_index: validator_index.parse()?,
+        })
+    }
+}
+
+impl FromStr for AccountRequest {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (block_number, address) = split_pair(value, "BLOCK:ADDRESS")?;
+        Ok(Self {
+            block_number: block_number.parse()?,
+            address: address.parse()?,
+        })
+    }
+}
+
+impl FromStr for StorageRequest {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (account, slots) = value
+            .rsplit_once(':')
+            .ok_or_else(|| anyhow!("expected BLOCK:ADDRESS:SLOT[,SLOT...], got `{value}`"))?;
+        let account: AccountRequest = account.parse()?;
+        Ok(Self {
+            block_number: account.block_number,
+            address: account.address,
+            slots: slots
+                .split(',')
+                .map(|slot| slot.parse().map_err(Error::from))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl<T: FromStr<Err: Into<Error>>> FromStr for OnChain<T> {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let (chain, value) = split_pair(value, "CHAIN:VALUE")?;
+        Ok(Self {
+            chain: chain.to_string(),
+            value: value.parse().map_err(Into::into)?,
+        })
+    }
+}
+
+fn split_pair<'a>(value: &'a str, format: &str) -> Result<(&'a str, &'a str)> {
+    value
+        .split_once(':')
+        .ok_or_else(|| anyhow!("expected {format}, got `{value}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_flag_values() {
+        let address = "0x1111111111111111111111111111111111111111";
+
+        let storage: StorageRequest = format!("42:{address}:0x1,2").parse().unwrap();
+        assert_eq!(storage.block_number, 42);
+        assert_eq!(storage.address, Address::repeat_byte(0x11));
+        assert_eq!(storage.slots, vec![U256::from(1), U256::from(2)]);
+
+        let account: OnChain<AccountRequest> = format!("base:7:{address}").parse().unwrap();
+        assert_eq!(account.chain, "base");
+        assert_eq!(account.value.block_number, 7);
+
+        let header: OnChain<u64> = "base:31000000".parse().unwrap();
+        assert_eq!(header.value, 31_000_000);
+
+        let range: OnChain<HeaderRange> = "base:10:20".parse().unwrap();
+        assert_eq!(range.value, HeaderRange { start: 10, end: 20 });
+        assert!("10".parse::<HeaderRange>().is_err());
+
+        assert!("42".parse::<AccountRequest>().is_err());
+        assert!("base:latest".parse::<OnChain<u64>>().is_err());
+    }
+
+    #[test]
+    fn reads_request_file() {
+        let request: BatchRequest = serde_json::from_str(
+            r#"{
+                "bankai_block": 1200,
+                "hashing": "keccak",
+                "finality": "finalized",
+                "ethereum": {
+                    "execution_headers": [9231247],
+                    "logs": [{"tx_hash": "0x4242424242424242424242424242424242424242424242424242424242424242"}]
+                },
+                "op_stack": {"base": {"headers": [31000000]}}
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(request.bankai_block, Some(1200));
+        assert_eq!(request.hashing, Some(HashingFunction::Keccak));
+        assert_eq!(request.finality, Some(Finality::Finalized));
+        assert_eq!(request.ethereum.logs[0].filter, LogFilter::new());
+        assert_eq!(request.op_stack["base"].headers, vec![31_000_000]);
+        assert!(!request.is_empty());
+        assert!(BatchRequest::default().is_empty());
+        assert!(serde_json::from_str::<BatchRequest>(r#"{"etherum": {}}"#).is_err());
+    }
+}
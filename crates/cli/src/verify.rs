@@ -0,0 +1,100 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use alloy_primitives::FixedBytes;
+use anyhow::{bail, Context, Result};
+use bankai_sdk::{Finality, ProofBundle};
+use bankai_types::inputs::file::BundleMetadata;
+use bankai_verify::{verify_batch_proof, verify_batch_report, VerifierConfig};
+use clap::Args;
+use serde_json::json;
+
+use crate::config::{parse_value, CliConfig};
+use crate::print_json;
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    /// Bundle file to verify
+    bundle: PathBuf,
+
+    /// Trusted Bankai program hash, repeatable. Replaces the configured hashes
+    #[arg(long = "program-hash", value_name = "HASH")]
+    program_hashes: Vec<FixedBytes<32>>,
+
+    /// Lowest accepted Bankai block version
+    #[arg(long)]
+    min_version: Option<u64>,
+
+    /// Highest accepted Bankai block version
+    #[arg(long)]
+    max_version: Option<u64>,
+
+    /// Minimum finality of verified headers, `finalized`, `justified` or `latest`
+    #[arg(long, value_parser = parse_value::<Finality>)]
+    finality: Option<Finality>,
+
+    /// Verify every proof and list failures instead of stopping at the first one
+    #[arg(long)]
+    report: bool,
+}
+
+pub fn run(config: &CliConfig, args: VerifyArgs) -> Result<()> {
+    let (metadata, bundle) = read_bundle(&args.bundle)?;
+
+    let program_hashes = if args.program_hashes.is_empty() {
+        config.program_hashes.clone()
+    } else {
+        args.program_hashes
+    };
+    if program_hashes.is_empty() {
+        bail!("no trusted program hash, pass --program-hash or set BANKAI_PROGRAM_HASHES");
+    }
+
+    let network = config.network.unwrap_or(metadata.network);
+    if network != metadata.network {
+        bail!(
+            "bundle was written for {:?}, expected {network:?}",
+            metadata.network
+        );
+    }
+    let verifier = VerifierConfig::new(network)
+        .with_program_hashes(program_hashes)
+        .with_versions(args.min_version.unwrap_or(0)..=args.max_version.unwrap_or(u64::MAX))
+        .with_finality(args.finality.unwrap_or_default());
+
+    if !args.report {
+        let results = verify_batch_proof(bundle, &verifier)?;
+        return print_json(&results);
+    }
+
+    let report = verify_batch_report(bundle, &verifier)?;
+    let failures: Vec<_> = report
+        .failures()
+        .into_iter()
+        .map(|failure| {
+            json!({
+                "category": format!("{:?}", failure.category),
+                "index": failure.index,
+                "error": failure.error.to_string(),
+            })
+        })
+        .collect();
+    let failed = failures.len();
+    print_json(&json!({
+        "failures": failures,
+        "results": report.into_verified(),
+    }))?;
+
+    if failed > 0 {
+        bail!("{failed} proofs failed verification");
+    }
+    Ok(())
+}
+
+/// Reads a bundle file and its metadata.
+pub fn read_bundle(path: &Path) -> Result<(BundleMetadata, ProofBundle)> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    ProofBundle::read_with_metadata(BufReader::new(file))
+        .with_context(|| format!("failed to read bundle {}", path.display()))
+}
@@ -0,0 +1,300 @@
+//! Recomputes the transaction and receipt roots of a block range and reports every block whose
+//! roots disagree with its header.
+//!
+//! ```text
+//! check-trie-roots <execution|op-stack> <rpc-url> <start-block> [count]
+//! ```
+//!
+//! Roots are rebuilt from EIP-2718 encoded items under RLP-encoded index keys, the encoding
+//! `mpt-generate` uses for transaction and receipt proofs. A block reported here fails with
+//! `InvalidTrieRoot` when users request its transaction or receipt proofs.
+
+use alloy_consensus::transaction::TxHashRef;
+use alloy_consensus::{ReceiptEnvelope, TxEnvelope};
+use alloy_eips::Encodable2718;
+use alloy_primitives::B256;
+use alloy_provider::{Provider, ProviderBuilder};
+use alloy_rpc_types_eth::{BlockTransactions, Transaction};
+use alloy_trie::root::ordered_trie_root_with_encoder;
+use anyhow::{anyhow, bail, Context, Result};
+use op_alloy_consensus::{OpReceiptEnvelope, OpTxEnvelope};
+use op_alloy_network::Optimism;
+
+const USAGE: &str = "usage: check-trie-roots <execution|op-stack> <rpc-url> <start-block> [count]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Chain {
+    Execution,
+    OpStack,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    chain: Chain,
+    rpc_url: String,
+    start_block: u64,
+    count: u64,
+}
+
+impl Args {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let args: Vec<String> = args.into_iter().collect();
+        if !(3..=4).contains(&args.len()) {
+            bail!(USAGE);
+        }
+
+        let chain = match args[0].as_str() {
+            "execution" => Chain::Execution,
+            "op-stack" => Chain::OpStack,
+            other => bail!("unknown chain `{other}`\n{USAGE}"),
+        };
+        let start_block = args[2].parse().context("invalid start block")?;
+        let count = match args.get(3) {
+            Some(count) => count.parse().context("invalid block count")?,
+            None => 1,
+        };
+        if count == 0 {
+            bail!("block count must be positive");
+        }
+
+        Ok(Self {
+            chain,
+            rpc_url: args[1].clone(),
+            start_block,
+            count,
+        })
+    }
+}
+
+/// Header roots of a block next to the roots recomputed from its items.
+struct BlockRoots {
+    block_number: u64,
+    tx_count: usize,
+    header_tx_root: B256,
+    computed_tx_root: B256,
+    header_receipt_root: B256,
+    computed_receipt_root: B256,
+}
+
+impl BlockRoots {
+    fn new<T: Encodable2718, R: Encodable2718>(
+        block_number: u64,
+        txs: &[T],
+        receipts: &[R],
+        header_tx_root: B256,
+        header_receipt_root: B256,
+    ) -> Self {
+        Self {
+            block_number,
+            tx_count: txs.len(),
+            header_tx_root,
+            computed_tx_root: ordered_trie_root_with_encoder(txs, |tx, buf| tx.encode_2718(buf)),
+            header_receipt_root,
+            computed_receipt_root: ordered_trie_root_with_encoder(receipts, |receipt, buf| {
+                receipt.encode_2718(buf)
+            }),
+        }
+    }
+
+    /// Returns a line per mismatching root.
+    fn mismatches(&self) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if self.header_tx_root != self.computed_tx_root {
+            mismatches.push(format!(
+                "block {}: transactions root mismatch ({} txs): header {}, computed {}",
+                self.block_number, self.tx_count, self.header_tx_root, self.computed_tx_root
+            ));
+        }
+        if self.header_receipt_root != self.computed_receipt_root {
+            mismatches.push(format!(
+                "block {}: receipts root mismatch ({} receipts): header {}, computed {}",
+                self.block_number,
+                self.tx_count,
+                self.header_receipt_root,
+                self.computed_receipt_root
+            ));
+        }
+        mismatches
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse(std::env::args().skip(1))?;
+    let end_block = args
+        .start_block
+        .checked_add(args.count - 1)
+        .ok_or_else(|| anyhow!("block range overflows"))?;
+
+    let mut mismatched_blocks = 0u64;
+    match args.chain {
+        Chain::Execution => {
+            let provider = ProviderBuilder::new()
+                .connect(&args.rpc_url)
+                .await
+                .context("rpc connection error")?;
+            for block_number in args.start_block..=end_block {
+                let roots = execution_roots(&provider, block_number).await?;
+                mismatched_blocks += u64::from(report(&roots));
+            }
+        }
+        Chain::OpStack => {
+            let provider = ProviderBuilder::new_with_network::<Optimism>()
+                .connect(&args.rpc_url)
+                .await
+                .context("rpc connection error")?;
+            for block_number in args.start_block..=end_block {
+                let roots = op_stack_roots(&provider, block_number).await?;
+                mismatched_blocks += u64::from(report(&roots));
+            }
+        }
+    }
+
+    println!(
+        "checked blocks {}..={end_block}: {mismatched_blocks} with mismatching roots",
+        args.start_block
+    );
+    if mismatched_blocks > 0 {
+        bail!("{mismatched_blocks} blocks have mismatching trie roots");
+    }
+    Ok(())
+}
+
+/// Prints the mismatches of `roots` and returns `true` if there are any.
+fn report(roots: &BlockRoots) -> bool {
+    let mismatches = roots.mismatches();
+    for mismatch in &mismatches {
+        println!("{mismatch}");
+    }
+    !mismatches.is_empty()
+}
+
+async fn execution_roots<P: Provider>(provider: &P, block_number: u64) -> Result<BlockRoots> {
+    let block = provider
+        .get_block_by_number(block_number.into())
+        .full()
+        .await?
+        .ok_or_else(|| anyhow!("block {block_number} not found"))?;
+    let txs = match &block.transactions {
+        BlockTransactions::Full(txs) => txs,
+        _ => bail!("block {block_number} response did not include full transactions"),
+    };
+
+    let receipts: Vec<ReceiptEnvelope> =
+        match provider.get_block_receipts(block_number.into()).await? {
+            Some(receipts) => receipts
+                .into_iter()
+                .map(|receipt| receipt.map_logs(|log| log.inner).into_inner())
+                .collect(),
+            None => {
+                let mut receipts = Vec::with_capacity(txs.len());
+                for tx in txs {
+                    let tx_hash = *tx.inner.tx_hash();
+                    let receipt = provider
+                        .get_transaction_receipt(tx_hash)
+                        .await?
+                        .ok_or_else(|| anyhow!("receipt not found for tx {tx_hash}"))?;
+                    receipts.push(receipt.map_logs(|log| log.inner).into_inner());
+                }
+                receipts
+            }
+        };
+    let txs: Vec<TxEnvelope> = txs.iter().cloned().map(Transaction::into_inner).collect();
+
+    Ok(BlockRoots::new(
+        block_number,
+        &txs,
+        &receipts,
+        block.header.transactions_root,
+        block.header.receipts_root,
+    ))
+}
+
+async fn op_stack_roots<P: Provider<Optimism>>(
+    provider: &P,
+    block_number: u64,
+) -> Result<BlockRoots> {
+    let block = provider
+        .get_block_by_number(block_number.into())
+        .full()
+        .await?
+        .ok_or_else(|| anyhow!("block {block_number} not found"))?;
+    let header_tx_root = block.header.transactions_root;
+    let header_receipt_root = block.header.receipts_root;
+    let txs = block
+        .transactions
+        .into_transactions_vec()
+        .into_iter()
+        .map(|tx| tx.inner.into_inner())
+        .collect::<Vec<OpTxEnvelope>>();
+
+    let receipts: Vec<OpReceiptEnvelope> =
+        match provider.get_block_receipts(block_number.into()).await? {
+            Some(receipts) => receipts.into_iter().map(Into::into).collect(),
+            None => {
+                let mut receipts = Vec::with_capacity(txs.len());
+                for tx in &txs {
+                    let tx_hash = tx.tx_hash();
+                    let receipt = provider
+                        .get_transaction_receipt(tx_hash)
+                        .await?
+                        .ok_or_else(|| anyhow!("receipt not found for tx {tx_hash}"))?;
+                    receipts.push(receipt.into());
+                }
+                receipts
+            }
+        };
+
+    Ok(BlockRoots::new(
+        block_number,
+        &txs,
+        &receipts,
+        header_tx_root,
+        header_receipt_root,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Result<Args> {
+        Args::parse(values.iter().map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn parses_chain_range_and_default_count() {
+        assert_eq!(
+            args(&["op-stack", "http://rpc", "100"]).unwrap(),
+            Args {
+                chain: Chain::OpStack,
+                rpc_url: "http://rpc".to_string(),
+                start_block: 100,
+                count: 1,
+            }
+        );
+        assert_eq!(
+            args(&["execution", "http://rpc", "100", "20"])
+                .unwrap()
+                .count,
+            20
+        );
+        assert!(args(&["beacon", "http://rpc", "100"]).is_err());
+        assert!(args(&["execution", "http://rpc", "100", "0"]).is_err());
+        assert!(args(&["execution", "http://rpc"]).is_err());
+    }
+
+    #[test]
+    fn reports_mismatching_roots() {
+        let txs: [TxEnvelope; 0] = [];
+        let receipts: [ReceiptEnvelope; 0] = [];
+        let empty_root = alloy_trie::EMPTY_ROOT_HASH;
+
+        let matching = BlockRoots::new(7, &txs, &receipts, empty_root, empty_root);
+        assert!(matching.mismatches().is_empty());
+
+        let mismatching = BlockRoots::new(7, &txs, &receipts, B256::ZERO, empty_root);
+        assert_eq!(mismatching.mismatches().len(), 1);
+        assert!(mismatching.mismatches()[0].starts_with("block 7: transactions root"));
+    }
+}
@@ -4,6 +4,7 @@ extern crate alloc;
 
 pub mod error;
 pub mod merkle;
+pub mod ssz;
 
 pub mod mmr;
 
@@ -0,0 +1,200 @@
+//! SSZ merkleization helpers for beacon chain proofs.
+//!
+//! Trees are SHA-256 binary Merkle trees padded with zero subtrees up to a fixed `limit` of
+//! chunks, as defined by the consensus specs.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::FixedBytes;
+
+use crate::merkle::{hash_path, MerkleHasher, Sha256Hasher};
+
+pub const BYTES_PER_CHUNK: usize = 32;
+
+/// Returns the root of an all-zero subtree of `depth`.
+pub fn zero_hash(depth: usize) -> FixedBytes<32> {
+    (0..depth).fold(FixedBytes::ZERO, |hash, _| {
+        Sha256Hasher::hash_pair(&hash, &hash)
+    })
+}
+
+/// Returns the depth of a tree holding `limit` chunks.
+pub fn tree_depth(limit: u64) -> usize {
+    if limit <= 1 {
+        0
+    } else {
+        (u64::BITS - (limit - 1).leading_zeros()) as usize
+    }
+}
+
+/// Packs serialized bytes into zero-padded 32-byte chunks.
+pub fn pack(bytes: &[u8]) -> Vec<FixedBytes<32>> {
+    bytes
+        .chunks(BYTES_PER_CHUNK)
+        .map(|chunk| {
+            let mut word = [0u8; BYTES_PER_CHUNK];
+            word[..chunk.len()].copy_from_slice(chunk);
+            FixedBytes::from(word)
+        })
+        .collect()
+}
+
+/// Returns the chunk of a `uint64` value.
+pub fn u64_leaf(value: u64) -> FixedBytes<32> {
+    let mut word = [0u8; BYTES_PER_CHUNK];
+    word[..8].copy_from_slice(&value.to_le_bytes());
+    FixedBytes::from(word)
+}
+
+/// Returns the chunk of a `boolean` value.
+pub fn bool_leaf(value: bool) -> FixedBytes<32> {
+    let mut word = [0u8; BYTES_PER_CHUNK];
+    word[0] = value as u8;
+    FixedBytes::from(word)
+}
+
+/// Reads a `uint64` value from its chunk, rejecting non-zero padding.
+pub fn leaf_u64(leaf: &FixedBytes<32>) -> Option<u64> {
+    if leaf[8..].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&leaf[..8]);
+    Some(u64::from_le_bytes(value))
+}
+
+/// Reads a `boolean` value from its chunk.
+pub fn leaf_bool(leaf: &FixedBytes<32>) -> Option<bool> {
+    match leaf_u64(leaf)? {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+/// Mixes a list length into the root of its data tree.
+pub fn mix_in_length(root: FixedBytes<32>, length: u64) -> FixedBytes<32> {
+    Sha256Hasher::hash_pair(&root, &u64_leaf(length))
+}
+
+/// Merkleizes `chunks` into a tree of `limit` chunks.
+///
+/// `chunks` must not exceed `limit`.
+pub fn merkleize(chunks: &[FixedBytes<32>], limit: u64) -> FixedBytes<32> {
+    let depth = tree_depth(limit);
+    if chunks.is_empty() {
+        return zero_hash(depth);
+    }
+
+    let mut layer = chunks.to_vec();
+    for level in 0..depth {
+        if layer.len() % 2 == 1 {
+            layer.push(zero_hash(level));
+        }
+        layer = layer
+            .chunks(2)
+            .map(|pair| Sha256Hasher::hash_pair(&pair[0], &pair[1]))
+            .collect();
+    }
+
+    layer[0]
+}
+
+/// Returns the Merkle branch of `chunks[index]` in a tree of `limit` chunks.
+///
+/// The branch is ordered from the leaf level up, as expected by [`verify_branch`].
+pub fn generate_branch(chunks: &[FixedBytes<32>], limit: u64, index: u64) -> Vec<FixedBytes<32>> {
+    let depth = tree_depth(limit);
+    let mut branch = Vec::with_capacity(depth);
+    let mut layer = chunks.to_vec();
+    let mut current_index = index as usize;
+
+    for level in 0..depth {
+        let zero = zero_hash(level);
+        branch.push(layer.get(current_index ^ 1).copied().unwrap_or(zero));
+
+        if layer.len() % 2 == 1 {
+            layer.push(zero);
+        }
+        layer = layer
+            .chunks(2)
+            .map(|pair| Sha256Hasher::hash_pair(&pair[0], &pair[1]))
+            .collect();
+        current_index /= 2;
+    }
+
+    branch
+}
+
+/// Verifies that `leaf` sits at `index` below `root`, where `index` counts positions at the
+/// depth given by the branch length.
+pub fn verify_branch(
+    leaf: FixedBytes<32>,
+    branch: &[FixedBytes<32>],
+    index: u64,
+    root: FixedBytes<32>,
+) -> bool {
+    if branch.len() < u64::BITS as usize && index >> branch.len() != 0 {
+        return false;
+    }
+    hash_path::<Sha256Hasher>(branch, leaf, index) == root
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::merkle::compute_root;
+
+    fn chunk(byte: u8) -> FixedBytes<32> {
+        FixedBytes::from([byte; 32])
+    }
+
+    #[test]
+    fn tree_depth_rounds_up_to_power_of_two() {
+        assert_eq!(tree_depth(0), 0);
+        assert_eq!(tree_depth(1), 0);
+        assert_eq!(tree_depth(2), 1);
+        assert_eq!(tree_depth(5), 3);
+        assert_eq!(tree_depth(1 << 40), 40);
+    }
+
+    #[test]
+    fn merkleize_matches_padded_tree() {
+        let chunks = vec![chunk(1), chunk(2), chunk(3)];
+        let mut padded = chunks.clone();
+        padded.resize(8, FixedBytes::ZERO);
+
+        assert_eq!(merkleize(&chunks, 8), compute_root::<Sha256Hasher>(&padded));
+        assert_eq!(merkleize(&[], 8), zero_hash(3));
+    }
+
+    #[test]
+    fn branch_round_trip_in_sparse_tree() {
+        let chunks = vec![chunk(1), chunk(2), chunk(3)];
+        let root = merkleize(&chunks, 1 << 20);
+
+        for (index, leaf) in chunks.iter().enumerate() {
+            let branch = generate_branch(&chunks, 1 << 20, index as u64);
+            assert_eq!(branch.len(), 20);
+            assert!(verify_branch(*leaf, &branch, index as u64, root));
+        }
+    }
+
+    #[test]
+    fn verify_branch_rejects_index_beyond_depth() {
+        let chunks = vec![chunk(1), chunk(2)];
+        let root = merkleize(&chunks, 2);
+        let branch = generate_branch(&chunks, 2, 0);
+
+        assert!(!verify_branch(chunks[0], &branch, 2, root));
+    }
+
+    #[test]
+    fn uint64_leaf_round_trip() {
+        assert_eq!(leaf_u64(&u64_leaf(42)), Some(42));
+        assert_eq!(leaf_u64(&chunk(1)), None);
+        assert_eq!(leaf_bool(&bool_leaf(true)), Some(true));
+    }
+}
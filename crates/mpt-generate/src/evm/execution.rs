@@ -1,35 +1,52 @@
 use alloy_consensus::{transaction::TxHashRef, TxEnvelope};
-use alloy_provider::{Provider, ProviderBuilder};
+use alloy_provider::{DynProvider, Provider, ProviderBuilder};
 use alloy_rpc_types_eth::{BlockTransactions, ReceiptEnvelope, Transaction};
 
 use alloy_primitives::B256;
 
 use bankai_core::error::CoreError;
+use tokio::sync::OnceCell;
 
 use super::proof::{
     build_receipt_proof_from_items, build_tx_proof_from_items, ReceiptProof, TxProof,
 };
 
+/// Builds transaction and receipt proofs from one execution RPC endpoint.
+///
+/// The provider is connected on first use and the chain id is fetched once, so keep one
+/// client per endpoint instead of creating one per proof.
 pub struct ExecutionProofClient {
     rpc_url: String,
+    provider: OnceCell<DynProvider>,
+    chain_id: OnceCell<u64>,
 }
 
 impl ExecutionProofClient {
     pub fn new(rpc_url: String) -> Self {
-        Self { rpc_url }
+        Self {
+            rpc_url,
+            provider: OnceCell::new(),
+            chain_id: OnceCell::new(),
+        }
     }
 
     pub async fn chain_id(&self) -> Result<u64, CoreError> {
-        self.provider()
-            .await?
-            .get_chain_id()
-            .await
-            .map_err(|e| CoreError::Provider(format!("rpc error: {e}")))
+        let chain_id = self
+            .chain_id
+            .get_or_try_init(|| async {
+                self.provider()
+                    .await?
+                    .get_chain_id()
+                    .await
+                    .map_err(|e| CoreError::Provider(format!("rpc error: {e}")))
+            })
+            .await?;
+        Ok(*chain_id)
     }
 
     pub async fn tx_proof(&self, tx_hash: B256) -> Result<TxProof, CoreError> {
         let provider = self.provider().await?;
-        let tx = self.transaction_by_hash(&provider, tx_hash).await?;
+        let tx = self.transaction_by_hash(provider, tx_hash).await?;
         let block_number = tx
             .block_number
             .ok_or_else(|| CoreError::NotFound(format!("missing block number for tx {tx_hash}")))?;
@@ -67,7 +84,7 @@ impl ExecutionProofClient {
 
     pub async fn receipt_proof(&self, tx_hash: B256) -> Result<ReceiptProof, CoreError> {
         let provider = self.provider().await?;
-        let tx = self.transaction_by_hash(&provider, tx_hash).await?;
+        let tx = self.transaction_by_hash(provider, tx_hash).await?;
         let block_number = tx
             .block_number
             .ok_or_else(|| CoreError::NotFound(format!("missing block number for tx {tx_hash}")))?;
@@ -81,7 +98,7 @@ impl ExecutionProofClient {
             .map_err(|e| CoreError::Provider(format!("rpc error: {e}")))?
             .ok_or_else(|| CoreError::NotFound(format!("block {block_number} not found")))?;
         let receipts = self
-            .block_receipts(&provider, block_number, &block.transactions)
+            .block_receipts(provider, block_number, &block.transactions)
             .await?;
 
         build_receipt_proof_from_items(
@@ -94,11 +111,17 @@ impl ExecutionProofClient {
         )
     }
 
-    async fn provider(&self) -> Result<impl Provider, CoreError> {
-        ProviderBuilder::new()
-            .connect(self.rpc_url.as_str())
+    /// Returns the shared provider, connecting it on first use.
+    pub async fn provider(&self) -> Result<&DynProvider, CoreError> {
+        self.provider
+            .get_or_try_init(|| async {
+                ProviderBuilder::new()
+                    .connect(self.rpc_url.as_str())
+                    .await
+                    .map(|provider| provider.erased())
+                    .map_err(|e| CoreError::Provider(format!("rpc connection error: {e}")))
+            })
             .await
-            .map_err(|e| CoreError::Provider(format!("rpc connection error: {e}")))
     }
 
     async fn transaction_by_hash<P: Provider>(
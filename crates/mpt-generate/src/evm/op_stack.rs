@@ -1,30 +1,46 @@
 use alloy_consensus::transaction::TxHashRef;
-use alloy_provider::{Provider, ProviderBuilder};
+use alloy_provider::{DynProvider, Provider, ProviderBuilder};
 use op_alloy_network::Optimism;
 
 use alloy_primitives::B256;
 
 use bankai_core::error::CoreError;
+use tokio::sync::OnceCell;
 
 use super::proof::{
     build_receipt_proof_from_items, build_tx_proof_from_items, ReceiptProof, TxProof,
 };
 
+/// Builds transaction and receipt proofs from one OP Stack RPC endpoint.
+///
+/// Like [`crate::ExecutionProofClient`], the provider and chain id are cached.
 pub struct OpStackProofClient {
     rpc_url: String,
+    provider: OnceCell<DynProvider<Optimism>>,
+    chain_id: OnceCell<u64>,
 }
 
 impl OpStackProofClient {
     pub fn new(rpc_url: String) -> Self {
-        Self { rpc_url }
+        Self {
+            rpc_url,
+            provider: OnceCell::new(),
+            chain_id: OnceCell::new(),
+        }
     }
 
     pub async fn chain_id(&self) -> Result<u64, CoreError> {
-        self.provider()
-            .await?
-            .get_chain_id()
-            .await
-            .map_err(|e| CoreError::Provider(format!("rpc error: {e}")))
+        let chain_id = self
+            .chain_id
+            .get_or_try_init(|| async {
+                self.provider()
+                    .await?
+                    .get_chain_id()
+                    .await
+                    .map_err(|e| CoreError::Provider(format!("rpc error: {e}")))
+            })
+            .await?;
+        Ok(*chain_id)
     }
 
     pub async fn tx_proof(&self, tx_hash: B256) -> Result<TxProof, CoreError> {
@@ -124,10 +140,16 @@ impl OpStackProofClient {
         )
     }
 
-    async fn provider(&self) -> Result<impl Provider<Optimism>, CoreError> {
-        ProviderBuilder::new_with_network::<Optimism>()
-            .connect(self.rpc_url.as_str())
+    /// Returns the shared provider, connecting it on first use.
+    pub async fn provider(&self) -> Result<&DynProvider<Optimism>, CoreError> {
+        self.provider
+            .get_or_try_init(|| async {
+                ProviderBuilder::new_with_network::<Optimism>()
+                    .connect(self.rpc_url.as_str())
+                    .await
+                    .map(|provider| provider.erased())
+                    .map_err(|e| CoreError::Provider(format!("rpc connection error: {e}")))
+            })
             .await
-            .map_err(|e| CoreError::Provider(format!("rpc connection error: {e}")))
     }
 }
@@ -3,14 +3,17 @@ use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use bankai_types::api::blocks::{
-    BankaiBlockFullOutputDto, BankaiBlockProofRequestDto, BankaiMmrProofRequestDto, BlockDetailDto,
-    BlockStatusDto, BlockSummaryDto, LatestBlockQueryDto,
+    BankaiBlockFullOutputDto, BankaiBlockProofRequestDto, BankaiMmrProofRequestDto,
+    BankaiTargetBlockSelectorDto, BlockDetailDto, BlockStatusDto, BlockSummaryDto,
+    LatestBlockQueryDto,
 };
+use bankai_types::api::ethereum::BankaiBlockFilterDto;
 use bankai_types::api::proofs::{
     BankaiBlockProofDto, BankaiBlockProofWithMmrDto, BankaiMmrProofDto, BlockProofPayloadDto,
 };
 use bankai_types::api::stats::PageDto;
-use bankai_types::common::ProofFormat;
+use bankai_types::common::{HashingFunction, ProofFormat};
+use bankai_types::inputs::bankai::HistoricalBankaiBlockProof;
 use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
 use cairo_air::utils::{deserialize_proof_from_file, ProofFormat as CairoProofFormat};
 use cairo_air::CairoProof;
@@ -128,6 +131,36 @@ impl BlocksApi {
         let response = self.core.client.post(&url).json(request).send().await?;
         handle_response(response).await
     }
+
+    /// Fetches a historical Bankai block and its inclusion proof in the Bankai MMR of
+    /// `reference_block_number`.
+    ///
+    /// Verify the result with `bankai_verify::bankai::history` after verifying the
+    /// reference block's STWO proof.
+    pub async fn historical_block_proof(
+        &self,
+        reference_block_number: u64,
+        target_block_number: u64,
+        hashing_function: HashingFunction,
+    ) -> SdkResult<HistoricalBankaiBlockProof> {
+        let request = BankaiMmrProofRequestDto {
+            filter: BankaiBlockFilterDto::with_bankai_block_number(reference_block_number),
+            target_block: BankaiTargetBlockSelectorDto {
+                block_number: Some(target_block_number),
+                block_hash: None,
+            },
+            hashing_function,
+        };
+        let mmr_proof = self.mmr_proof(&request).await?;
+        let block = self.full(target_block_number).await?.block.to_block();
+
+        Ok(HistoricalBankaiBlockProof {
+            block,
+            mmr_proof: mmr_proof.try_into().map_err(|e| {
+                SdkError::InvalidInput(format!("invalid Bankai MMR proof hex from API: {e}"))
+            })?,
+        })
+    }
 }
 
 pub fn parse_block_proof_payload(
@@ -78,6 +78,50 @@ impl ApiClient {
     pub fn op_stack(&self) -> op_stack::OpStackApi {
         op_stack::OpStackApi::new(Arc::clone(&self.core))
     }
+
+    /// Sends a GET request to `path` and returns the raw JSON response.
+    ///
+    /// Use this for endpoints without a typed wrapper, for example
+    /// `get_json("/v1/blocks/latest", &[("status", "completed")])`.
+    pub async fn get_json<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        path: &str,
+        query: &[(K, V)],
+    ) -> SdkResult<serde_json::Value> {
+        let url = format!("{}/{}", self.core.base_url, path.trim_start_matches('/'));
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value.as_ref()))
+            .collect();
+        let response = self.core.client.get(&url).query(&query).send().await?;
+        handle_response(response).await
+    }
+
+    /// Sends a POST request with a JSON `body` to `path` and returns the raw JSON response.
+    ///
+    /// Use this for proof endpoints without a typed wrapper, for example
+    /// `post_json("/v1/blocks/mmr_proof", &[], &request)`.
+    pub async fn post_json<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        path: &str,
+        query: &[(K, V)],
+        body: &serde_json::Value,
+    ) -> SdkResult<serde_json::Value> {
+        let url = format!("{}/{}", self.core.base_url, path.trim_start_matches('/'));
+        let query: Vec<(&str, &str)> = query
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value.as_ref()))
+            .collect();
+        let response = self
+            .core
+            .client
+            .post(&url)
+            .query(&query)
+            .json(body)
+            .send()
+            .await?;
+        handle_response(response).await
+    }
 }
 
 fn build_http_client() -> reqwest::Client {
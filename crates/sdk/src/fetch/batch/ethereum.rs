@@ -1,53 +1,100 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use alloy_primitives::hex::ToHexExt;
+use alloy_primitives::{Address, FixedBytes};
 use alloy_rpc_types_beacon::header::HeaderResponse;
-use alloy_rpc_types_eth::{Account as AlloyAccount, Header as ExecutionHeader};
+use alloy_rpc_types_eth::Header as ExecutionHeader;
 use bankai_types::api::ethereum::{BankaiBlockFilterDto, EthereumLightClientProofRequestDto};
 use bankai_types::api::proofs::BankaiBlockProofDto;
-use bankai_types::inputs::evm::beacon::BeaconHeaderProof;
+use bankai_types::inputs::evm::beacon::{
+    BeaconHeaderBatchProof, BeaconHeaderProof, BeaconValidatorProof, ExecutionPayloadProof,
+};
 use bankai_types::inputs::evm::execution::{
-    AccountProof, ExecutionHeaderProof, ReceiptProof, StorageSlotProof, TxProof,
+    AccountProof, CodeProof, ExecutionHeaderBatchProof, ExecutionHeaderProof, LogProof,
+    ReceiptProof, StorageSlotProof, TxProof,
 };
 use bankai_types::results::evm::beacon::BeaconHeader;
+use futures::future::try_join_all;
 use tree_hash::TreeHash;
 
-use super::{beacon_fetcher, execution_fetcher, validate_bankai_block_proof, ProofBatchBuilder};
+use super::limiter::RpcLimiter;
+use super::{
+    beacon_fetcher, check_header_range, check_linked_headers, execution_fetcher,
+    fetch_storage_proofs, range_mmr_proof, validate_bankai_block_proof, ProofBatchBuilder,
+};
 use crate::errors::{SdkError, SdkResult};
 use crate::fetch::api::ApiClient;
+use crate::fetch::clients::execution_client::ProofQuery;
+use crate::fetch::evm::{proof_account, proof_code, proof_storage_slots};
 
 pub(super) struct EthereumBatchData {
     pub block_proof: Option<BankaiBlockProofDto>,
     pub execution_header_proofs: Vec<ExecutionHeaderProof>,
     pub beacon_header_proofs: Vec<BeaconHeaderProof>,
+    pub execution_header_range_proofs: Vec<ExecutionHeaderBatchProof>,
+    pub beacon_header_range_proofs: Vec<BeaconHeaderBatchProof>,
     pub account_proofs: Vec<AccountProof>,
     pub storage_slot_proofs: Vec<StorageSlotProof>,
     pub tx_proofs: Vec<TxProof>,
     pub receipt_proofs: Vec<ReceiptProof>,
+    pub code_proofs: Vec<CodeProof>,
+    pub execution_payload_proofs: Vec<ExecutionPayloadProof>,
+    pub validator_proofs: Vec<BeaconValidatorProof>,
+    pub log_proofs: Vec<LogProof>,
+}
+
+#[derive(Default)]
+struct ExecutionData {
+    header_map: BTreeMap<(u64, u64), ExecutionHeader>,
+    ranges: Vec<Vec<ExecutionHeader>>,
+    tx_proofs: Vec<TxProof>,
+    receipt_proofs: Vec<ReceiptProof>,
+    log_proofs: Vec<LogProof>,
+}
+
+#[derive(Default)]
+struct BeaconData {
+    header_map: BTreeMap<(u64, u64), HeaderResponse>,
+    ranges: Vec<Vec<HeaderResponse>>,
+    execution_payload_proofs: Vec<ExecutionPayloadProof>,
+    validator_proofs: Vec<BeaconValidatorProof>,
 }
 
 pub(super) async fn assemble_ethereum_proofs(
     builder: &ProofBatchBuilder<'_>,
     api: &ApiClient,
     filter: &BankaiBlockFilterDto,
+    limiter: &RpcLimiter,
 ) -> SdkResult<EthereumBatchData> {
     let eth = &builder.ethereum;
     let needs_exec = !eth.execution_header.is_empty()
+        || !eth.execution_header_range.is_empty()
         || !eth.account.is_empty()
         || !eth.storage_slot.is_empty()
         || !eth.tx_proof.is_empty()
-        || !eth.receipt_proof.is_empty();
-    let needs_beacon = !eth.beacon_header.is_empty();
+        || !eth.receipt_proof.is_empty()
+        || !eth.code.is_empty()
+        || !eth.logs.is_empty();
+    let needs_beacon = !eth.beacon_header.is_empty()
+        || !eth.beacon_header_range.is_empty()
+        || !eth.execution_payload.is_empty()
+        || !eth.validator.is_empty();
 
     if !needs_exec && !needs_beacon {
         return Ok(EthereumBatchData {
             block_proof: None,
             execution_header_proofs: Vec::new(),
             beacon_header_proofs: Vec::new(),
+            execution_header_range_proofs: Vec::new(),
+            beacon_header_range_proofs: Vec::new(),
             account_proofs: Vec::new(),
             storage_slot_proofs: Vec::new(),
             tx_proofs: Vec::new(),
             receipt_proofs: Vec::new(),
+            code_proofs: Vec::new(),
+            execution_payload_proofs: Vec::new(),
+            validator_proofs: Vec::new(),
+            log_proofs: Vec::new(),
         });
     }
 
@@ -60,88 +107,64 @@ pub(super) async fn assemble_ethereum_proofs(
     for request in &eth.beacon_header {
         beacon_headers.insert((request.network_id, request.slot));
     }
+    for request in &eth.execution_payload {
+        beacon_headers.insert((request.network_id, request.slot));
+    }
+    for request in &eth.validator {
+        beacon_headers.insert((request.network_id, request.slot));
+    }
     for request in &eth.account {
         exec_headers.insert((request.network_id, request.block_number));
     }
     for request in &eth.storage_slot {
         exec_headers.insert((request.network_id, request.block_number));
     }
-
-    let mut tx_proofs = Vec::new();
-    let mut receipt_proofs = Vec::new();
-    let mut exec_header_map: BTreeMap<(u64, u64), ExecutionHeader> = BTreeMap::new();
-
-    if needs_exec {
-        let exec_fetcher = execution_fetcher(builder)?;
-
-        for request in &eth.tx_proof {
-            if exec_fetcher.network_id() != request.network_id {
-                return Err(SdkError::InvalidInput(
-                    "execution network_id mismatch".into(),
-                ));
-            }
-            tx_proofs.push(exec_fetcher.tx_proof(request.tx_hash).await?);
-        }
-
-        for request in &eth.receipt_proof {
-            if exec_fetcher.network_id() != request.network_id {
-                return Err(SdkError::InvalidInput(
-                    "execution network_id mismatch".into(),
-                ));
-            }
-            receipt_proofs.push(exec_fetcher.receipt_proof(request.tx_hash).await?);
-        }
-
-        for proof in &tx_proofs {
-            exec_headers.insert((proof.network_id, proof.block_number));
-        }
-        for proof in &receipt_proofs {
-            exec_headers.insert((proof.network_id, proof.block_number));
-        }
-
-        for (network_id, block_number) in &exec_headers {
-            if exec_fetcher.network_id() != *network_id {
-                return Err(SdkError::InvalidInput(format!(
-                    "execution network_id mismatch: requested {}, configured {}",
-                    network_id,
-                    exec_fetcher.network_id()
-                )));
-            }
-            exec_header_map.insert(
-                (*network_id, *block_number),
-                exec_fetcher.header_only(*block_number).await?,
-            );
-        }
+    for request in &eth.code {
+        exec_headers.insert((request.network_id, request.block_number));
     }
 
-    let mut beacon_header_map: BTreeMap<(u64, u64), HeaderResponse> = BTreeMap::new();
-
-    if needs_beacon {
-        let beacon_fetcher = beacon_fetcher(builder)?;
-        for (network_id, slot) in &beacon_headers {
-            if beacon_fetcher.network_id() != *network_id {
-                return Err(SdkError::InvalidInput(format!(
-                    "beacon network_id mismatch: requested {}, configured {}",
-                    network_id,
-                    beacon_fetcher.network_id()
-                )));
+    // Execution and beacon data come from different endpoints, so fetch them side by side.
+    let (execution, beacon) = tokio::try_join!(
+        async {
+            if needs_exec {
+                fetch_execution_data(builder, limiter, exec_headers).await
+            } else {
+                Ok(ExecutionData::default())
             }
-            beacon_header_map.insert(
-                (*network_id, *slot),
-                beacon_fetcher.header_only(*slot).await?,
-            );
-        }
-    }
+        },
+        async {
+            if needs_beacon {
+                fetch_beacon_data(builder, limiter, beacon_headers).await
+            } else {
+                Ok(BeaconData::default())
+            }
+        },
+    )?;
+    let ExecutionData {
+        header_map: exec_header_map,
+        ranges: exec_ranges,
+        tx_proofs,
+        receipt_proofs,
+        log_proofs,
+    } = execution;
+    let BeaconData {
+        header_map: beacon_header_map,
+        ranges: beacon_ranges,
+        execution_payload_proofs,
+        validator_proofs,
+    } = beacon;
 
     let mut block_proof = None;
     let mut exec_mmr_by_hash = BTreeMap::new();
     let mut beacon_mmr_by_hash = BTreeMap::new();
 
-    if !exec_header_map.is_empty() {
-        let header_hashes = exec_header_map
+    if !exec_header_map.is_empty() || !exec_ranges.is_empty() {
+        let header_hashes: BTreeSet<String> = exec_header_map
             .values()
+            .chain(exec_ranges.iter().flatten())
             .map(|header| header.hash.to_string())
             .collect();
+        let header_hashes = header_hashes.into_iter().collect();
         let request = EthereumLightClientProofRequestDto {
             filter: filter.clone(),
             hashing_function: builder.hashing,
@@ -162,14 +185,13 @@ pub(super) async fn assemble_ethereum_proofs(
         }
     }
 
-    if !beacon_header_map.is_empty() {
-        let header_hashes = beacon_header_map
+    if !beacon_header_map.is_empty() || !beacon_ranges.is_empty() {
+        let header_hashes: BTreeSet<String> = beacon_header_map
             .values()
-            .map(|header| {
-                let root = BeaconHeader::from(header.clone()).tree_hash_root();
-                format!("0x{}", root.encode_hex())
-            })
+            .chain(beacon_ranges.iter().flatten())
+            .map(beacon_header_key_hex)
             .collect();
+        let header_hashes = header_hashes.into_iter().collect();
         let request = EthereumLightClientProofRequestDto {
             filter: filter.clone(),
             hashing_function: builder.hashing,
@@ -201,10 +223,8 @@ pub(super) async fn assemble_ethereum_proofs(
 
     let mut beacon_header_proofs = Vec::new();
     for header in beacon_header_map.values() {
-        let root = BeaconHeader::from(header.clone()).tree_hash_root();
-        let key = format!("0x{}", root.encode_hex());
         let mmr_proof = beacon_mmr_by_hash
-            .get(&key)
+            .get(&beacon_header_key_hex(header))
             .ok_or_else(|| SdkError::NotFound("missing MMR proof for beacon header".into()))?;
         beacon_header_proofs.push(BeaconHeaderProof {
             header: header.clone(),
@@ -214,71 +234,368 @@ pub(super) async fn assemble_ethereum_proofs(
         });
     }
 
+    let execution_header_range_proofs = exec_ranges
+        .into_iter()
+        .map(|headers| {
+            let mmr_proof = range_mmr_proof(
+                "execution",
+                headers.iter().map(|header| header.hash.to_string()),
+                &exec_mmr_by_hash,
+            )?;
+            Ok(ExecutionHeaderBatchProof { headers, mmr_proof })
+        })
+        .collect::<SdkResult<Vec<_>>>()?;
+
+    let beacon_header_range_proofs = beacon_ranges
+        .into_iter()
+        .map(|headers| {
+            let mmr_proof = range_mmr_proof(
+                "beacon",
+                headers.iter().map(beacon_header_key_hex),
+                &beacon_mmr_by_hash,
+            )?;
+            Ok(BeaconHeaderBatchProof { headers, mmr_proof })
+        })
+        .collect::<SdkResult<Vec<_>>>()?;
+
     let mut account_proofs = Vec::new();
     let mut storage_slot_proofs = Vec::new();
+    let mut code_proofs = Vec::new();
 
-    if !eth.account.is_empty() || !eth.storage_slot.is_empty() {
+    if !eth.account.is_empty() || !eth.storage_slot.is_empty() || !eth.code.is_empty() {
         let exec_fetcher = execution_fetcher(builder)?;
-
-        for request in &eth.account {
-            if exec_fetcher.network_id() != request.network_id {
-                return Err(SdkError::InvalidInput(
-                    "execution network_id mismatch".into(),
-                ));
-            }
-            let proof = exec_fetcher
-                .account(
-                    request.block_number,
-                    request.address,
-                    builder.hashing,
-                    builder.bankai_block_number,
-                )
-                .await?;
-            let header = exec_header_map
-                .get(&(request.network_id, request.block_number))
-                .ok_or_else(|| SdkError::NotFound("header not fetched for account".into()))?;
-            account_proofs.push(AccountProof {
-                account: AlloyAccount {
-                    balance: proof.balance,
-                    nonce: proof.nonce,
-                    code_hash: proof.code_hash,
-                    storage_root: proof.storage_hash,
-                },
-                address: request.address,
-                network_id: request.network_id,
-                block_number: request.block_number,
-                state_root: header.state_root,
-                mpt_proof: proof.account_proof,
-            });
+        let rpc = exec_fetcher.rpc_url();
+        let network_ids = eth
+            .account
+            .iter()
+            .map(|request| request.network_id)
+            .chain(eth.storage_slot.iter().map(|request| request.network_id))
+            .chain(eth.code.iter().map(|request| request.network_id));
+        for network_id in network_ids {
+            check_execution_network(exec_fetcher.network_id(), network_id)?;
         }
 
-        for request in &eth.storage_slot {
-            if exec_fetcher.network_id() != request.network_id {
-                return Err(SdkError::InvalidInput(
-                    "execution network_id mismatch".into(),
-                ));
-            }
-            storage_slot_proofs.push(
-                exec_fetcher
-                    .storage_slot_proof(
+        let accounts = async {
+            let queries: Vec<(u64, Address)> = eth
+                .account
+                .iter()
+                .map(|request| (request.block_number, request.address))
+                .collect();
+            let proofs = limiter.run(rpc, exec_fetcher.accounts(&queries)).await?;
+            eth.account
+                .iter()
+                .zip(proofs)
+                .map(|(request, proof)| {
+                    let header = exec_header_map
+                        .get(&(request.network_id, request.block_number))
+                        .ok_or_else(|| {
+                            SdkError::NotFound("header not fetched for account".into())
+                        })?;
+                    Ok(AccountProof {
+                        account: proof_account(&proof),
+                        address: request.address,
+                        network_id: request.network_id,
+                        block_number: request.block_number,
+                        state_root: header.state_root,
+                        mpt_proof: proof.account_proof,
+                    })
+                })
+                .collect::<SdkResult<Vec<_>>>()
+        };
+
+        let storage_slots = async {
+            let requests: Vec<_> = eth
+                .storage_slot
+                .iter()
+                .map(|request| {
+                    let query = ProofQuery {
+                        address: request.address,
+                        block_number: request.block_number,
+                        slot_keys: request.slot_keys.clone(),
+                    };
+                    (query, request.bytes_slots.as_slice())
+                })
+                .collect();
+            let proofs = fetch_storage_proofs(requests, |queries| async move {
+                limiter.run(rpc, exec_fetcher.proofs(&queries)).await
+            })
+            .await?;
+            eth.storage_slot
+                .iter()
+                .zip(proofs)
+                .map(|(request, proof)| {
+                    let header = exec_header_map
+                        .get(&(request.network_id, request.block_number))
+                        .ok_or_else(|| {
+                            SdkError::NotFound("header not fetched for storage slot".into())
+                        })?;
+                    Ok(proof_storage_slots(
+                        proof,
+                        request.network_id,
                         request.block_number,
-                        request.address,
-                        &request.slot_keys,
-                        builder.hashing,
-                        builder.bankai_block_number,
-                    )
-                    .await?,
-            );
-        }
+                        header.state_root,
+                    ))
+                })
+                .collect::<SdkResult<Vec<_>>>()
+        };
+
+        let code = async {
+            let accounts: Vec<(u64, Address)> = eth
+                .code
+                .iter()
+                .map(|request| (request.block_number, request.address))
+                .collect();
+            let (proofs, codes) = tokio::try_join!(
+                limiter.run(rpc, exec_fetcher.accounts(&accounts)),
+                limiter.run(rpc, exec_fetcher.codes(&accounts)),
+            )?;
+            eth.code
+                .iter()
+                .zip(proofs.into_iter().zip(codes))
+                .map(|(request, (proof, code))| {
+                    let header = exec_header_map
+                        .get(&(request.network_id, request.block_number))
+                        .ok_or_else(|| SdkError::NotFound("header not fetched for code".into()))?;
+                    Ok(proof_code(
+                        proof,
+                        code,
+                        request.network_id,
+                        request.block_number,
+                        header.state_root,
+                    ))
+                })
+                .collect::<SdkResult<Vec<_>>>()
+        };
+
+        (account_proofs, storage_slot_proofs, code_proofs) =
+            tokio::try_join!(accounts, storage_slots, code)?;
     }
 
     Ok(EthereumBatchData {
         block_proof,
         execution_header_proofs,
         beacon_header_proofs,
+        execution_header_range_proofs,
+        beacon_header_range_proofs,
         account_proofs,
         storage_slot_proofs,
         tx_proofs,
         receipt_proofs,
+        code_proofs,
+        execution_payload_proofs,
+        validator_proofs,
+        log_proofs,
+    })
+}
+
+/// Fetches execution headers, header ranges and the transaction, receipt and log proofs.
+async fn fetch_execution_data(
+    builder: &ProofBatchBuilder<'_>,
+    limiter: &RpcLimiter,
+    mut exec_headers: BTreeSet<(u64, u64)>,
+) -> SdkResult<ExecutionData> {
+    let eth = &builder.ethereum;
+    let exec_fetcher = execution_fetcher(builder)?;
+    let rpc = exec_fetcher.rpc_url();
+
+    let network_ids = eth
+        .tx_proof
+        .iter()
+        .map(|request| request.network_id)
+        .chain(eth.receipt_proof.iter().map(|request| request.network_id))
+        .chain(eth.logs.iter().map(|request| request.network_id))
+        .chain(
+            eth.execution_header_range
+                .iter()
+                .map(|request| request.network_id),
+        );
+    for network_id in network_ids {
+        check_execution_network(exec_fetcher.network_id(), network_id)?;
+    }
+    for request in &eth.execution_header_range {
+        check_header_range("execution", request.start, request.end)?;
+    }
+
+    let tx_proofs = try_join_all(
+        eth.tx_proof
+            .iter()
+            .map(|request| limiter.run(rpc, exec_fetcher.tx_proof(request.tx_hash))),
+    );
+    let receipt_proofs = try_join_all(
+        eth.receipt_proof
+            .iter()
+            .map(|request| limiter.run(rpc, exec_fetcher.receipt_proof(request.tx_hash))),
+    );
+    let log_proofs = try_join_all(eth.logs.iter().map(|request| async move {
+        let (tx, receipt) = tokio::try_join!(
+            limiter.run(rpc, exec_fetcher.tx_proof(request.tx_hash)),
+            limiter.run(rpc, exec_fetcher.receipt_proof(request.tx_hash)),
+        )?;
+        Ok::<_, SdkError>(LogProof {
+            tx,
+            receipt,
+            filter: request.filter.clone(),
+        })
+    }));
+    let (tx_proofs, receipt_proofs, log_proofs) =
+        tokio::try_join!(tx_proofs, receipt_proofs, log_proofs)?;
+
+    for proof in &tx_proofs {
+        exec_headers.insert((proof.network_id, proof.block_number));
+    }
+    for proof in &log_proofs {
+        exec_headers.insert((proof.receipt.network_id, proof.receipt.block_number));
+    }
+    for proof in &receipt_proofs {
+        exec_headers.insert((proof.network_id, proof.block_number));
+    }
+    for (network_id, _) in &exec_headers {
+        if exec_fetcher.network_id() != *network_id {
+            return Err(SdkError::InvalidInput(format!(
+                "execution network_id mismatch: requested {}, configured {}",
+                network_id,
+                exec_fetcher.network_id()
+            )));
+        }
+    }
+
+    // Single headers go out as one JSON-RPC batch.
+    let headers = async {
+        let block_numbers: Vec<u64> = exec_headers.iter().map(|(_, block)| *block).collect();
+        let headers = limiter
+            .run(rpc, exec_fetcher.headers_only(&block_numbers))
+            .await?;
+        Ok::<_, SdkError>(exec_headers.into_iter().zip(headers).collect())
+    };
+    let ranges = try_join_all(eth.execution_header_range.iter().map(|request| async move {
+        let block_numbers: Vec<u64> = (request.start..=request.end).collect();
+        let headers = limiter
+            .run(rpc, exec_fetcher.headers_only(&block_numbers))
+            .await?;
+        check_linked_headers(
+            "execution",
+            headers
+                .iter()
+                .map(|header| (header.number, header.hash, header.parent_hash)),
+        )?;
+        Ok::<_, SdkError>(headers)
+    }));
+    let (header_map, ranges) = tokio::try_join!(headers, ranges)?;
+
+    Ok(ExecutionData {
+        header_map,
+        ranges,
+        tx_proofs,
+        receipt_proofs,
+        log_proofs,
+    })
+}
+
+/// Fetches beacon headers, header ranges and the execution payload and validator proofs.
+async fn fetch_beacon_data(
+    builder: &ProofBatchBuilder<'_>,
+    limiter: &RpcLimiter,
+    beacon_headers: BTreeSet<(u64, u64)>,
+) -> SdkResult<BeaconData> {
+    let eth = &builder.ethereum;
+    let beacon_fetcher = beacon_fetcher(builder)?;
+    let rpc = beacon_fetcher.rpc_url();
+
+    let network_ids = beacon_headers
+        .iter()
+        .map(|(network_id, _)| *network_id)
+        .chain(
+            eth.beacon_header_range
+                .iter()
+                .map(|request| request.network_id),
+        );
+    for network_id in network_ids {
+        if beacon_fetcher.network_id() != network_id {
+            return Err(SdkError::InvalidInput(format!(
+                "beacon network_id mismatch: requested {}, configured {}",
+                network_id,
+                beacon_fetcher.network_id()
+            )));
+        }
+    }
+    for request in &eth.beacon_header_range {
+        check_header_range("beacon", request.start, request.end)?;
+    }
+
+    let headers = try_join_all(beacon_headers.into_iter().map(|key| async move {
+        let header = limiter.run(rpc, beacon_fetcher.header_only(key.1)).await?;
+        Ok::<_, SdkError>((key, header))
+    }));
+
+    let ranges = try_join_all(eth.beacon_header_range.iter().map(|request| async move {
+        let slots = try_join_all(
+            (request.start..=request.end)
+                .map(|slot| limiter.run(rpc, beacon_fetcher.header_if_exists(slot))),
+        )
+        .await?;
+        // Missed slots have no header.
+        let headers: Vec<HeaderResponse> = slots.into_iter().flatten().collect();
+        if headers.is_empty() {
+            return Err(SdkError::NotFound(format!(
+                "no beacon headers in slots {}..={}",
+                request.start, request.end
+            )));
+        }
+        check_linked_headers(
+            "beacon",
+            headers.iter().map(|header| {
+                let message = &header.data.header.message;
+                (message.slot, beacon_header_key(header), message.parent_root)
+            }),
+        )?;
+        Ok(headers)
+    }));
+
+    let execution_payload_proofs = try_join_all(
+        eth.execution_payload
+            .iter()
+            .map(|request| limiter.run(rpc, beacon_fetcher.execution_payload_proof(request.slot))),
+    );
+
+    // Group validators by slot so each beacon state is downloaded once.
+    let mut validators_by_slot: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+    for request in &eth.validator {
+        validators_by_slot
+            .entry(request.slot)
+            .or_default()
+            .push(request.validator_index);
+    }
+    let validator_proofs =
+        try_join_all(validators_by_slot.iter().map(|(slot, indices)| {
+            limiter.run(rpc, beacon_fetcher.validator_proofs(*slot, indices))
+        }));
+
+    let (headers, ranges, execution_payload_proofs, validator_proofs) =
+        tokio::try_join!(headers, ranges, execution_payload_proofs, validator_proofs)?;
+
+    Ok(BeaconData {
+        header_map: headers.into_iter().collect(),
+        ranges,
+        execution_payload_proofs,
+        validator_proofs: validator_proofs.into_iter().flatten().collect(),
     })
 }
+
+fn check_execution_network(configured: u64, requested: u64) -> SdkResult<()> {
+    if configured != requested {
+        return Err(SdkError::InvalidInput(
+            "execution network_id mismatch".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the tree hash root committed for a beacon header in the beacon MMR.
+fn beacon_header_key(header: &HeaderResponse) -> FixedBytes<32> {
+    BeaconHeader::from(header.clone()).tree_hash_root()
+}
+
+/// Returns [`beacon_header_key`] in the hex form used by the Bankai API.
+fn beacon_header_key_hex(header: &HeaderResponse) -> String {
+    format!("0x{}", beacon_header_key(header).encode_hex())
+}
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use tokio::sync::Semaphore;
+
+/// Caps the number of in-flight RPC calls per endpoint while a batch is assembled.
+///
+/// Endpoints are keyed by URL, so chains sharing one RPC also share its limit.
+pub(super) struct RpcLimiter {
+    limit: usize,
+    overrides: BTreeMap<String, usize>,
+    endpoints: Mutex<BTreeMap<String, Arc<Semaphore>>>,
+}
+
+impl RpcLimiter {
+    /// Creates a limiter allowing `limit` concurrent calls per endpoint, or the
+    /// endpoint's entry in `overrides`. Limits are at least one.
+    pub fn new(limit: usize, overrides: BTreeMap<String, usize>) -> Self {
+        Self {
+            limit,
+            overrides,
+            endpoints: Mutex::default(),
+        }
+    }
+
+    /// Runs `call` once a slot for `endpoint` is free.
+    pub async fn run<F: Future>(&self, endpoint: &str, call: F) -> F::Output {
+        let semaphore = self.semaphore(endpoint);
+        let _permit = semaphore
+            .acquire()
+            .await
+            .expect("RPC semaphores are never closed");
+        call.await
+    }
+
+    fn semaphore(&self, endpoint: &str) -> Arc<Semaphore> {
+        let mut endpoints = self
+            .endpoints
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        endpoints
+            .entry(endpoint.to_string())
+            .or_insert_with(|| {
+                let limit = self.overrides.get(endpoint).copied().unwrap_or(self.limit);
+                Arc::new(Semaphore::new(limit.max(1)))
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use futures::future::join_all;
+
+    use super::RpcLimiter;
+
+    #[tokio::test]
+    async fn limits_concurrent_calls_per_endpoint() {
+        let limiter = RpcLimiter::new(2, BTreeMap::from([("http://c".to_string(), 1)]));
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let call = |endpoint: &'static str| {
+            let (limiter, in_flight, peak) = (&limiter, &in_flight, &peak);
+            async move {
+                limiter
+                    .run(endpoint, async {
+                        let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(current, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(10)).await;
+                        in_flight.fetch_sub(1, Ordering::SeqCst);
+                    })
+                    .await
+            }
+        };
+
+        join_all((0..8).map(|_| call("http://a"))).await;
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+
+        peak.store(0, Ordering::SeqCst);
+        join_all((0..4).flat_map(|_| [call("http://a"), call("http://b")])).await;
+        assert_eq!(peak.load(Ordering::SeqCst), 4);
+
+        peak.store(0, Ordering::SeqCst);
+        join_all((0..4).map(|_| call("http://c"))).await;
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+    }
+}
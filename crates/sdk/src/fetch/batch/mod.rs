@@ -1,38 +1,62 @@
 use alloy_primitives::FixedBytes;
 use alloy_primitives::{Address, U256};
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
 use bankai_types::api::ethereum::BankaiBlockFilterDto;
-use bankai_types::api::proofs::BankaiBlockProofDto;
-use bankai_types::common::{HashingFunction, ProofFormat};
+use bankai_types::api::proofs::{BankaiBlockProofDto, MmrProofDto};
+use bankai_types::block::BankaiBlock;
+use bankai_types::common::{Finality, HashingFunction, ProofFormat};
+use bankai_types::inputs::evm::execution::LogFilter;
 use bankai_types::inputs::evm::op_stack::OpStackProofs;
-use bankai_types::inputs::evm::EvmProofs;
+use bankai_types::inputs::evm::{EvmProofs, MmrMultiProof, MmrProof};
 use bankai_types::inputs::ProofBundle;
+use bankai_types::storage;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::ops::RangeInclusive;
 use std::time::Instant;
 
 use crate::debug;
 use crate::errors::{SdkError, SdkResult};
 use crate::fetch::api::blocks::parse_block_proof_payload;
 use crate::fetch::api::ApiClient;
+use crate::fetch::clients::execution_client::ProofQuery;
 use crate::fetch::evm::{beacon::BeaconChainFetcher, execution::ExecutionChainFetcher};
 use crate::fetch::requests::{
-    AccountProofRequest, BeaconHeaderProofRequest, EvmProofsRequest, ExecutionHeaderProofRequest,
-    OpStackAccountProofRequest, OpStackHeaderProofRequest, OpStackProofsRequest,
+    AccountProofRequest, BeaconHeaderProofRequest, BeaconHeaderRangeRequest,
+    BeaconValidatorProofRequest, CodeProofRequest, EvmProofsRequest, ExecutionHeaderProofRequest,
+    ExecutionHeaderRangeRequest, ExecutionPayloadProofRequest, LogsProofRequest,
+    OpStackAccountProofRequest, OpStackCodeProofRequest, OpStackHeaderProofRequest,
+    OpStackHeaderRangeRequest, OpStackLogsProofRequest, OpStackProofsRequest,
     OpStackReceiptProofRequest, OpStackStorageSlotProofRequest, OpStackTxProofRequest,
     ReceiptProofRequest, StorageSlotProofRequest, TxProofRequest,
 };
 use crate::Bankai;
 
 mod ethereum;
+mod limiter;
 mod op_stack;
 
 use self::ethereum::assemble_ethereum_proofs;
+use self::limiter::RpcLimiter;
 use self::op_stack::assemble_op_stack_proofs;
 
+/// Default number of concurrent calls per RPC endpoint while a batch is assembled.
+pub const DEFAULT_RPC_CONCURRENCY: usize = 8;
+
+/// Largest number of blocks or slots one header range request may span.
+pub const MAX_HEADER_RANGE_LEN: u64 = 1024;
+
 /// Builder for the main SDK flow: collect requests, execute the batch, then verify the bundle.
 pub struct ProofBatchBuilder<'a> {
     bankai: &'a Bankai,
     bankai_block_number: u64,
     hashing: HashingFunction,
     proof_format: ProofFormat,
+    finality: Finality,
+    pool_nodes: bool,
+    batch_header_proofs: bool,
+    rpc_concurrency: usize,
+    endpoint_rpc_concurrency: BTreeMap<String, usize>,
     ethereum: EvmProofsRequest,
     op_stack: OpStackProofsRequest,
 }
@@ -45,6 +69,11 @@ impl<'a> ProofBatchBuilder<'a> {
             bankai_block_number,
             hashing,
             proof_format: ProofFormat::Bin,
+            finality: Finality::Latest,
+            pool_nodes: false,
+            batch_header_proofs: false,
+            rpc_concurrency: DEFAULT_RPC_CONCURRENCY,
+            endpoint_rpc_concurrency: BTreeMap::new(),
             ethereum: EvmProofsRequest::default(),
             op_stack: OpStackProofsRequest::default(),
         }
@@ -70,6 +99,66 @@ impl<'a> ProofBatchBuilder<'a> {
         self
     }
 
+    /// Adds a request for every Ethereum execution header in `blocks`.
+    ///
+    /// The headers are fetched with batched RPC requests and proven with one multi-leaf MMR
+    /// proof. The verifier returns them as a parent-hash-linked chain in
+    /// `results.evm.execution_header_range`. A range may span at most
+    /// [`MAX_HEADER_RANGE_LEN`] blocks.
+    pub fn ethereum_execution_header_range(mut self, blocks: RangeInclusive<u64>) -> Self {
+        self.ethereum
+            .execution_header_range
+            .push(ExecutionHeaderRangeRequest {
+                network_id: self.bankai.network().execution_network_id(),
+                start: *blocks.start(),
+                end: *blocks.end(),
+            });
+        self
+    }
+
+    /// Adds a request for every Ethereum beacon header in `slots`.
+    ///
+    /// Missed slots have no header and are skipped. The verifier returns the headers as a
+    /// parent-root-linked chain in `results.evm.beacon_header_range`. A range may span at
+    /// most [`MAX_HEADER_RANGE_LEN`] slots.
+    pub fn ethereum_beacon_header_range(mut self, slots: RangeInclusive<u64>) -> Self {
+        self.ethereum
+            .beacon_header_range
+            .push(BeaconHeaderRangeRequest {
+                network_id: self.bankai.network().beacon_network_id(),
+                start: *slots.start(),
+                end: *slots.end(),
+            });
+        self
+    }
+
+    /// Adds an Ethereum execution payload proof request for `slot`.
+    ///
+    /// The beacon header of `slot` is added to the batch, and the payload's block number,
+    /// block hash, state root and timestamp are verified against its body root.
+    pub fn ethereum_execution_payload(mut self, slot: u64) -> Self {
+        self.ethereum
+            .execution_payload
+            .push(ExecutionPayloadProofRequest {
+                network_id: self.bankai.network().beacon_network_id(),
+                slot,
+            });
+        self
+    }
+
+    /// Adds a beacon validator proof request for `validator_index` at `slot`.
+    ///
+    /// The beacon header of `slot` is added to the batch, and the validator record and balance
+    /// are verified against its state root. The beacon node must serve the debug state API.
+    pub fn ethereum_validator(mut self, slot: u64, validator_index: u64) -> Self {
+        self.ethereum.validator.push(BeaconValidatorProofRequest {
+            network_id: self.bankai.network().beacon_network_id(),
+            slot,
+            validator_index,
+        });
+        self
+    }
+
     /// Adds an Ethereum account proof request.
     pub fn ethereum_account(mut self, block_number: u64, address: Address) -> Self {
         self.ethereum.account.push(AccountProofRequest {
@@ -92,6 +181,37 @@ impl<'a> ProofBatchBuilder<'a> {
             block_number,
             address,
             slot_keys,
+            bytes_slots: Vec::new(),
+        });
+        self
+    }
+
+    /// Adds an Ethereum storage proof request for the `bytes` or `string` value at `slot`.
+    ///
+    /// Long values span several data slots, which are looked up at fetch time and proven
+    /// together with `slot`. Read the value with `VerifiedStorageSlots::read_bytes`.
+    pub fn ethereum_storage_bytes(
+        mut self,
+        block_number: u64,
+        address: Address,
+        slot: U256,
+    ) -> Self {
+        self.ethereum.storage_slot.push(StorageSlotProofRequest {
+            network_id: self.bankai.network().execution_network_id(),
+            block_number,
+            address,
+            slot_keys: Vec::new(),
+            bytes_slots: vec![slot],
+        });
+        self
+    }
+
+    /// Adds an Ethereum contract bytecode request, verified against the account code hash.
+    pub fn ethereum_code(mut self, block_number: u64, address: Address) -> Self {
+        self.ethereum.code.push(CodeProofRequest {
+            network_id: self.bankai.network().execution_network_id(),
+            block_number,
+            address,
         });
         self
     }
@@ -114,12 +234,75 @@ impl<'a> ProofBatchBuilder<'a> {
         self
     }
 
+    /// Adds an Ethereum logs request for the receipt of `tx_hash`.
+    ///
+    /// The transaction and its receipt are proven, and only the receipt logs matching `filter`
+    /// are returned after verification.
+    pub fn ethereum_logs(mut self, tx_hash: FixedBytes<32>, filter: LogFilter) -> Self {
+        self.ethereum.logs.push(LogsProofRequest {
+            network_id: self.bankai.network().execution_network_id(),
+            tx_hash,
+            filter,
+        });
+        self
+    }
+
+    /// Adds an Ethereum logs request for the `sol!` event `E` in the receipt of `tx_hash`.
+    ///
+    /// Decode the verified logs with `results.decode_events::<E>()`.
+    #[cfg(feature = "sol-types")]
+    pub fn ethereum_events<E: alloy_sol_types::SolEvent>(self, tx_hash: FixedBytes<32>) -> Self {
+        self.ethereum_logs(tx_hash, LogFilter::sol_event::<E>())
+    }
+
     /// Overrides the proof payload format requested from the Bankai API.
     pub fn proof_format(mut self, proof_format: ProofFormat) -> Self {
         self.proof_format = proof_format;
         self
     }
 
+    /// Requires every fetched header to be at least `finality` in the Bankai block.
+    ///
+    /// [`ProofBatchBuilder::execute`] fails instead of returning a bundle with headers above
+    /// the finalized (or justified) heights. Verify with a matching
+    /// `VerifierConfig::with_finality` to enforce the same policy on the verifier side.
+    pub fn finality(mut self, finality: Finality) -> Self {
+        self.finality = finality;
+        self
+    }
+
+    /// Stores the MPT nodes shared by account, storage and code proofs once per chain.
+    ///
+    /// Shrinks bundles with many state proofs. See [`ProofBundle::pool_nodes`].
+    pub fn pool_nodes(mut self) -> Self {
+        self.pool_nodes = true;
+        self
+    }
+
+    /// Proves the headers of each chain with one multi-leaf MMR proof.
+    ///
+    /// Shrinks bundles with many headers. See [`ProofBundle::batch_header_proofs`].
+    pub fn batch_header_proofs(mut self) -> Self {
+        self.batch_header_proofs = true;
+        self
+    }
+
+    /// Sets how many calls may be in flight per RPC endpoint while the batch is fetched.
+    ///
+    /// Defaults to [`DEFAULT_RPC_CONCURRENCY`]. A limit of zero is treated as one.
+    pub fn rpc_concurrency(mut self, limit: usize) -> Self {
+        self.rpc_concurrency = limit;
+        self
+    }
+
+    /// Overrides [`ProofBatchBuilder::rpc_concurrency`] for the endpoint at `rpc_url`.
+    ///
+    /// `rpc_url` must match the URL the SDK was configured with.
+    pub fn endpoint_rpc_concurrency(mut self, rpc_url: impl Into<String>, limit: usize) -> Self {
+        self.endpoint_rpc_concurrency.insert(rpc_url.into(), limit);
+        self
+    }
+
     /// Adds an OP Stack header proof request for `chain_name` and `block_number`.
     pub fn op_stack_header(mut self, chain_name: impl Into<String>, block_number: u64) -> Self {
         self.op_stack.header.push(OpStackHeaderProofRequest {
@@ -154,6 +337,23 @@ impl<'a> ProofBatchBuilder<'a> {
         self
     }
 
+    /// Adds a request for every OP Stack header of `chain_name` in `blocks`.
+    ///
+    /// Works like [`ProofBatchBuilder::ethereum_execution_header_range`]. The verified chain
+    /// is returned in `results.op_stack.header_range`.
+    pub fn op_stack_header_range(
+        mut self,
+        chain_name: impl Into<String>,
+        blocks: RangeInclusive<u64>,
+    ) -> Self {
+        self.op_stack.header_range.push(OpStackHeaderRangeRequest {
+            chain_name: chain_name.into(),
+            start: *blocks.start(),
+            end: *blocks.end(),
+        });
+        self
+    }
+
     /// Adds an OP Stack account proof request.
     pub fn op_stack_account(
         mut self,
@@ -184,10 +384,48 @@ impl<'a> ProofBatchBuilder<'a> {
                 block_number,
                 address,
                 slot_keys,
+                bytes_slots: Vec::new(),
+            });
+        self
+    }
+
+    /// Adds an OP Stack storage proof request for the `bytes` or `string` value at `slot`.
+    ///
+    /// Works like [`ProofBatchBuilder::ethereum_storage_bytes`].
+    pub fn op_stack_storage_bytes(
+        mut self,
+        chain_name: impl Into<String>,
+        block_number: u64,
+        address: Address,
+        slot: U256,
+    ) -> Self {
+        self.op_stack
+            .storage_slot
+            .push(OpStackStorageSlotProofRequest {
+                chain_name: chain_name.into(),
+                block_number,
+                address,
+                slot_keys: Vec::new(),
+                bytes_slots: vec![slot],
             });
         self
     }
 
+    /// Adds an OP Stack contract bytecode request, verified against the account code hash.
+    pub fn op_stack_code(
+        mut self,
+        chain_name: impl Into<String>,
+        block_number: u64,
+        address: Address,
+    ) -> Self {
+        self.op_stack.code.push(OpStackCodeProofRequest {
+            chain_name: chain_name.into(),
+            block_number,
+            address,
+        });
+        self
+    }
+
     /// Adds an OP Stack transaction proof request by transaction hash.
     pub fn op_stack_tx(mut self, chain_name: impl Into<String>, tx_hash: FixedBytes<32>) -> Self {
         self.op_stack.tx_proof.push(OpStackTxProofRequest {
@@ -212,13 +450,40 @@ impl<'a> ProofBatchBuilder<'a> {
         self
     }
 
+    /// Adds an OP Stack logs request for the receipt of `tx_hash`.
+    ///
+    /// Works like [`ProofBatchBuilder::ethereum_logs`], including for deposit receipts.
+    pub fn op_stack_logs(
+        mut self,
+        chain_name: impl Into<String>,
+        tx_hash: FixedBytes<32>,
+        filter: LogFilter,
+    ) -> Self {
+        self.op_stack.logs.push(OpStackLogsProofRequest {
+            chain_name: chain_name.into(),
+            tx_hash,
+            filter,
+        });
+        self
+    }
+
+    /// Adds an OP Stack logs request for the `sol!` event `E` in the receipt of `tx_hash`.
+    #[cfg(feature = "sol-types")]
+    pub fn op_stack_events<E: alloy_sol_types::SolEvent>(
+        self,
+        chain_name: impl Into<String>,
+        tx_hash: FixedBytes<32>,
+    ) -> Self {
+        self.op_stack_logs(chain_name, tx_hash, LogFilter::sol_event::<E>())
+    }
+
     /// Executes the batch and returns the fetched proof bundle.
     ///
     /// The returned [`ProofBundle`] must still be verified with `bankai-verify`.
     pub async fn execute(self) -> SdkResult<ProofBundle> {
         let total_start = Instant::now();
         debug::log(format!(
-            "batch execute start bankai_block={} eth_requests={}/{}/{}/{}/{}/{} op_requests={}/{}/{}/{}/{}",
+            "batch execute start bankai_block={} eth_requests={}/{}/{}/{}/{}/{}/{}/{}/{}/{} eth_ranges={}/{} op_requests={}/{}/{}/{}/{}/{}/{} op_ranges={}",
             self.bankai_block_number,
             self.ethereum.execution_header.len(),
             self.ethereum.beacon_header.len(),
@@ -226,29 +491,48 @@ impl<'a> ProofBatchBuilder<'a> {
             self.ethereum.storage_slot.len(),
             self.ethereum.tx_proof.len(),
             self.ethereum.receipt_proof.len(),
+            self.ethereum.code.len(),
+            self.ethereum.execution_payload.len(),
+            self.ethereum.validator.len(),
+            self.ethereum.logs.len(),
+            self.ethereum.execution_header_range.len(),
+            self.ethereum.beacon_header_range.len(),
             self.op_stack.header.len(),
             self.op_stack.account.len(),
             self.op_stack.storage_slot.len(),
             self.op_stack.tx_proof.len(),
             self.op_stack.receipt_proof.len(),
+            self.op_stack.code.len(),
+            self.op_stack.logs.len(),
+            self.op_stack.header_range.len(),
         ));
 
         let api: &ApiClient = &self.bankai.api;
         let filter = BankaiBlockFilterDto::with_bankai_block_number(self.bankai_block_number);
 
-        let ethereum_start = Instant::now();
-        let ethereum = assemble_ethereum_proofs(&self, api, &filter).await?;
-        debug::log(format!(
-            "assembled ethereum proofs in {} ms",
-            debug::elapsed_ms(ethereum_start)
-        ));
+        let limiter = RpcLimiter::new(self.rpc_concurrency, self.endpoint_rpc_concurrency.clone());
 
-        let op_stack_start = Instant::now();
-        let op_stack = assemble_op_stack_proofs(&self, api, &filter).await?;
-        debug::log(format!(
-            "assembled op-stack proofs in {} ms",
-            debug::elapsed_ms(op_stack_start)
-        ));
+        // Ethereum and OP Stack data come from different endpoints, so fetch them together.
+        let (ethereum, op_stack) = tokio::try_join!(
+            async {
+                let ethereum_start = Instant::now();
+                let ethereum = assemble_ethereum_proofs(&self, api, &filter, &limiter).await?;
+                debug::log(format!(
+                    "assembled ethereum proofs in {} ms",
+                    debug::elapsed_ms(ethereum_start)
+                ));
+                Ok::<_, SdkError>(ethereum)
+            },
+            async {
+                let op_stack_start = Instant::now();
+                let op_stack = assemble_op_stack_proofs(&self, api, &filter, &limiter).await?;
+                debug::log(format!(
+                    "assembled op-stack proofs in {} ms",
+                    debug::elapsed_ms(op_stack_start)
+                ));
+                Ok::<_, SdkError>(op_stack)
+            },
+        )?;
 
         let block_proof_dto = match select_matching_chain_block_proof(
             self.bankai_block_number,
@@ -290,34 +574,57 @@ impl<'a> ProofBatchBuilder<'a> {
         let evm_proofs = EvmProofs {
             execution_header_proof: ethereum.execution_header_proofs,
             beacon_header_proof: ethereum.beacon_header_proofs,
+            execution_header_batch_proof: Vec::new(),
+            beacon_header_batch_proof: Vec::new(),
+            execution_header_range_proof: ethereum.execution_header_range_proofs,
+            beacon_header_range_proof: ethereum.beacon_header_range_proofs,
             account_proof: ethereum.account_proofs,
             storage_slot_proof: ethereum.storage_slot_proofs,
             tx_proof: ethereum.tx_proofs,
             receipt_proof: ethereum.receipt_proofs,
+            code_proof: ethereum.code_proofs,
+            execution_payload_proof: ethereum.execution_payload_proofs,
+            beacon_validator_proof: ethereum.validator_proofs,
+            log_proof: ethereum.log_proofs,
+            node_pool: None,
         };
-        let evm_proofs = (!evm_proofs.is_empty()).then_some(evm_proofs);
 
         let op_stack_proofs = OpStackProofs {
             header_proof: op_stack.header_proofs,
+            header_batch_proof: Vec::new(),
+            header_range_proof: op_stack.header_range_proofs,
             account_proof: op_stack.account_proofs,
             storage_slot_proof: op_stack.storage_slot_proofs,
             tx_proof: op_stack.tx_proofs,
             receipt_proof: op_stack.receipt_proofs,
+            code_proof: op_stack.code_proofs,
+            log_proof: op_stack.log_proofs,
+            node_pool: None,
         };
+        check_finality(&block, &evm_proofs, &op_stack_proofs, self.finality)?;
+        let evm_proofs = (!evm_proofs.is_empty()).then_some(evm_proofs);
         let op_stack_proofs = (!op_stack_proofs.is_empty()).then_some(op_stack_proofs);
 
-        debug::log(format!(
-            "batch execute complete in {} ms",
-            debug::elapsed_ms(total_start)
-        ));
-
-        Ok(ProofBundle {
+        let mut bundle = ProofBundle {
             hashing_function: self.hashing,
             block_proof,
             block,
             evm_proofs,
             op_stack_proofs,
-        })
+        };
+        if self.batch_header_proofs {
+            bundle.batch_header_proofs();
+        }
+        if self.pool_nodes {
+            bundle.pool_nodes();
+        }
+
+        debug::log(format!(
+            "batch execute complete in {} ms",
+            debug::elapsed_ms(total_start)
+        ));
+
+        Ok(bundle)
     }
 }
 
@@ -347,6 +654,79 @@ fn select_matching_chain_block_proof(
     Ok(selected.cloned())
 }
 
+/// Rejects bundles with headers less final than `required` in `block`.
+fn check_finality(
+    block: &BankaiBlock,
+    evm_proofs: &EvmProofs,
+    op_stack_proofs: &OpStackProofs,
+    required: Finality,
+) -> SdkResult<()> {
+    let non_final = |kind: &str, height: u64, finality: Finality| {
+        SdkError::InvalidInput(format!(
+            "{kind} {height} is {finality:?} in Bankai block {}, but {required:?} is required",
+            block.block_number
+        ))
+    };
+
+    let execution_headers = evm_proofs
+        .execution_header_proof
+        .iter()
+        .map(|proof| &proof.header)
+        .chain(
+            evm_proofs
+                .execution_header_batch_proof
+                .iter()
+                .chain(&evm_proofs.execution_header_range_proof)
+                .flat_map(|proof| &proof.headers),
+        );
+    for header in execution_headers {
+        let finality = block.execution_finality(header.number);
+        if finality > required {
+            return Err(non_final("execution block", header.number, finality));
+        }
+    }
+    let beacon_headers = evm_proofs
+        .beacon_header_proof
+        .iter()
+        .map(|proof| &proof.header)
+        .chain(
+            evm_proofs
+                .beacon_header_batch_proof
+                .iter()
+                .chain(&evm_proofs.beacon_header_range_proof)
+                .flat_map(|proof| &proof.headers),
+        );
+    for header in beacon_headers {
+        let slot = header.data.header.message.slot;
+        let finality = block.beacon_finality(slot);
+        if finality > required {
+            return Err(non_final("beacon slot", slot, finality));
+        }
+    }
+    let op_snapshots = op_stack_proofs
+        .header_proof
+        .iter()
+        .map(|proof| &proof.snapshot)
+        .chain(
+            op_stack_proofs
+                .header_batch_proof
+                .iter()
+                .chain(&op_stack_proofs.header_range_proof)
+                .map(|proof| &proof.snapshot),
+        );
+    for snapshot in op_snapshots {
+        let finality = block.op_finality(snapshot);
+        if finality > required {
+            return Err(non_final(
+                "OP Stack L1 submission block",
+                snapshot.l1_submission_block,
+                finality,
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub(super) fn execution_fetcher<'a>(
     builder: &'a ProofBatchBuilder<'a>,
 ) -> SdkResult<&'a ExecutionChainFetcher> {
@@ -359,6 +739,132 @@ pub(super) fn beacon_fetcher<'a>(
     builder.bankai.ethereum().beacon()
 }
 
+/// Returns the slots of fetched `bytes`/`string` heads together with their data slots.
+///
+/// Fails if a head is not a valid `bytes`/`string` head or is longer than
+/// [`storage::MAX_BYTES_LENGTH`].
+pub(super) fn bytes_slot_keys(heads: &EIP1186AccountProofResponse) -> SdkResult<Vec<U256>> {
+    let mut keys = Vec::new();
+    for entry in &heads.storage_proof {
+        let slot_key: U256 = entry.key.as_b256().into();
+        let data_slots = storage::bytes_data_slots(slot_key, entry.value).ok_or_else(|| {
+            SdkError::InvalidInput(format!(
+                "slot {slot_key} does not hold a bytes or string value of at most {} bytes",
+                storage::MAX_BYTES_LENGTH
+            ))
+        })?;
+        keys.push(slot_key);
+        keys.extend(data_slots);
+    }
+    Ok(keys)
+}
+
+/// Fetches storage proofs for `(query, bytes_slots)` requests with batched `eth_getProof` calls.
+///
+/// A first batch reads the `bytes`/`string` heads of the requests that have any, a second one
+/// proves each query's slots together with those heads and their data slots. Proofs are
+/// returned in request order.
+pub(super) async fn fetch_storage_proofs<Fut>(
+    requests: Vec<(ProofQuery, &[U256])>,
+    fetch_proofs: impl Fn(Vec<ProofQuery>) -> Fut,
+) -> SdkResult<Vec<EIP1186AccountProofResponse>>
+where
+    Fut: Future<Output = SdkResult<Vec<EIP1186AccountProofResponse>>>,
+{
+    if requests.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let head_queries: Vec<ProofQuery> = requests
+        .iter()
+        .filter(|(_, bytes_slots)| !bytes_slots.is_empty())
+        .map(|(query, bytes_slots)| ProofQuery {
+            address: query.address,
+            block_number: query.block_number,
+            slot_keys: bytes_slots.to_vec(),
+        })
+        .collect();
+    let mut heads = if head_queries.is_empty() {
+        Vec::new()
+    } else {
+        fetch_proofs(head_queries).await?
+    }
+    .into_iter();
+
+    let mut queries = Vec::with_capacity(requests.len());
+    for (mut query, bytes_slots) in requests {
+        if !bytes_slots.is_empty() {
+            let heads = heads.next().ok_or_else(|| {
+                SdkError::Provider("missing bytes slot head proof in RPC batch".into())
+            })?;
+            query.slot_keys.extend(bytes_slot_keys(&heads)?);
+        }
+        queries.push(query);
+    }
+    fetch_proofs(queries).await
+}
+
+/// Checks that a `start..=end` header range is not empty and spans at most
+/// [`MAX_HEADER_RANGE_LEN`] headers.
+pub(super) fn check_header_range(kind: &str, start: u64, end: u64) -> SdkResult<()> {
+    if start > end {
+        return Err(SdkError::InvalidInput(format!(
+            "empty {kind} header range {start}..={end}"
+        )));
+    }
+    if end - start >= MAX_HEADER_RANGE_LEN {
+        return Err(SdkError::InvalidInput(format!(
+            "{kind} header range {start}..={end} spans more than {MAX_HEADER_RANGE_LEN} headers"
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `(height, hash, parent_hash)` headers increase and link by parent hash.
+///
+/// Catches reorgs during range fetching before the MMR proofs are requested.
+pub(super) fn check_linked_headers(
+    kind: &str,
+    headers: impl IntoIterator<Item = (u64, FixedBytes<32>, FixedBytes<32>)>,
+) -> SdkResult<()> {
+    let mut previous: Option<(u64, FixedBytes<32>)> = None;
+    for (height, hash, parent_hash) in headers {
+        if let Some((previous_height, previous_hash)) = previous {
+            if height <= previous_height || parent_hash != previous_hash {
+                return Err(SdkError::InvalidInput(format!(
+                    "{kind} header {height} does not link to header {previous_height}"
+                )));
+            }
+        }
+        previous = Some((height, hash));
+    }
+    Ok(())
+}
+
+/// Merges the API MMR proofs of a header range into one multi-leaf proof.
+pub(super) fn range_mmr_proof(
+    kind: &str,
+    header_hashes: impl IntoIterator<Item = String>,
+    mmr_by_hash: &BTreeMap<String, MmrProofDto>,
+) -> SdkResult<MmrMultiProof> {
+    let proofs = header_hashes
+        .into_iter()
+        .map(|header_hash| {
+            let mmr_proof = mmr_by_hash.get(&header_hash).ok_or_else(|| {
+                SdkError::NotFound(format!("missing MMR proof for {kind} header {header_hash}"))
+            })?;
+            MmrProof::try_from(mmr_proof.clone()).map_err(|e| {
+                SdkError::InvalidInput(format!("invalid {kind} MMR proof hex from API: {e}"))
+            })
+        })
+        .collect::<SdkResult<Vec<_>>>()?;
+    MmrMultiProof::from_proofs(&proofs).ok_or_else(|| {
+        SdkError::InvalidInput(format!(
+            "{kind} header range MMR proofs do not share one MMR"
+        ))
+    })
+}
+
 pub(super) fn validate_bankai_block_proof(
     block_proof: &BankaiBlockProofDto,
     expected_block_number: u64,
@@ -380,11 +886,26 @@ pub(super) fn validate_bankai_block_proof(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+
     use alloy_primitives::{Address, FixedBytes, U256};
+    use alloy_rpc_types_eth::EIP1186AccountProofResponse;
     use bankai_types::api::proofs::{BankaiBlockProofDto, BlockProofPayloadDto};
     use bankai_types::block::{BankaiBlock, BankaiBlockOutput};
 
-    use super::{select_matching_chain_block_proof, ProofBatchBuilder};
+    use bankai_types::block::ExecutionClient;
+    use bankai_types::common::Finality;
+    use bankai_types::inputs::evm::execution::ExecutionHeaderProof;
+    use bankai_types::inputs::evm::op_stack::OpStackProofs;
+    use bankai_types::inputs::evm::{EvmProofs, MmrProof};
+    use bankai_types::results::evm::execution::ExecutionHeader;
+
+    use super::{
+        check_finality, check_header_range, check_linked_headers, fetch_storage_proofs,
+        select_matching_chain_block_proof, LogFilter, ProofBatchBuilder, ProofQuery,
+        MAX_HEADER_RANGE_LEN,
+    };
+    use crate::errors::SdkError;
     use crate::{Bankai, HashingFunction, Network};
 
     fn block_output(block_number: u64, hash_byte: u8) -> BankaiBlockOutput {
@@ -408,7 +929,9 @@ mod tests {
             .op_stack_account("base", 12, Address::ZERO)
             .op_stack_storage_slot("base", 12, Address::ZERO, vec![U256::from(1u64)])
             .op_stack_tx("base", hash)
-            .op_stack_receipt("base", hash);
+            .op_stack_receipt("base", hash)
+            .op_stack_code("base", 12, Address::ZERO)
+            .op_stack_logs("base", hash, LogFilter::new().address(Address::ZERO));
 
         assert_eq!(builder.op_stack.header.len(), 3);
         assert_eq!(builder.op_stack.header[0].block_number, Some(12));
@@ -417,6 +940,56 @@ mod tests {
         assert_eq!(builder.op_stack.storage_slot.len(), 1);
         assert_eq!(builder.op_stack.tx_proof.len(), 1);
         assert_eq!(builder.op_stack.receipt_proof.len(), 1);
+        assert_eq!(builder.op_stack.code.len(), 1);
+        assert_eq!(builder.op_stack.logs.len(), 1);
+        assert_eq!(builder.op_stack.logs[0].filter.address, vec![Address::ZERO]);
+    }
+
+    #[test]
+    fn builder_collects_header_ranges() {
+        let sdk = Bankai::new(Network::Local, None, None, None);
+        let builder = ProofBatchBuilder::new(&sdk, 7, HashingFunction::Keccak)
+            .ethereum_execution_header_range(100..=120)
+            .ethereum_beacon_header_range(64..=95)
+            .op_stack_header_range("base", 5..=9);
+
+        assert_eq!(builder.ethereum.execution_header_range.len(), 1);
+        assert_eq!(builder.ethereum.execution_header_range[0].start, 100);
+        assert_eq!(builder.ethereum.execution_header_range[0].end, 120);
+        assert_eq!(builder.ethereum.beacon_header_range[0].end, 95);
+        assert_eq!(builder.op_stack.header_range[0].chain_name, "base");
+        assert_eq!(builder.op_stack.header_range[0].start, 5);
+    }
+
+    #[test]
+    fn check_header_range_rejects_empty_and_oversized_ranges() {
+        assert!(check_header_range("execution", 5, 5).is_ok());
+        assert!(check_header_range("beacon", 0, MAX_HEADER_RANGE_LEN - 1).is_ok());
+        assert!(check_header_range("beacon", 0, MAX_HEADER_RANGE_LEN).is_err());
+        assert!(check_header_range("OP", 0, u64::MAX).is_err());
+        assert!(check_header_range("execution", 6, 5).is_err());
+    }
+
+    #[test]
+    fn check_linked_headers_rejects_broken_links() {
+        let hash = |byte: u8| FixedBytes::from([byte; 32]);
+        let linked = [
+            (10, hash(1), hash(0)),
+            (11, hash(2), hash(1)),
+            (13, hash(3), hash(2)),
+        ];
+
+        assert!(check_linked_headers("beacon", linked).is_ok());
+        assert!(check_linked_headers(
+            "execution",
+            [(10, hash(1), hash(0)), (11, hash(2), hash(9))]
+        )
+        .is_err());
+        assert!(check_linked_headers(
+            "execution",
+            [(10, hash(1), hash(0)), (10, hash(2), hash(1))]
+        )
+        .is_err());
     }
 
     #[test]
@@ -462,4 +1035,115 @@ mod tests {
 
         assert!(proof.is_none());
     }
+
+    fn execution_header_proof(number: u64) -> ExecutionHeaderProof {
+        ExecutionHeaderProof {
+            header: ExecutionHeader {
+                number,
+                ..Default::default()
+            },
+            mmr_proof: MmrProof {
+                network_id: 1,
+                block_number: number,
+                hashing_function: HashingFunction::Keccak,
+                header_hash: FixedBytes::ZERO,
+                root: FixedBytes::ZERO,
+                elements_index: 0,
+                elements_count: 0,
+                path: Vec::new(),
+                peaks: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn check_finality_rejects_headers_above_required_height() {
+        let block = BankaiBlock {
+            execution: ExecutionClient {
+                justified_height: 120,
+                finalized_height: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let evm_proofs = EvmProofs {
+            execution_header_proof: vec![execution_header_proof(100), execution_header_proof(110)],
+            ..Default::default()
+        };
+        let op_stack_proofs = OpStackProofs::default();
+
+        assert!(check_finality(&block, &evm_proofs, &op_stack_proofs, Finality::Justified).is_ok());
+        assert!(
+            check_finality(&block, &evm_proofs, &op_stack_proofs, Finality::Finalized).is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_storage_proofs_batches_heads_then_slots() {
+        let rounds: Mutex<Vec<Vec<(u64, Vec<U256>)>>> = Mutex::default();
+        let fetch = |queries: Vec<ProofQuery>| {
+            let rounds = &rounds;
+            async move {
+                rounds.lock().unwrap().push(
+                    queries
+                        .iter()
+                        .map(|query| (query.block_number, query.slot_keys.clone()))
+                        .collect(),
+                );
+                Ok::<_, SdkError>(vec![EIP1186AccountProofResponse::default(); queries.len()])
+            }
+        };
+        let query = |block_number: u64, slot: u64| ProofQuery {
+            address: Address::ZERO,
+            block_number,
+            slot_keys: vec![U256::from(slot)],
+        };
+        let bytes_slots = [U256::from(9u64)];
+
+        let proofs = fetch_storage_proofs(
+            vec![(query(1, 1), &[][..]), (query(2, 2), &bytes_slots[..])],
+            fetch,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(proofs.len(), 2);
+        assert_eq!(
+            rounds.into_inner().unwrap(),
+            vec![
+                vec![(2, vec![U256::from(9u64)])],
+                vec![(1, vec![U256::from(1u64)]), (2, vec![U256::from(2u64)])],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_storage_proofs_rejects_oversized_bytes_heads() {
+        let head = serde_json::from_value::<EIP1186AccountProofResponse>(serde_json::json!({
+            "address": Address::ZERO,
+            "balance": "0x0",
+            "codeHash": FixedBytes::<32>::ZERO,
+            "nonce": "0x0",
+            "storageHash": FixedBytes::<32>::ZERO,
+            "accountProof": [],
+            "storageProof": [{
+                "key": FixedBytes::<32>::ZERO,
+                "value": (U256::from(1u8) << 255) + U256::from(1u8),
+                "proof": [],
+            }],
+        }))
+        .unwrap();
+        let fetch = |queries: Vec<ProofQuery>| {
+            let head = head.clone();
+            async move { Ok::<_, SdkError>(vec![head; queries.len()]) }
+        };
+        let query = ProofQuery {
+            address: Address::ZERO,
+            block_number: 1,
+            slot_keys: Vec::new(),
+        };
+
+        let result = fetch_storage_proofs(vec![(query, &[U256::ZERO][..])], fetch).await;
+        assert!(matches!(result, Err(SdkError::InvalidInput(_))));
+    }
 }
@@ -1,47 +1,120 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
 use std::time::Instant;
 
-use alloy_rpc_types_eth::{Account as AlloyAccount, Header as ExecutionHeader};
+use alloy_primitives::Address;
+use alloy_rpc_types_eth::Header as ExecutionHeader;
 use bankai_types::api::ethereum::BankaiBlockFilterDto;
 use bankai_types::api::op_stack::OpStackLightClientProofRequestDto;
 use bankai_types::api::proofs::BankaiBlockProofDto;
-use bankai_types::inputs::evm::execution::{AccountProof, ReceiptProof, StorageSlotProof, TxProof};
-use bankai_types::inputs::evm::op_stack::{OpStackHeaderProof, OpStackMerkleProof};
+use bankai_types::inputs::evm::execution::{
+    AccountProof, CodeProof, LogProof, ReceiptProof, StorageSlotProof, TxProof,
+};
+use bankai_types::inputs::evm::op_stack::{
+    OpStackHeaderBatchProof, OpStackHeaderProof, OpStackMerkleProof,
+};
+use futures::future::try_join_all;
 
-use super::{validate_bankai_block_proof, ProofBatchBuilder};
+use super::limiter::RpcLimiter;
+use super::{
+    check_header_range, check_linked_headers, fetch_storage_proofs, range_mmr_proof,
+    validate_bankai_block_proof, ProofBatchBuilder,
+};
 use crate::debug;
 use crate::errors::{SdkError, SdkResult};
 use crate::fetch::api::ApiClient;
+use crate::fetch::clients::execution_client::ProofQuery;
+use crate::fetch::evm::{proof_account, proof_code, proof_storage_slots};
 
 pub(super) struct OpStackBatchData {
     pub block_proof: Option<BankaiBlockProofDto>,
     pub header_proofs: Vec<OpStackHeaderProof>,
+    pub header_range_proofs: Vec<OpStackHeaderBatchProof>,
     pub account_proofs: Vec<AccountProof>,
     pub storage_slot_proofs: Vec<StorageSlotProof>,
     pub tx_proofs: Vec<TxProof>,
     pub receipt_proofs: Vec<ReceiptProof>,
+    pub code_proofs: Vec<CodeProof>,
+    pub log_proofs: Vec<LogProof>,
 }
 
 pub(super) async fn assemble_op_stack_proofs(
     builder: &ProofBatchBuilder<'_>,
     api: &ApiClient,
     filter: &BankaiBlockFilterDto,
+    limiter: &RpcLimiter,
 ) -> SdkResult<OpStackBatchData> {
+    let op = &builder.op_stack;
     debug::log(format!(
-        "assembling op-stack proofs headers={} accounts={} storage_slots={} txs={} receipts={}",
-        builder.op_stack.header.len(),
-        builder.op_stack.account.len(),
-        builder.op_stack.storage_slot.len(),
-        builder.op_stack.tx_proof.len(),
-        builder.op_stack.receipt_proof.len(),
+        "assembling op-stack proofs headers={} header_ranges={} accounts={} storage_slots={} txs={} receipts={} code={} logs={}",
+        op.header.len(),
+        op.header_range.len(),
+        op.account.len(),
+        op.storage_slot.len(),
+        op.tx_proof.len(),
+        op.receipt_proof.len(),
+        op.code.len(),
+        op.logs.len(),
     ));
 
-    let mut block_proof = None;
-    let mut op_header_map: BTreeMap<(String, String), ExecutionHeader> = BTreeMap::new();
-    let mut op_chain_ids = BTreeMap::new();
-    let mut op_committed_heights = BTreeMap::new();
+    for request in &op.header_range {
+        check_header_range(
+            &format!("{} OP", request.chain_name),
+            request.start,
+            request.end,
+        )?;
+    }
+
+    // Every chain is checked against its snapshot chain id, so resolve them all up front.
+    let chain_names: BTreeSet<&str> = op
+        .header
+        .iter()
+        .map(|request| request.chain_name.as_str())
+        .chain(
+            op.header_range
+                .iter()
+                .map(|request| request.chain_name.as_str()),
+        )
+        .chain(op.account.iter().map(|request| request.chain_name.as_str()))
+        .chain(
+            op.storage_slot
+                .iter()
+                .map(|request| request.chain_name.as_str()),
+        )
+        .chain(op.code.iter().map(|request| request.chain_name.as_str()))
+        .chain(
+            op.tx_proof
+                .iter()
+                .map(|request| request.chain_name.as_str()),
+        )
+        .chain(
+            op.receipt_proof
+                .iter()
+                .map(|request| request.chain_name.as_str()),
+        )
+        .chain(op.logs.iter().map(|request| request.chain_name.as_str()))
+        .collect();
+    let latest_chains: BTreeSet<&str> = op
+        .header
+        .iter()
+        .filter(|request| request.block_number.is_none() && request.header_hash.is_none())
+        .map(|request| request.chain_name.as_str())
+        .collect();
 
-    for request in &builder.op_stack.header {
+    let chain_ids = try_join_all(chain_names.iter().map(|chain_name| async move {
+        let fetcher = builder.bankai.op_stack(chain_name)?;
+        let chain_id = limiter.run(fetcher.rpc_url(), fetcher.chain_id()).await?;
+        Ok::<_, SdkError>((chain_name.to_string(), chain_id))
+    }));
+    let committed_heights = try_join_all(latest_chains.iter().map(|chain_name| async move {
+        let height = api.op_stack().height(chain_name, filter).await?.height;
+        Ok::<_, SdkError>((chain_name.to_string(), height))
+    }));
+    let (chain_ids, committed_heights) = tokio::try_join!(chain_ids, committed_heights)?;
+    let op_chain_ids: &BTreeMap<String, u64> = &chain_ids.into_iter().collect();
+    let op_committed_heights: &BTreeMap<String, u64> = &committed_heights.into_iter().collect();
+
+    let headers = try_join_all(op.header.iter().map(|request| async move {
         let request_start = Instant::now();
         debug::log(format!(
             "op-stack header request chain={} block={:?} header_hash_present={}",
@@ -50,156 +123,358 @@ pub(super) async fn assemble_op_stack_proofs(
             request.header_hash.is_some()
         ));
         let fetcher = builder.bankai.op_stack(&request.chain_name)?;
+        let rpc = fetcher.rpc_url();
         let header = match (request.block_number, request.header_hash) {
-            (Some(block_number), _) => fetcher.header_only(block_number).await?,
-            (None, Some(header_hash)) => fetcher.header_only_by_hash(header_hash).await?,
+            (Some(block_number), _) => limiter.run(rpc, fetcher.header_only(block_number)).await?,
+            (None, Some(header_hash)) => {
+                limiter
+                    .run(rpc, fetcher.header_only_by_hash(header_hash))
+                    .await?
+            }
             (None, None) => {
-                let block_number = get_or_fetch_op_committed_height(
-                    &mut op_committed_heights,
-                    api,
-                    &request.chain_name,
-                    filter,
-                )
-                .await?;
-                fetcher.header_only(block_number).await?
+                let block_number = op_committed_heights[&request.chain_name];
+                limiter.run(rpc, fetcher.header_only(block_number)).await?
             }
         };
-        op_header_map.insert(
-            (request.chain_name.clone(), header.hash.to_string()),
-            header,
-        );
         debug::log(format!(
             "op-stack header request chain={} completed in {} ms",
             request.chain_name,
             debug::elapsed_ms(request_start)
         ));
-    }
+        Ok::<_, SdkError>(header)
+    }));
 
-    let mut account_proofs = Vec::new();
-    for request in &builder.op_stack.account {
+    let ranges = try_join_all(op.header_range.iter().map(|request| async move {
         let request_start = Instant::now();
-        debug::log(format!(
-            "op-stack account request chain={} block={} address={}",
-            request.chain_name, request.block_number, request.address
-        ));
         let fetcher = builder.bankai.op_stack(&request.chain_name)?;
-        let chain_id = get_or_fetch_op_chain_id(&mut op_chain_ids, fetcher).await?;
-        let header = fetcher.header_only(request.block_number).await?;
-        op_header_map.insert(
-            (request.chain_name.clone(), header.hash.to_string()),
-            header.clone(),
-        );
-        let proof = fetcher
-            .account(request.block_number, request.address)
+        let block_numbers: Vec<u64> = (request.start..=request.end).collect();
+        let headers = limiter
+            .run(fetcher.rpc_url(), fetcher.headers_only(&block_numbers))
             .await?;
-        account_proofs.push(AccountProof {
-            account: AlloyAccount {
-                balance: proof.balance,
-                nonce: proof.nonce,
-                code_hash: proof.code_hash,
-                storage_root: proof.storage_hash,
-            },
-            address: request.address,
-            network_id: chain_id,
-            block_number: request.block_number,
-            state_root: header.state_root,
-            mpt_proof: proof.account_proof,
-        });
+        check_linked_headers(
+            "OP",
+            headers
+                .iter()
+                .map(|header| (header.number, header.hash, header.parent_hash)),
+        )?;
         debug::log(format!(
-            "op-stack account request chain={} block={} completed in {} ms",
+            "op-stack header range chain={} blocks={}..={} completed in {} ms",
             request.chain_name,
-            request.block_number,
+            request.start,
+            request.end,
             debug::elapsed_ms(request_start)
         ));
-    }
+        Ok::<_, SdkError>(headers)
+    }));
 
-    let mut storage_slot_proofs = Vec::new();
-    for request in &builder.op_stack.storage_slot {
+    // Account, storage and code proofs are batched per chain, since each chain has its own RPC.
+    let accounts = fetch_by_chain(
+        op.account.iter().map(|request| request.chain_name.as_str()),
+        |chain_name, indices| async move {
+            let request_start = Instant::now();
+            debug::log(format!(
+                "op-stack account batch chain={} accounts={}",
+                chain_name,
+                indices.len()
+            ));
+            let fetcher = builder.bankai.op_stack(chain_name)?;
+            let rpc = fetcher.rpc_url();
+            let requests: Vec<_> = indices.iter().map(|&index| &op.account[index]).collect();
+            let block_numbers: Vec<u64> = requests
+                .iter()
+                .map(|request| request.block_number)
+                .collect();
+            let queries: Vec<ProofQuery> = requests
+                .iter()
+                .map(|request| ProofQuery {
+                    address: request.address,
+                    block_number: request.block_number,
+                    slot_keys: Vec::new(),
+                })
+                .collect();
+            let (headers, proofs) = tokio::try_join!(
+                limiter.run(rpc, fetcher.headers_only(&block_numbers)),
+                limiter.run(rpc, fetcher.proofs(&queries)),
+            )?;
+            let network_id = op_chain_ids[chain_name];
+            let accounts: Vec<_> = requests
+                .iter()
+                .zip(headers.into_iter().zip(proofs))
+                .map(|(request, (header, proof))| {
+                    let account_proof = AccountProof {
+                        account: proof_account(&proof),
+                        address: request.address,
+                        network_id,
+                        block_number: request.block_number,
+                        state_root: header.state_root,
+                        mpt_proof: proof.account_proof,
+                    };
+                    (account_proof, header)
+                })
+                .collect();
+            debug::log(format!(
+                "op-stack account batch chain={} completed in {} ms",
+                chain_name,
+                debug::elapsed_ms(request_start)
+            ));
+            Ok::<_, SdkError>(accounts)
+        },
+    );
+
+    let storage_slots = fetch_by_chain(
+        op.storage_slot
+            .iter()
+            .map(|request| request.chain_name.as_str()),
+        |chain_name, indices| async move {
+            let request_start = Instant::now();
+            debug::log(format!(
+                "op-stack storage batch chain={} requests={}",
+                chain_name,
+                indices.len()
+            ));
+            let fetcher = builder.bankai.op_stack(chain_name)?;
+            let rpc = fetcher.rpc_url();
+            let requests: Vec<_> = indices
+                .iter()
+                .map(|&index| &op.storage_slot[index])
+                .collect();
+            let block_numbers: Vec<u64> = requests
+                .iter()
+                .map(|request| request.block_number)
+                .collect();
+            let storage_requests: Vec<_> = requests
+                .iter()
+                .map(|request| {
+                    let query = ProofQuery {
+                        address: request.address,
+                        block_number: request.block_number,
+                        slot_keys: request.slot_keys.clone(),
+                    };
+                    (query, request.bytes_slots.as_slice())
+                })
+                .collect();
+            let (headers, proofs) = tokio::try_join!(
+                limiter.run(rpc, fetcher.headers_only(&block_numbers)),
+                fetch_storage_proofs(storage_requests, |queries| async move {
+                    limiter.run(rpc, fetcher.proofs(&queries)).await
+                }),
+            )?;
+            let network_id = op_chain_ids[chain_name];
+            let storage_slots: Vec<_> = requests
+                .iter()
+                .zip(headers.into_iter().zip(proofs))
+                .map(|(request, (header, proof))| {
+                    let proof = proof_storage_slots(
+                        proof,
+                        network_id,
+                        request.block_number,
+                        header.state_root,
+                    );
+                    (proof, header)
+                })
+                .collect();
+            debug::log(format!(
+                "op-stack storage batch chain={} completed in {} ms",
+                chain_name,
+                debug::elapsed_ms(request_start)
+            ));
+            Ok::<_, SdkError>(storage_slots)
+        },
+    );
+
+    let code = fetch_by_chain(
+        op.code.iter().map(|request| request.chain_name.as_str()),
+        |chain_name, indices| async move {
+            let request_start = Instant::now();
+            debug::log(format!(
+                "op-stack code batch chain={} accounts={}",
+                chain_name,
+                indices.len()
+            ));
+            let fetcher = builder.bankai.op_stack(chain_name)?;
+            let rpc = fetcher.rpc_url();
+            let requests: Vec<_> = indices.iter().map(|&index| &op.code[index]).collect();
+            let block_numbers: Vec<u64> = requests
+                .iter()
+                .map(|request| request.block_number)
+                .collect();
+            let accounts: Vec<(u64, Address)> = requests
+                .iter()
+                .map(|request| (request.block_number, request.address))
+                .collect();
+            let queries: Vec<ProofQuery> = accounts
+                .iter()
+                .map(|(block_number, address)| ProofQuery {
+                    address: *address,
+                    block_number: *block_number,
+                    slot_keys: Vec::new(),
+                })
+                .collect();
+            let (headers, proofs, codes) = tokio::try_join!(
+                limiter.run(rpc, fetcher.headers_only(&block_numbers)),
+                limiter.run(rpc, fetcher.proofs(&queries)),
+                limiter.run(rpc, fetcher.codes(&accounts)),
+            )?;
+            let network_id = op_chain_ids[chain_name];
+            let code: Vec<_> = requests
+                .iter()
+                .zip(headers.into_iter().zip(proofs.into_iter().zip(codes)))
+                .map(|(request, (header, (proof, code)))| {
+                    let proof = proof_code(
+                        proof,
+                        code,
+                        network_id,
+                        request.block_number,
+                        header.state_root,
+                    );
+                    (proof, header)
+                })
+                .collect();
+            debug::log(format!(
+                "op-stack code batch chain={} completed in {} ms",
+                chain_name,
+                debug::elapsed_ms(request_start)
+            ));
+            Ok::<_, SdkError>(code)
+        },
+    );
+
+    let txs = try_join_all(op.tx_proof.iter().map(|request| async move {
         let request_start = Instant::now();
         debug::log(format!(
-            "op-stack storage request chain={} block={} slots={}",
-            request.chain_name,
-            request.block_number,
-            request.slot_keys.len()
+            "op-stack tx request chain={} tx_hash={}",
+            request.chain_name, request.tx_hash
         ));
         let fetcher = builder.bankai.op_stack(&request.chain_name)?;
-        let header = fetcher.header_only(request.block_number).await?;
-        op_header_map.insert(
-            (request.chain_name.clone(), header.hash.to_string()),
-            header,
-        );
-        storage_slot_proofs.push(
-            fetcher
-                .storage_slot_proof(request.block_number, request.address, &request.slot_keys)
-                .await?,
-        );
+        let rpc = fetcher.rpc_url();
+        let proof = limiter.run(rpc, fetcher.tx_proof(request.tx_hash)).await?;
+        check_op_chain_id(&request.chain_name, proof.network_id, op_chain_ids)?;
+        let header = limiter
+            .run(rpc, fetcher.header_only(proof.block_number))
+            .await?;
         debug::log(format!(
-            "op-stack storage request chain={} block={} completed in {} ms",
+            "op-stack tx request chain={} completed in {} ms",
             request.chain_name,
-            request.block_number,
             debug::elapsed_ms(request_start)
         ));
-    }
+        Ok::<_, SdkError>((proof, header))
+    }));
 
-    let mut tx_proofs = Vec::new();
-    for request in &builder.op_stack.tx_proof {
+    let receipts = try_join_all(op.receipt_proof.iter().map(|request| async move {
         let request_start = Instant::now();
         debug::log(format!(
-            "op-stack tx request chain={} tx_hash={}",
+            "op-stack receipt request chain={} tx_hash={}",
             request.chain_name, request.tx_hash
         ));
         let fetcher = builder.bankai.op_stack(&request.chain_name)?;
-        let chain_id = get_or_fetch_op_chain_id(&mut op_chain_ids, fetcher).await?;
-        let proof = fetcher.tx_proof(request.tx_hash).await?;
-        if proof.network_id != chain_id {
-            return Err(SdkError::InvalidInput(format!(
-                "OP chain_id mismatch for {}: rpc returned {}, snapshot returned {}",
-                request.chain_name, proof.network_id, chain_id
-            )));
-        }
-        let header = fetcher.header_only(proof.block_number).await?;
-        op_header_map.insert(
-            (request.chain_name.clone(), header.hash.to_string()),
-            header,
-        );
-        tx_proofs.push(proof);
+        let rpc = fetcher.rpc_url();
+        let proof = limiter
+            .run(rpc, fetcher.receipt_proof(request.tx_hash))
+            .await?;
+        check_op_chain_id(&request.chain_name, proof.network_id, op_chain_ids)?;
+        let header = limiter
+            .run(rpc, fetcher.header_only(proof.block_number))
+            .await?;
         debug::log(format!(
-            "op-stack tx request chain={} completed in {} ms",
+            "op-stack receipt request chain={} completed in {} ms",
             request.chain_name,
             debug::elapsed_ms(request_start)
         ));
-    }
+        Ok::<_, SdkError>((proof, header))
+    }));
 
-    let mut receipt_proofs = Vec::new();
-    for request in &builder.op_stack.receipt_proof {
+    let logs = try_join_all(op.logs.iter().map(|request| async move {
         let request_start = Instant::now();
         debug::log(format!(
-            "op-stack receipt request chain={} tx_hash={}",
+            "op-stack logs request chain={} tx_hash={}",
             request.chain_name, request.tx_hash
         ));
         let fetcher = builder.bankai.op_stack(&request.chain_name)?;
-        let chain_id = get_or_fetch_op_chain_id(&mut op_chain_ids, fetcher).await?;
-        let proof = fetcher.receipt_proof(request.tx_hash).await?;
-        if proof.network_id != chain_id {
-            return Err(SdkError::InvalidInput(format!(
-                "OP chain_id mismatch for {}: rpc returned {}, snapshot returned {}",
-                request.chain_name, proof.network_id, chain_id
-            )));
-        }
-        let header = fetcher.header_only(proof.block_number).await?;
-        op_header_map.insert(
-            (request.chain_name.clone(), header.hash.to_string()),
-            header,
-        );
-        receipt_proofs.push(proof);
+        let rpc = fetcher.rpc_url();
+        let (tx, receipt) = tokio::try_join!(
+            limiter.run(rpc, fetcher.tx_proof(request.tx_hash)),
+            limiter.run(rpc, fetcher.receipt_proof(request.tx_hash)),
+        )?;
+        check_op_chain_id(&request.chain_name, receipt.network_id, op_chain_ids)?;
+        let header = limiter
+            .run(rpc, fetcher.header_only(receipt.block_number))
+            .await?;
         debug::log(format!(
-            "op-stack receipt request chain={} completed in {} ms",
+            "op-stack logs request chain={} completed in {} ms",
             request.chain_name,
             debug::elapsed_ms(request_start)
         ));
+        let proof = LogProof {
+            tx,
+            receipt,
+            filter: request.filter.clone(),
+        };
+        Ok::<_, SdkError>((proof, header))
+    }));
+
+    let (headers, range_headers, accounts, storage_slots, code, txs, receipts, logs) = tokio::try_join!(
+        headers,
+        ranges,
+        accounts,
+        storage_slots,
+        code,
+        txs,
+        receipts,
+        logs
+    )?;
+
+    let mut op_header_map: BTreeMap<(String, String), ExecutionHeader> = BTreeMap::new();
+    for (request, header) in op.header.iter().zip(headers) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+    }
+
+    let mut account_proofs = Vec::new();
+    for (request, (proof, header)) in op.account.iter().zip(accounts) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+        account_proofs.push(proof);
     }
 
+    let mut storage_slot_proofs = Vec::new();
+    for (request, (proof, header)) in op.storage_slot.iter().zip(storage_slots) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+        storage_slot_proofs.push(proof);
+    }
+
+    let mut code_proofs = Vec::new();
+    for (request, (proof, header)) in op.code.iter().zip(code) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+        code_proofs.push(proof);
+    }
+
+    let mut tx_proofs = Vec::new();
+    for (request, (proof, header)) in op.tx_proof.iter().zip(txs) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+        tx_proofs.push(proof);
+    }
+
+    let mut receipt_proofs = Vec::new();
+    for (request, (proof, header)) in op.receipt_proof.iter().zip(receipts) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+        receipt_proofs.push(proof);
+    }
+
+    let mut log_proofs = Vec::new();
+    for (request, (proof, header)) in op.logs.iter().zip(logs) {
+        insert_op_header(&mut op_header_map, &request.chain_name, header);
+        log_proofs.push(proof);
+    }
+
+    let mut op_ranges: BTreeMap<String, Vec<Vec<ExecutionHeader>>> = BTreeMap::new();
+    for (request, headers) in op.header_range.iter().zip(range_headers) {
+        op_ranges
+            .entry(request.chain_name.clone())
+            .or_default()
+            .push(headers);
+    }
+
+    let mut block_proof = None;
     let mut header_proofs = Vec::new();
+    let mut header_range_proofs = Vec::new();
     let mut op_header_hashes_by_chain: BTreeMap<String, Vec<String>> = BTreeMap::new();
     for (chain_name, header_hash) in op_header_map.keys() {
         op_header_hashes_by_chain
@@ -207,12 +482,29 @@ pub(super) async fn assemble_op_stack_proofs(
             .or_default()
             .push(header_hash.clone());
     }
+    for chain_name in op_ranges.keys() {
+        op_header_hashes_by_chain
+            .entry(chain_name.clone())
+            .or_default();
+    }
 
     for (chain_name, header_hashes) in op_header_hashes_by_chain {
+        let ranges = op_ranges.remove(&chain_name).unwrap_or_default();
+        // Range headers are proven by the same request as the chain's single headers.
+        let request_hashes: BTreeSet<String> = header_hashes
+            .iter()
+            .cloned()
+            .chain(
+                ranges
+                    .iter()
+                    .flatten()
+                    .map(|header| header.hash.to_string()),
+            )
+            .collect();
         let request = OpStackLightClientProofRequestDto {
             filter: filter.clone(),
             hashing_function: builder.hashing,
-            header_hashes: header_hashes.clone(),
+            header_hashes: request_hashes.into_iter().collect(),
             proof_format: builder.proof_format,
         };
         let request_start = Instant::now();
@@ -224,7 +516,7 @@ pub(super) async fn assemble_op_stack_proofs(
             format!(
                 "api op-stack light_client_proof chain={} headers={}",
                 chain_name,
-                header_hashes.len()
+                request.header_hashes.len()
             ),
             request_start,
             &proof_result,
@@ -242,9 +534,7 @@ pub(super) async fn assemble_op_stack_proofs(
         }
 
         let snapshot = proof.snapshot.clone();
-        let expected_chain_id =
-            get_or_fetch_op_chain_id(&mut op_chain_ids, builder.bankai.op_stack(&chain_name)?)
-                .await?;
+        let expected_chain_id = op_chain_ids[&chain_name];
         if snapshot.chain_id != expected_chain_id {
             return Err(SdkError::InvalidInput(format!(
                 "OP snapshot chain_id mismatch for {}: rpc returned {}, proof returned {}",
@@ -283,42 +573,88 @@ pub(super) async fn assemble_op_stack_proofs(
                 })?,
             });
         }
+
+        for headers in ranges {
+            let mmr_proof = range_mmr_proof(
+                "OP",
+                headers.iter().map(|header| header.hash.to_string()),
+                &mmr_by_hash,
+            )?;
+            header_range_proofs.push(OpStackHeaderBatchProof {
+                headers,
+                snapshot: snapshot.clone(),
+                merkle_proof: merkle_proof.clone(),
+                mmr_proof,
+            });
+        }
     }
 
     Ok(OpStackBatchData {
         block_proof,
         header_proofs,
+        header_range_proofs,
         account_proofs,
         storage_slot_proofs,
         tx_proofs,
         receipt_proofs,
+        code_proofs,
+        log_proofs,
     })
 }
 
-async fn get_or_fetch_op_chain_id(
-    chain_ids: &mut BTreeMap<String, u64>,
-    fetcher: &crate::fetch::evm::op_stack::OpStackChainFetcher,
-) -> SdkResult<u64> {
-    if let Some(chain_id) = chain_ids.get(fetcher.chain_name()) {
-        return Ok(*chain_id);
+/// Runs `fetch` once per OP chain with the indices of that chain's requests.
+///
+/// `fetch` returns one item per index, and the items are returned in request order.
+async fn fetch_by_chain<'a, T, Fut>(
+    chain_names: impl Iterator<Item = &'a str>,
+    fetch: impl Fn(&'a str, Vec<usize>) -> Fut,
+) -> SdkResult<Vec<T>>
+where
+    Fut: Future<Output = SdkResult<Vec<T>>>,
+{
+    let mut by_chain: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    let mut len = 0;
+    for (index, chain_name) in chain_names.enumerate() {
+        by_chain.entry(chain_name).or_default().push(index);
+        len = index + 1;
+    }
+
+    let chains = try_join_all(by_chain.into_iter().map(|(chain_name, indices)| {
+        let items = fetch(chain_name, indices.clone());
+        async move { Ok::<_, SdkError>((indices, items.await?)) }
+    }))
+    .await?;
+
+    let mut results: Vec<Option<T>> = (0..len).map(|_| None).collect();
+    for (indices, items) in chains {
+        for (index, item) in indices.into_iter().zip(items) {
+            results[index] = Some(item);
+        }
     }
+    results
+        .into_iter()
+        .map(|item| item.ok_or_else(|| SdkError::Provider("missing OP proof in RPC batch".into())))
+        .collect()
+}
 
-    let chain_id = fetcher.chain_id().await?;
-    chain_ids.insert(fetcher.chain_name().to_string(), chain_id);
-    Ok(chain_id)
+fn insert_op_header(
+    headers: &mut BTreeMap<(String, String), ExecutionHeader>,
+    chain_name: &str,
+    header: ExecutionHeader,
+) {
+    headers.insert((chain_name.to_string(), header.hash.to_string()), header);
 }
 
-async fn get_or_fetch_op_committed_height(
-    committed_heights: &mut BTreeMap<String, u64>,
-    api: &ApiClient,
+fn check_op_chain_id(
     chain_name: &str,
-    filter: &BankaiBlockFilterDto,
-) -> SdkResult<u64> {
-    if let Some(height) = committed_heights.get(chain_name) {
-        return Ok(*height);
+    rpc_chain_id: u64,
+    chain_ids: &BTreeMap<String, u64>,
+) -> SdkResult<()> {
+    let chain_id = chain_ids[chain_name];
+    if rpc_chain_id != chain_id {
+        return Err(SdkError::InvalidInput(format!(
+            "OP chain_id mismatch for {chain_name}: rpc returned {rpc_chain_id}, snapshot returned {chain_id}"
+        )));
     }
-
-    let height = api.op_stack().height(chain_name, filter).await?.height;
-    committed_heights.insert(chain_name.to_string(), height);
-    Ok(height)
+    Ok(())
 }
@@ -36,4 +36,52 @@ impl BeaconFetcher {
 
         Ok(header_response)
     }
+
+    /// Fetches a `SignedBeaconBlock` as SSZ bytes together with its consensus fork name.
+    pub async fn fetch_block_ssz(&self, slot: u64) -> SdkResult<(String, Vec<u8>)> {
+        let url = format!("{}/eth/v2/beacon/blocks/{}", self.beacon_rpc, slot);
+        self.fetch_ssz(&url, &format!("beacon block slot {slot}"))
+            .await
+    }
+
+    /// Fetches a `BeaconState` as SSZ bytes together with its consensus fork name.
+    ///
+    /// Uses the debug namespace, which must be enabled on the beacon node. Mainnet states are
+    /// several hundred megabytes.
+    pub async fn fetch_state_ssz(&self, slot: u64) -> SdkResult<(String, Vec<u8>)> {
+        let url = format!("{}/eth/v2/debug/beacon/states/{}", self.beacon_rpc, slot);
+        self.fetch_ssz(&url, &format!("beacon state slot {slot}"))
+            .await
+    }
+
+    async fn fetch_ssz(&self, url: &str, what: &str) -> SdkResult<(String, Vec<u8>)> {
+        let response = self
+            .client
+            .get(url)
+            .header("Accept", "application/octet-stream")
+            .send()
+            .await
+            .map_err(SdkError::from)?;
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(SdkError::NotFound(format!("{what} not found")));
+        }
+        if !status.is_success() {
+            return Err(SdkError::Provider(format!(
+                "{what} request failed with status {status}"
+            )));
+        }
+
+        let version = response
+            .headers()
+            .get("Eth-Consensus-Version")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned)
+            .ok_or_else(|| {
+                SdkError::Beacon(format!("{what} response is missing Eth-Consensus-Version"))
+            })?;
+        let bytes = response.bytes().await.map_err(SdkError::from)?;
+
+        Ok((version, bytes.to_vec()))
+    }
 }
@@ -2,25 +2,61 @@ use std::time::Instant;
 
 use crate::debug;
 use crate::errors::{SdkError, SdkResult};
-use alloy_primitives::{Address, FixedBytes, U256};
-use alloy_provider::{Provider, ProviderBuilder};
-use alloy_rpc_types_eth::{EIP1186AccountProofResponse, Header as ExecutionHeader};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_provider::{DynProvider, Provider};
+use alloy_rpc_types_eth::{
+    Block, BlockId, BlockNumberOrTag, EIP1186AccountProofResponse, Header as ExecutionHeader,
+};
 use bankai_types::inputs::evm::execution::{ReceiptProof, TxProof};
 use mpt_generate::ExecutionProofClient;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
+/// Maximum number of calls sent in one JSON-RPC batch request.
+const RPC_BATCH_SIZE: usize = 100;
+
+/// One `eth_getProof` call of a batch.
+pub struct ProofQuery {
+    pub address: Address,
+    pub block_number: u64,
+    pub slot_keys: Vec<U256>,
+}
+
+impl ProofQuery {
+    /// Returns the `eth_getProof` parameters: address, storage keys and block.
+    fn params(&self) -> (Address, Vec<FixedBytes<32>>, BlockId) {
+        let keys = self
+            .slot_keys
+            .iter()
+            .map(|k| FixedBytes::from(k.to_be_bytes::<32>()))
+            .collect();
+        (self.address, keys, BlockId::number(self.block_number))
+    }
+}
+
+/// Execution RPC client for one endpoint.
+///
+/// Shares one provider and cached chain id with its proof client, so keep one fetcher per
+/// endpoint for its whole lifetime.
 pub struct ExecutionFetcher {
     pub rpc_url: String,
     pub _network_id: u64,
+    proof_client: ExecutionProofClient,
 }
 
 impl ExecutionFetcher {
     pub fn new(rpc_url: String, network_id: u64) -> Self {
         Self {
+            proof_client: ExecutionProofClient::new(rpc_url.clone()),
             rpc_url,
             _network_id: network_id,
         }
     }
 
+    async fn provider(&self) -> SdkResult<&DynProvider> {
+        Ok(self.proof_client.provider().await?)
+    }
+
     pub async fn fetch_header(&self, block_number: u64) -> SdkResult<ExecutionHeader> {
         let start = Instant::now();
         let label = format!(
@@ -29,10 +65,7 @@ impl ExecutionFetcher {
             block_number
         );
         let result = async {
-            let provider = ProviderBuilder::new()
-                .connect(self.rpc_url.as_str())
-                .await
-                .map_err(|e| SdkError::Provider(format!("rpc connection error: {e}")))?;
+            let provider = self.provider().await?;
 
             let block = provider
                 .get_block_by_number(block_number.into())
@@ -49,6 +82,110 @@ impl ExecutionFetcher {
         result
     }
 
+    /// Fetches the headers of `block_numbers` with JSON-RPC batch requests, in input order.
+    pub async fn fetch_headers(&self, block_numbers: &[u64]) -> SdkResult<Vec<ExecutionHeader>> {
+        let start = Instant::now();
+        let label = format!(
+            "rpc eth_getBlockByNumber batch endpoint={} blocks={}",
+            debug::endpoint_label(&self.rpc_url),
+            block_numbers.len()
+        );
+        let result = async {
+            let params: Vec<_> = block_numbers
+                .iter()
+                .map(|block_number| (BlockNumberOrTag::Number(*block_number), false))
+                .collect();
+            let blocks: Vec<Option<Block>> =
+                self.batch_calls("eth_getBlockByNumber", &params).await?;
+            block_numbers
+                .iter()
+                .zip(blocks)
+                .map(|(block_number, block)| {
+                    block.map(|block| block.header).ok_or_else(|| {
+                        SdkError::NotFound(format!("block {block_number} not found"))
+                    })
+                })
+                .collect()
+        }
+        .await;
+        debug::log_result(label, start, &result);
+        result
+    }
+
+    /// Fetches the `eth_getProof` responses of `queries` with JSON-RPC batch requests, in
+    /// input order.
+    pub async fn fetch_proofs(
+        &self,
+        queries: &[ProofQuery],
+    ) -> SdkResult<Vec<EIP1186AccountProofResponse>> {
+        let start = Instant::now();
+        let label = format!(
+            "rpc eth_getProof batch endpoint={} proofs={}",
+            debug::endpoint_label(&self.rpc_url),
+            queries.len()
+        );
+        let params: Vec<_> = queries.iter().map(ProofQuery::params).collect();
+        let result = self.batch_calls("eth_getProof", &params).await;
+        debug::log_result(label, start, &result);
+        result
+    }
+
+    /// Fetches the bytecode of `(block_number, address)` pairs with JSON-RPC batch requests,
+    /// in input order.
+    pub async fn fetch_codes(&self, accounts: &[(u64, Address)]) -> SdkResult<Vec<Bytes>> {
+        let start = Instant::now();
+        let label = format!(
+            "rpc eth_getCode batch endpoint={} accounts={}",
+            debug::endpoint_label(&self.rpc_url),
+            accounts.len()
+        );
+        let params: Vec<_> = accounts
+            .iter()
+            .map(|(block_number, address)| (*address, BlockId::number(*block_number)))
+            .collect();
+        let result = self.batch_calls("eth_getCode", &params).await;
+        debug::log_result(label, start, &result);
+        result
+    }
+
+    /// Sends one `method` call per entry of `params` in JSON-RPC batches of
+    /// [`RPC_BATCH_SIZE`], returning the responses in input order.
+    async fn batch_calls<Params, Resp>(
+        &self,
+        method: &'static str,
+        params: &[Params],
+    ) -> SdkResult<Vec<Resp>>
+    where
+        Params: Serialize + Clone + std::fmt::Debug + Send + Sync + Unpin,
+        Resp: DeserializeOwned + std::fmt::Debug + Send + Sync + Unpin + 'static,
+    {
+        let provider = self.provider().await?;
+        let mut responses = Vec::with_capacity(params.len());
+        for chunk in params.chunks(RPC_BATCH_SIZE) {
+            let mut batch = provider.client().new_batch();
+            let mut waiters = Vec::with_capacity(chunk.len());
+            for params in chunk {
+                let waiter = batch
+                    .add_call::<_, Resp>(method, params)
+                    .map_err(|e| SdkError::Provider(format!("rpc error: {e}")))?;
+                waiters.push(waiter);
+            }
+            batch
+                .send()
+                .await
+                .map_err(|e| SdkError::Provider(format!("rpc error: {e}")))?;
+
+            for waiter in waiters {
+                responses.push(
+                    waiter
+                        .await
+                        .map_err(|e| SdkError::Provider(format!("rpc error: {e}")))?,
+                );
+            }
+        }
+        Ok(responses)
+    }
+
     pub async fn fetch_header_by_hash(
         &self,
         block_hash: FixedBytes<32>,
@@ -60,10 +197,7 @@ impl ExecutionFetcher {
             block_hash
         );
         let result = async {
-            let provider = ProviderBuilder::new()
-                .connect(self.rpc_url.as_str())
-                .await
-                .map_err(|e| SdkError::Provider(format!("rpc connection error: {e}")))?;
+            let provider = self.provider().await?;
 
             let block = provider
                 .get_block_by_hash(block_hash)
@@ -80,24 +214,14 @@ impl ExecutionFetcher {
         result
     }
 
+    /// Returns the endpoint's chain id, fetched once per fetcher.
     pub async fn fetch_chain_id(&self) -> SdkResult<u64> {
         let start = Instant::now();
         let label = format!(
             "rpc eth_chainId endpoint={}",
             debug::endpoint_label(&self.rpc_url)
         );
-        let result = async {
-            let provider = ProviderBuilder::new()
-                .connect(self.rpc_url.as_str())
-                .await
-                .map_err(|e| SdkError::Provider(format!("rpc connection error: {e}")))?;
-
-            provider
-                .get_chain_id()
-                .await
-                .map_err(|e| SdkError::Provider(format!("rpc error: {e}")))
-        }
-        .await;
+        let result = self.proof_client.chain_id().await.map_err(SdkError::from);
         debug::log_result(label, start, &result);
         result
     }
@@ -115,10 +239,7 @@ impl ExecutionFetcher {
             block_number
         );
         let result = async {
-            let provider = ProviderBuilder::new()
-                .connect(self.rpc_url.as_str())
-                .await
-                .map_err(|e| SdkError::Provider(format!("rpc connection error: {e}")))?;
+            let provider = self.provider().await?;
 
             let proof = provider
                 .get_proof(address, vec![])
@@ -133,6 +254,28 @@ impl ExecutionFetcher {
         result
     }
 
+    pub async fn fetch_code(&self, address: Address, block_number: u64) -> SdkResult<Bytes> {
+        let start = Instant::now();
+        let label = format!(
+            "rpc eth_getCode endpoint={} address={} block={}",
+            debug::endpoint_label(&self.rpc_url),
+            address,
+            block_number
+        );
+        let result = async {
+            let provider = self.provider().await?;
+
+            provider
+                .get_code_at(address)
+                .block_id(block_number.into())
+                .await
+                .map_err(|e| SdkError::Provider(format!("rpc error: {e}")))
+        }
+        .await;
+        debug::log_result(label, start, &result);
+        result
+    }
+
     /// Fetches storage slot proofs for one or more slots from the same contract.
     pub async fn fetch_storage_slot_proof(
         &self,
@@ -140,10 +283,7 @@ impl ExecutionFetcher {
         block_number: u64,
         slot_keys: &[U256],
     ) -> SdkResult<EIP1186AccountProofResponse> {
-        let provider = ProviderBuilder::new()
-            .connect(self.rpc_url.as_str())
-            .await
-            .map_err(|e| SdkError::Provider(format!("rpc connection error: {e}")))?;
+        let provider = self.provider().await?;
 
         let keys: Vec<FixedBytes<32>> = slot_keys
             .iter()
@@ -160,9 +300,7 @@ impl ExecutionFetcher {
     }
 
     pub async fn fetch_tx_proof(&self, tx_hash: FixedBytes<32>) -> SdkResult<TxProof> {
-        let proof = ExecutionProofClient::new(self.rpc_url.clone())
-            .tx_proof(tx_hash)
-            .await?;
+        let proof = self.proof_client.tx_proof(tx_hash).await?;
         Ok(TxProof {
             network_id: proof.network_id,
             block_number: proof.block_number,
@@ -174,9 +312,7 @@ impl ExecutionFetcher {
     }
 
     pub async fn fetch_receipt_proof(&self, tx_hash: FixedBytes<32>) -> SdkResult<ReceiptProof> {
-        let proof = ExecutionProofClient::new(self.rpc_url.clone())
-            .receipt_proof(tx_hash)
-            .await?;
+        let proof = self.proof_client.receipt_proof(tx_hash).await?;
         Ok(ReceiptProof {
             network_id: proof.network_id,
             block_number: proof.block_number,
@@ -187,3 +323,26 @@ impl ExecutionFetcher {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proof_query_params_match_eth_get_proof() {
+        let query = ProofQuery {
+            address: Address::repeat_byte(0x11),
+            block_number: 26,
+            slot_keys: vec![U256::from(1)],
+        };
+
+        assert_eq!(
+            serde_json::to_value(query.params()).unwrap(),
+            serde_json::json!([
+                format!("0x{}", "11".repeat(20)),
+                [format!("0x{:064x}", 1)],
+                "0x1a"
+            ])
+        );
+    }
+}
@@ -1,35 +1,49 @@
-use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
 use alloy_rpc_types_eth::{EIP1186AccountProofResponse, Header as ExecutionHeader};
 use bankai_types::inputs::evm::execution::{ReceiptProof, TxProof};
 use mpt_generate::OpStackProofClient;
 
 use crate::errors::SdkResult;
-use crate::fetch::clients::execution_client::ExecutionFetcher;
+use crate::fetch::clients::execution_client::{ExecutionFetcher, ProofQuery};
 
+/// OP Stack RPC client for one endpoint.
+///
+/// Plain execution calls go through a long-lived [`ExecutionFetcher`], receipts and
+/// transactions through an OP-typed proof client. Both cache their provider and chain id.
 pub struct OpStackFetcher {
-    rpc_url: String,
+    execution: ExecutionFetcher,
+    proof_client: OpStackProofClient,
 }
 
 impl OpStackFetcher {
     pub fn new(rpc_url: String) -> Self {
-        Self { rpc_url }
+        Self {
+            proof_client: OpStackProofClient::new(rpc_url.clone()),
+            execution: ExecutionFetcher::new(rpc_url, 0),
+        }
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        &self.execution.rpc_url
     }
 
     pub async fn fetch_header(&self, block_number: u64) -> SdkResult<ExecutionHeader> {
-        self.execution_fetcher(0).fetch_header(block_number).await
+        self.execution.fetch_header(block_number).await
+    }
+
+    pub async fn fetch_headers(&self, block_numbers: &[u64]) -> SdkResult<Vec<ExecutionHeader>> {
+        self.execution.fetch_headers(block_numbers).await
     }
 
     pub async fn fetch_header_by_hash(
         &self,
         header_hash: FixedBytes<32>,
     ) -> SdkResult<ExecutionHeader> {
-        self.execution_fetcher(0)
-            .fetch_header_by_hash(header_hash)
-            .await
+        self.execution.fetch_header_by_hash(header_hash).await
     }
 
     pub async fn fetch_chain_id(&self) -> SdkResult<u64> {
-        self.execution_fetcher(0).fetch_chain_id().await
+        self.execution.fetch_chain_id().await
     }
 
     pub async fn fetch_account_proof(
@@ -37,18 +51,33 @@ impl OpStackFetcher {
         address: Address,
         block_number: u64,
     ) -> SdkResult<EIP1186AccountProofResponse> {
-        self.execution_fetcher(0)
+        self.execution
             .fetch_account_proof(address, block_number)
             .await
     }
 
+    pub async fn fetch_code(&self, address: Address, block_number: u64) -> SdkResult<Bytes> {
+        self.execution.fetch_code(address, block_number).await
+    }
+
+    pub async fn fetch_proofs(
+        &self,
+        queries: &[ProofQuery],
+    ) -> SdkResult<Vec<EIP1186AccountProofResponse>> {
+        self.execution.fetch_proofs(queries).await
+    }
+
+    pub async fn fetch_codes(&self, accounts: &[(u64, Address)]) -> SdkResult<Vec<Bytes>> {
+        self.execution.fetch_codes(accounts).await
+    }
+
     pub async fn fetch_storage_slot_proof(
         &self,
         address: Address,
         block_number: u64,
         slot_keys: &[U256],
     ) -> SdkResult<EIP1186AccountProofResponse> {
-        self.execution_fetcher(0)
+        self.execution
             .fetch_storage_slot_proof(address, block_number, slot_keys)
             .await
     }
@@ -58,9 +87,7 @@ impl OpStackFetcher {
         tx_hash: FixedBytes<32>,
         _network_id: u64,
     ) -> SdkResult<TxProof> {
-        let proof = OpStackProofClient::new(self.rpc_url.clone())
-            .tx_proof(tx_hash)
-            .await?;
+        let proof = self.proof_client.tx_proof(tx_hash).await?;
         Ok(TxProof {
             network_id: proof.network_id,
             block_number: proof.block_number,
@@ -76,9 +103,7 @@ impl OpStackFetcher {
         tx_hash: FixedBytes<32>,
         _network_id: u64,
     ) -> SdkResult<ReceiptProof> {
-        let proof = OpStackProofClient::new(self.rpc_url.clone())
-            .receipt_proof(tx_hash)
-            .await?;
+        let proof = self.proof_client.receipt_proof(tx_hash).await?;
         Ok(ReceiptProof {
             network_id: proof.network_id,
             block_number: proof.block_number,
@@ -88,8 +113,4 @@ impl OpStackFetcher {
             encoded_receipt: proof.encoded_receipt,
         })
     }
-
-    fn execution_fetcher(&self, network_id: u64) -> ExecutionFetcher {
-        ExecutionFetcher::new(self.rpc_url.clone(), network_id)
-    }
 }
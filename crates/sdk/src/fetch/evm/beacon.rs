@@ -1,11 +1,18 @@
 use alloy_primitives::hex::ToHexExt;
+use bankai_core::ssz;
 use bankai_types::api::ethereum::{BankaiBlockFilterDto, EthereumMmrProofRequestDto};
 use bankai_types::common::HashingFunction;
-use bankai_types::inputs::evm::beacon::BeaconHeaderProof;
+use bankai_types::inputs::evm::beacon::{
+    BeaconHeaderProof, BeaconValidatorProof, ExecutionPayloadProof,
+};
 use bankai_types::results::evm::beacon::BeaconHeader;
 use tree_hash::TreeHash;
 
-use crate::errors::SdkResult;
+use crate::errors::{SdkError, SdkResult};
+use crate::fetch::evm::ssz::{
+    beacon_state_schema, decode_validator, descend, field_roots, parse_fork, signed_block_schema,
+    ContainerTree, BLOCK_BODY_PATH,
+};
 use crate::fetch::{api::ApiClient, clients::beacon_client::BeaconFetcher};
 
 /// Fetcher for Ethereum beacon chain data with MMR proofs
@@ -79,9 +86,7 @@ impl BeaconChainFetcher {
         Ok(BeaconHeaderProof {
             header: header_response,
             mmr_proof: mmr_proof.try_into().map_err(|e| {
-                crate::errors::SdkError::InvalidInput(format!(
-                    "invalid beacon MMR proof hex from API: {e}"
-                ))
+                SdkError::InvalidInput(format!("invalid beacon MMR proof hex from API: {e}"))
             })?,
         })
     }
@@ -97,8 +102,128 @@ impl BeaconChainFetcher {
         self.beacon_client.fetch_header(slot).await
     }
 
+    /// Fetches the beacon header of a slot without MMR proof, if the slot has a block
+    ///
+    /// Returns `None` for missed slots instead of an error.
+    pub async fn header_if_exists(
+        &self,
+        slot: u64,
+    ) -> SdkResult<Option<alloy_rpc_types_beacon::header::HeaderResponse>> {
+        match self.beacon_client.fetch_header(slot).await {
+            Ok(header) => Ok(Some(header)),
+            Err(SdkError::NotFound(_)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Fetches an SSZ proof linking a slot's execution payload to its beacon block body root
+    ///
+    /// The proof is verified against the beacon header of the same slot, so the batch must
+    /// also contain that header.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The beacon chain slot whose execution payload to prove
+    ///
+    /// # Returns
+    ///
+    /// An `ExecutionPayloadProof` with the payload field roots and the body branch
+    pub async fn execution_payload_proof(&self, slot: u64) -> SdkResult<ExecutionPayloadProof> {
+        let (version, block) = self.beacon_client.fetch_block_ssz(slot).await?;
+        let fork = parse_fork(&version)?;
+
+        let (body, body_bytes) = descend(signed_block_schema(fork), &block, &BLOCK_BODY_PATH)?;
+        let body_roots = field_roots(body.fields()?, body_bytes)?;
+        let body_branch = ssz::generate_branch(
+            &body_roots,
+            1 << ExecutionPayloadProof::BODY_TREE_DEPTH,
+            ExecutionPayloadProof::BODY_FIELD_INDEX,
+        );
+
+        let (payload, payload_bytes) = descend(
+            body,
+            body_bytes,
+            &[ExecutionPayloadProof::BODY_FIELD_INDEX as usize],
+        )?;
+        let payload_fields = field_roots(payload.fields()?, payload_bytes)?;
+
+        Ok(ExecutionPayloadProof {
+            network_id: self.network_id,
+            slot,
+            payload_fields,
+            body_branch,
+        })
+    }
+
+    /// Fetches SSZ proofs of validator records and balances against a slot's beacon state
+    ///
+    /// The full beacon state is downloaded once and merkleized, so batching several validators
+    /// of the same slot is much cheaper than fetching them one by one. The proofs are verified
+    /// against the beacon header of the same slot, so the batch must also contain that header.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - The beacon chain slot whose state to prove against
+    /// * `validator_indices` - The validator indices to prove
+    ///
+    /// # Returns
+    ///
+    /// One `BeaconValidatorProof` per requested index, in request order
+    pub async fn validator_proofs(
+        &self,
+        slot: u64,
+        validator_indices: &[u64],
+    ) -> SdkResult<Vec<BeaconValidatorProof>> {
+        let (version, state) = self.beacon_client.fetch_state_ssz(slot).await?;
+        let fork = parse_fork(&version)?;
+        let tree = ContainerTree::new(beacon_state_schema(fork), &state)?;
+
+        let header: BeaconHeader = self.beacon_client.fetch_header(slot).await?.into();
+        if tree.root() != header.state_root {
+            return Err(SdkError::Beacon(format!(
+                "beacon state of slot {slot} does not match the header state root"
+            )));
+        }
+
+        let validators_field = BeaconValidatorProof::VALIDATORS_FIELD_INDEX as usize;
+        let mut proofs = Vec::with_capacity(validator_indices.len());
+        for &validator_index in validator_indices {
+            let validator_bytes =
+                tree.list_item(validators_field, validator_index)
+                    .map_err(|_| {
+                        SdkError::NotFound(format!(
+                            "validator {validator_index} not found at slot {slot}"
+                        ))
+                    })?;
+            let validator = decode_validator(validator_bytes)?;
+            let (_, validator_branch) = tree.list_leaf_proof(validators_field, validator_index)?;
+            let (balance_chunk, balance_branch) = tree.list_leaf_proof(
+                BeaconValidatorProof::BALANCES_FIELD_INDEX as usize,
+                validator_index / BeaconValidatorProof::BALANCES_PER_CHUNK,
+            )?;
+
+            proofs.push(BeaconValidatorProof {
+                network_id: self.network_id,
+                slot,
+                fork,
+                validator_index,
+                validator,
+                validator_branch,
+                balance_chunk,
+                balance_branch,
+            });
+        }
+
+        Ok(proofs)
+    }
+
     /// Returns the network ID for this fetcher
     pub fn network_id(&self) -> u64 {
         self.network_id
     }
+
+    /// Returns the beacon API endpoint URL
+    pub(crate) fn rpc_url(&self) -> &str {
+        &self.beacon_client.beacon_rpc
+    }
 }
@@ -1,14 +1,15 @@
-use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
 use alloy_rpc_types_eth::EIP1186AccountProofResponse;
 pub use alloy_rpc_types_eth::Header as ExecutionHeader;
 use bankai_types::api::ethereum::{BankaiBlockFilterDto, EthereumMmrProofRequestDto};
 use bankai_types::common::HashingFunction;
 
 use crate::errors::SdkResult;
-use crate::fetch::{api::ApiClient, clients::execution_client::ExecutionFetcher};
-use alloy_rpc_types_eth::Account as AlloyAccount;
+use crate::fetch::api::ApiClient;
+use crate::fetch::clients::execution_client::{ExecutionFetcher, ProofQuery};
+use crate::fetch::evm::{proof_code, proof_storage_slots};
 use bankai_types::inputs::evm::execution::{
-    ExecutionHeaderProof, ReceiptProof, StorageSlotEntry, StorageSlotProof, TxProof,
+    CodeProof, ExecutionHeaderProof, ReceiptProof, StorageSlotProof, TxProof,
 };
 
 /// Fetcher for Ethereum execution layer data with MMR proofs
@@ -23,7 +24,7 @@ use bankai_types::inputs::evm::execution::{
 pub struct ExecutionChainFetcher {
     #[allow(dead_code)]
     api_client: ApiClient,
-    rpc_url: String,
+    client: ExecutionFetcher,
     network_id: u64,
 }
 
@@ -38,7 +39,7 @@ impl ExecutionChainFetcher {
     pub fn new(api_client: ApiClient, rpc_url: String, network_id: u64) -> Self {
         Self {
             api_client,
-            rpc_url,
+            client: ExecutionFetcher::new(rpc_url, network_id),
             network_id,
         }
     }
@@ -64,9 +65,7 @@ impl ExecutionChainFetcher {
         hashing_function: HashingFunction,
         filter: BankaiBlockFilterDto,
     ) -> SdkResult<ExecutionHeaderProof> {
-        let header = ExecutionFetcher::new(self.rpc_url.clone(), self.network_id)
-            .fetch_header(block_number)
-            .await?;
+        let header = self.client.fetch_header(block_number).await?;
         let request = EthereumMmrProofRequestDto {
             filter,
             hashing_function,
@@ -93,12 +92,22 @@ impl ExecutionChainFetcher {
     /// Used internally by the batch builder. For verification purposes, use `header()` instead
     /// to get the header with its MMR proof.
     pub async fn header_only(&self, block_number: u64) -> SdkResult<ExecutionHeader> {
-        let header = ExecutionFetcher::new(self.rpc_url.clone(), self.network_id)
-            .fetch_header(block_number)
-            .await?;
+        let header = self.client.fetch_header(block_number).await?;
         Ok(header)
     }
 
+    /// Fetches execution headers without MMR proofs, batching the RPC requests
+    ///
+    /// The headers are returned in the order of `block_numbers`.
+    pub async fn headers_only(&self, block_numbers: &[u64]) -> SdkResult<Vec<ExecutionHeader>> {
+        self.client.fetch_headers(block_numbers).await
+    }
+
+    /// Returns the execution RPC endpoint URL
+    pub(crate) fn rpc_url(&self) -> &str {
+        &self.client.rpc_url
+    }
+
     /// Returns the network ID for this fetcher
     pub fn network_id(&self) -> u64 {
         self.network_id
@@ -127,12 +136,48 @@ impl ExecutionChainFetcher {
         _hashing_function: HashingFunction,
         _bankai_block_number: u64,
     ) -> SdkResult<EIP1186AccountProofResponse> {
-        let proof = ExecutionFetcher::new(self.rpc_url.clone(), self.network_id)
+        let proof = self
+            .client
             .fetch_account_proof(address, block_number)
             .await?;
         Ok(proof)
     }
 
+    /// Fetches account proofs for `(block_number, address)` pairs, batching the RPC requests
+    ///
+    /// The proofs are returned in the order of `accounts`. See [`Self::account`].
+    pub async fn accounts(
+        &self,
+        accounts: &[(u64, Address)],
+    ) -> SdkResult<Vec<EIP1186AccountProofResponse>> {
+        let queries: Vec<ProofQuery> = accounts
+            .iter()
+            .map(|(block_number, address)| ProofQuery {
+                address: *address,
+                block_number: *block_number,
+                slot_keys: Vec::new(),
+            })
+            .collect();
+        self.proofs(&queries).await
+    }
+
+    /// Fetches the `eth_getProof` responses of `queries`, batching the RPC requests
+    ///
+    /// The proofs are returned in the order of `queries`.
+    pub(crate) async fn proofs(
+        &self,
+        queries: &[ProofQuery],
+    ) -> SdkResult<Vec<EIP1186AccountProofResponse>> {
+        self.client.fetch_proofs(queries).await
+    }
+
+    /// Fetches the bytecode of `(block_number, address)` pairs, batching the RPC requests
+    ///
+    /// The bytecode is returned in the order of `accounts`.
+    pub(crate) async fn codes(&self, accounts: &[(u64, Address)]) -> SdkResult<Vec<Bytes>> {
+        self.client.fetch_codes(accounts).await
+    }
+
     /// Fetches a transaction proof for a specific transaction hash
     ///
     /// Returns the transaction data along with a Merkle proof that can verify the transaction
@@ -147,9 +192,7 @@ impl ExecutionChainFetcher {
     ///
     /// A transaction proof containing the transaction and its Merkle proof
     pub async fn tx_proof(&self, tx_hash: FixedBytes<32>) -> SdkResult<TxProof> {
-        let proof = ExecutionFetcher::new(self.rpc_url.clone(), self.network_id)
-            .fetch_tx_proof(tx_hash)
-            .await?;
+        let proof = self.client.fetch_tx_proof(tx_hash).await?;
         Ok(proof)
     }
 
@@ -158,9 +201,7 @@ impl ExecutionChainFetcher {
     /// Returns the receipt data together with a Merkle proof that can be verified against the
     /// header's receipts root once the header itself has been decommitted and verified.
     pub async fn receipt_proof(&self, tx_hash: FixedBytes<32>) -> SdkResult<ReceiptProof> {
-        let proof = ExecutionFetcher::new(self.rpc_url.clone(), self.network_id)
-            .fetch_receipt_proof(tx_hash)
-            .await?;
+        let proof = self.client.fetch_receipt_proof(tx_hash).await?;
         Ok(proof)
     }
 
@@ -185,37 +226,44 @@ impl ExecutionChainFetcher {
         _hashing_function: HashingFunction,
         _bankai_block_number: u64,
     ) -> SdkResult<StorageSlotProof> {
-        let proof = ExecutionFetcher::new(self.rpc_url.clone(), self.network_id)
+        let proof = self
+            .client
             .fetch_storage_slot_proof(address, block_number, slot_keys)
             .await?;
 
         let header = self.header_only(block_number).await?;
 
-        let slots: Vec<StorageSlotEntry> = proof
-            .storage_proof
-            .into_iter()
-            .map(|s| StorageSlotEntry {
-                slot_key: s.key.as_b256().into(),
-                slot_value: s.value,
-                storage_mpt_proof: s.proof,
-            })
-            .collect();
+        Ok(proof_storage_slots(
+            proof,
+            self.network_id,
+            block_number,
+            header.state_root,
+        ))
+    }
 
-        let account_state: AlloyAccount = AlloyAccount {
-            balance: proof.balance,
-            nonce: proof.nonce,
-            code_hash: proof.code_hash,
-            storage_root: proof.storage_hash,
-        };
+    /// Fetches contract bytecode together with the account proof that commits its code hash.
+    ///
+    /// Calls `eth_getProof` and `eth_getCode` at the same block. The bytecode can be verified
+    /// by hashing it against the account's code hash once the account proof is verified.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_number` - The block number to query
+    /// * `address` - The contract address
+    pub async fn code_proof(&self, block_number: u64, address: Address) -> SdkResult<CodeProof> {
+        let proof = self
+            .client
+            .fetch_account_proof(address, block_number)
+            .await?;
+        let code = self.client.fetch_code(address, block_number).await?;
+        let header = self.header_only(block_number).await?;
 
-        Ok(StorageSlotProof {
-            account: account_state,
-            address,
-            network_id: self.network_id,
+        Ok(proof_code(
+            proof,
+            code,
+            self.network_id,
             block_number,
-            state_root: header.state_root,
-            account_mpt_proof: proof.account_proof,
-            slots,
-        })
+            header.state_root,
+        ))
     }
 }
@@ -1,3 +1,70 @@
+use alloy_primitives::{Bytes, FixedBytes};
+use alloy_rpc_types_eth::{Account as AlloyAccount, EIP1186AccountProofResponse};
+use bankai_types::inputs::evm::execution::{
+    AccountProof, CodeProof, StorageSlotEntry, StorageSlotProof,
+};
+
 pub mod beacon;
 pub mod execution;
 pub mod op_stack;
+pub(crate) mod ssz;
+
+/// Extracts the account state from an `eth_getProof` response.
+///
+/// Absent accounts are normalized to the empty account so exclusion proofs verify.
+pub(crate) fn proof_account(proof: &EIP1186AccountProofResponse) -> AlloyAccount {
+    AccountProof::normalize_account(AlloyAccount {
+        balance: proof.balance,
+        nonce: proof.nonce,
+        code_hash: proof.code_hash,
+        storage_root: proof.storage_hash,
+    })
+}
+
+/// Builds a storage slot proof from an `eth_getProof` response with storage keys.
+pub(crate) fn proof_storage_slots(
+    proof: EIP1186AccountProofResponse,
+    network_id: u64,
+    block_number: u64,
+    state_root: FixedBytes<32>,
+) -> StorageSlotProof {
+    let account = proof_account(&proof);
+    let slots = proof
+        .storage_proof
+        .into_iter()
+        .map(|slot| StorageSlotEntry {
+            slot_key: slot.key.as_b256().into(),
+            slot_value: slot.value,
+            storage_mpt_proof: slot.proof,
+        })
+        .collect();
+
+    StorageSlotProof {
+        account,
+        address: proof.address,
+        network_id,
+        block_number,
+        state_root,
+        account_mpt_proof: proof.account_proof,
+        slots,
+    }
+}
+
+/// Builds a code proof from an `eth_getProof` response and the bytecode at the same block.
+pub(crate) fn proof_code(
+    proof: EIP1186AccountProofResponse,
+    code: Bytes,
+    network_id: u64,
+    block_number: u64,
+    state_root: FixedBytes<32>,
+) -> CodeProof {
+    CodeProof {
+        account: proof_account(&proof),
+        address: proof.address,
+        network_id,
+        block_number,
+        state_root,
+        account_mpt_proof: proof.account_proof,
+        code,
+    }
+}
@@ -1,14 +1,16 @@
-use alloy_primitives::{Address, FixedBytes, U256};
-use alloy_rpc_types_eth::{Account as AlloyAccount, EIP1186AccountProofResponse};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
 use bankai_types::api::ethereum::BankaiBlockFilterDto;
 use bankai_types::api::op_stack::{OpChainSnapshotSummaryDto, OpStackLightClientProofRequestDto};
 use bankai_types::common::HashingFunction;
 use bankai_types::inputs::evm::{
-    execution::{ReceiptProof, StorageSlotEntry, StorageSlotProof, TxProof},
+    execution::{CodeProof, ReceiptProof, StorageSlotProof, TxProof},
     op_stack::OpStackHeaderProof,
 };
 
 use crate::errors::{SdkError, SdkResult};
+use crate::fetch::clients::execution_client::ProofQuery;
+use crate::fetch::evm::{proof_code, proof_storage_slots};
 use crate::fetch::{api::ApiClient, clients::op_stack_client::OpStackFetcher};
 
 /// Fetches OP Stack data and proof material for one configured chain.
@@ -33,6 +35,11 @@ impl OpStackChainFetcher {
         &self.chain_name
     }
 
+    /// Returns the configured OP RPC endpoint URL.
+    pub(crate) fn rpc_url(&self) -> &str {
+        self.op_stack_client.rpc_url()
+    }
+
     /// Fetches an OP Stack header proof by block number.
     pub async fn header(
         &self,
@@ -62,6 +69,14 @@ impl OpStackChainFetcher {
         self.op_stack_client.fetch_header(block_number).await
     }
 
+    /// Fetches the raw execution headers of `block_numbers` with batched RPC requests.
+    pub async fn headers_only(
+        &self,
+        block_numbers: &[u64],
+    ) -> SdkResult<Vec<alloy_rpc_types_eth::Header>> {
+        self.op_stack_client.fetch_headers(block_numbers).await
+    }
+
     /// Fetches the raw execution header by hash from the configured OP RPC.
     pub async fn header_only_by_hash(
         &self,
@@ -81,6 +96,19 @@ impl OpStackChainFetcher {
             .await
     }
 
+    /// Fetches the `eth_getProof` responses of `queries` with batched RPC requests.
+    pub(crate) async fn proofs(
+        &self,
+        queries: &[ProofQuery],
+    ) -> SdkResult<Vec<EIP1186AccountProofResponse>> {
+        self.op_stack_client.fetch_proofs(queries).await
+    }
+
+    /// Fetches the bytecode of `(block_number, address)` pairs with batched RPC requests.
+    pub(crate) async fn codes(&self, accounts: &[(u64, Address)]) -> SdkResult<Vec<Bytes>> {
+        self.op_stack_client.fetch_codes(accounts).await
+    }
+
     /// Fetches a transaction proof from the configured OP RPC.
     pub async fn tx_proof(&self, tx_hash: FixedBytes<32>) -> SdkResult<TxProof> {
         let network_id = self.chain_id().await?;
@@ -111,32 +139,34 @@ impl OpStackChainFetcher {
         let header = self.header_only(block_number).await?;
         let network_id = self.chain_id().await?;
 
-        let slots = proof
-            .storage_proof
-            .into_iter()
-            .map(|slot| StorageSlotEntry {
-                slot_key: slot.key.as_b256().into(),
-                slot_value: slot.value,
-                storage_mpt_proof: slot.proof,
-            })
-            .collect();
-
-        let account = AlloyAccount {
-            balance: proof.balance,
-            nonce: proof.nonce,
-            code_hash: proof.code_hash,
-            storage_root: proof.storage_hash,
-        };
+        Ok(proof_storage_slots(
+            proof,
+            network_id,
+            block_number,
+            header.state_root,
+        ))
+    }
+
+    /// Fetches contract bytecode with its account proof from the configured OP RPC.
+    pub async fn code_proof(&self, block_number: u64, address: Address) -> SdkResult<CodeProof> {
+        let proof = self
+            .op_stack_client
+            .fetch_account_proof(address, block_number)
+            .await?;
+        let code = self
+            .op_stack_client
+            .fetch_code(address, block_number)
+            .await?;
+        let header = self.header_only(block_number).await?;
+        let network_id = self.chain_id().await?;
 
-        Ok(StorageSlotProof {
-            account,
-            address,
+        Ok(proof_code(
+            proof,
+            code,
             network_id,
             block_number,
-            state_root: header.state_root,
-            account_mpt_proof: proof.account_proof,
-            slots,
-        })
+            header.state_root,
+        ))
     }
 
     /// Returns the configured OP chain ID from the RPC.
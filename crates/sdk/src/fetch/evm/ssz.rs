@@ -0,0 +1,873 @@
+//! Schema-driven SSZ decoding and hash tree roots for beacon API payloads.
+//!
+//! Beacon nodes only serve whole SSZ objects, so proofs are built by splitting the serialized
+//! object along its schema and merkleizing every field. Limits follow the mainnet preset,
+//! which Sepolia also uses.
+
+use alloy_primitives::FixedBytes;
+use bankai_core::ssz::{self, BYTES_PER_CHUNK};
+use bankai_types::inputs::evm::beacon::BeaconFork;
+use bankai_types::results::evm::beacon::Validator;
+
+use crate::errors::{SdkError, SdkResult};
+
+const OFFSET_SIZE: usize = 4;
+
+/// SSZ type description.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Ssz {
+    /// Little-endian unsigned integer of the given byte width.
+    Uint(usize),
+    Bool,
+    ByteVector(usize),
+    ByteList(u64),
+    Bitvector(usize),
+    Bitlist(u64),
+    Vector(&'static Ssz, u64),
+    List(&'static Ssz, u64),
+    Container(&'static [Ssz]),
+}
+
+impl Ssz {
+    fn is_basic(&self) -> bool {
+        matches!(self, Ssz::Uint(_) | Ssz::Bool)
+    }
+
+    /// Serialized size of fixed-size types, `None` for variable-size types.
+    fn fixed_size(&self) -> Option<usize> {
+        match self {
+            Ssz::Uint(size) => Some(*size),
+            Ssz::Bool => Some(1),
+            Ssz::ByteVector(size) => Some(*size),
+            Ssz::Bitvector(bits) => Some(bits.div_ceil(8)),
+            Ssz::ByteList(_) | Ssz::Bitlist(_) | Ssz::List(_, _) => None,
+            Ssz::Vector(element, length) => element
+                .fixed_size()
+                .map(|size| size * usize::try_from(*length).unwrap_or(usize::MAX)),
+            Ssz::Container(fields) => fields.iter().map(Ssz::fixed_size).sum(),
+        }
+    }
+
+    /// Serialized size of this type when embedded in a container or sequence.
+    fn embedded_size(&self) -> usize {
+        self.fixed_size().unwrap_or(OFFSET_SIZE)
+    }
+
+    /// Returns the field types of a container.
+    pub(crate) fn fields(&self) -> SdkResult<&'static [Ssz]> {
+        match self {
+            Ssz::Container(fields) => Ok(fields),
+            _ => Err(invalid("expected a container")),
+        }
+    }
+}
+
+const U64: Ssz = Ssz::Uint(8);
+const U256: Ssz = Ssz::Uint(32);
+const BYTES20: Ssz = Ssz::ByteVector(20);
+const BYTES32: Ssz = Ssz::ByteVector(32);
+const BYTES48: Ssz = Ssz::ByteVector(48);
+const BYTES96: Ssz = Ssz::ByteVector(96);
+
+const CHECKPOINT: Ssz = Ssz::Container(&[U64, BYTES32]);
+const ETH1_DATA: Ssz = Ssz::Container(&[BYTES32, U64, BYTES32]);
+const BEACON_BLOCK_HEADER: Ssz = Ssz::Container(&[U64, U64, BYTES32, BYTES32, BYTES32]);
+const SIGNED_BEACON_BLOCK_HEADER: Ssz = Ssz::Container(&[BEACON_BLOCK_HEADER, BYTES96]);
+const PROPOSER_SLASHING: Ssz =
+    Ssz::Container(&[SIGNED_BEACON_BLOCK_HEADER, SIGNED_BEACON_BLOCK_HEADER]);
+const ATTESTATION_DATA: Ssz = Ssz::Container(&[U64, U64, BYTES32, CHECKPOINT, CHECKPOINT]);
+const DEPOSIT_DATA: Ssz = Ssz::Container(&[BYTES48, BYTES32, U64, BYTES96]);
+const DEPOSIT: Ssz = Ssz::Container(&[Ssz::Vector(&BYTES32, 33), DEPOSIT_DATA]);
+const SIGNED_VOLUNTARY_EXIT: Ssz = Ssz::Container(&[Ssz::Container(&[U64, U64]), BYTES96]);
+const SYNC_AGGREGATE: Ssz = Ssz::Container(&[Ssz::Bitvector(512), BYTES96]);
+const SIGNED_BLS_TO_EXECUTION_CHANGE: Ssz =
+    Ssz::Container(&[Ssz::Container(&[U64, BYTES48, BYTES20]), BYTES96]);
+const WITHDRAWAL: Ssz = Ssz::Container(&[U64, U64, BYTES20, U64]);
+
+/// `ExecutionPayload` from Deneb onwards.
+const EXECUTION_PAYLOAD: Ssz = Ssz::Container(&[
+    BYTES32,                                     // parent_hash
+    BYTES20,                                     // fee_recipient
+    BYTES32,                                     // state_root
+    BYTES32,                                     // receipts_root
+    Ssz::ByteVector(256),                        // logs_bloom
+    BYTES32,                                     // prev_randao
+    U64,                                         // block_number
+    U64,                                         // gas_limit
+    U64,                                         // gas_used
+    U64,                                         // timestamp
+    Ssz::ByteList(32),                           // extra_data
+    U256,                                        // base_fee_per_gas
+    BYTES32,                                     // block_hash
+    Ssz::List(&Ssz::ByteList(1 << 30), 1 << 20), // transactions
+    Ssz::List(&WITHDRAWAL, 16),                  // withdrawals
+    U64,                                         // blob_gas_used
+    U64,                                         // excess_blob_gas
+]);
+
+const INDEXED_ATTESTATION: Ssz =
+    Ssz::Container(&[Ssz::List(&U64, 2048), ATTESTATION_DATA, BYTES96]);
+const ATTESTER_SLASHING: Ssz = Ssz::Container(&[INDEXED_ATTESTATION, INDEXED_ATTESTATION]);
+const ATTESTATION: Ssz = Ssz::Container(&[Ssz::Bitlist(2048), ATTESTATION_DATA, BYTES96]);
+
+const BEACON_BLOCK_BODY_DENEB: Ssz = Ssz::Container(&[
+    BYTES96,                                        // randao_reveal
+    ETH1_DATA,                                      // eth1_data
+    BYTES32,                                        // graffiti
+    Ssz::List(&PROPOSER_SLASHING, 16),              // proposer_slashings
+    Ssz::List(&ATTESTER_SLASHING, 2),               // attester_slashings
+    Ssz::List(&ATTESTATION, 128),                   // attestations
+    Ssz::List(&DEPOSIT, 16),                        // deposits
+    Ssz::List(&SIGNED_VOLUNTARY_EXIT, 16),          // voluntary_exits
+    SYNC_AGGREGATE,                                 // sync_aggregate
+    EXECUTION_PAYLOAD,                              // execution_payload
+    Ssz::List(&SIGNED_BLS_TO_EXECUTION_CHANGE, 16), // bls_to_execution_changes
+    Ssz::List(&BYTES48, 4096),                      // blob_kzg_commitments
+]);
+
+const INDEXED_ATTESTATION_ELECTRA: Ssz =
+    Ssz::Container(&[Ssz::List(&U64, 131_072), ATTESTATION_DATA, BYTES96]);
+const ATTESTER_SLASHING_ELECTRA: Ssz =
+    Ssz::Container(&[INDEXED_ATTESTATION_ELECTRA, INDEXED_ATTESTATION_ELECTRA]);
+const ATTESTATION_ELECTRA: Ssz = Ssz::Container(&[
+    Ssz::Bitlist(131_072),
+    ATTESTATION_DATA,
+    BYTES96,
+    Ssz::Bitvector(64),
+]);
+const DEPOSIT_REQUEST: Ssz = Ssz::Container(&[BYTES48, BYTES32, U64, BYTES96, U64]);
+const WITHDRAWAL_REQUEST: Ssz = Ssz::Container(&[BYTES20, BYTES48, U64]);
+const CONSOLIDATION_REQUEST: Ssz = Ssz::Container(&[BYTES20, BYTES48, BYTES48]);
+const EXECUTION_REQUESTS: Ssz = Ssz::Container(&[
+    Ssz::List(&DEPOSIT_REQUEST, 8192),
+    Ssz::List(&WITHDRAWAL_REQUEST, 16),
+    Ssz::List(&CONSOLIDATION_REQUEST, 2),
+]);
+
+/// `BeaconBlockBody` from Electra onwards (unchanged in Fulu).
+const BEACON_BLOCK_BODY_ELECTRA: Ssz = Ssz::Container(&[
+    BYTES96,                                        // randao_reveal
+    ETH1_DATA,                                      // eth1_data
+    BYTES32,                                        // graffiti
+    Ssz::List(&PROPOSER_SLASHING, 16),              // proposer_slashings
+    Ssz::List(&ATTESTER_SLASHING_ELECTRA, 1),       // attester_slashings
+    Ssz::List(&ATTESTATION_ELECTRA, 8),             // attestations
+    Ssz::List(&DEPOSIT, 16),                        // deposits
+    Ssz::List(&SIGNED_VOLUNTARY_EXIT, 16),          // voluntary_exits
+    SYNC_AGGREGATE,                                 // sync_aggregate
+    EXECUTION_PAYLOAD,                              // execution_payload
+    Ssz::List(&SIGNED_BLS_TO_EXECUTION_CHANGE, 16), // bls_to_execution_changes
+    Ssz::List(&BYTES48, 4096),                      // blob_kzg_commitments
+    EXECUTION_REQUESTS,                             // execution_requests
+]);
+
+const SIGNED_BEACON_BLOCK_DENEB: Ssz = Ssz::Container(&[
+    Ssz::Container(&[U64, U64, BYTES32, BYTES32, BEACON_BLOCK_BODY_DENEB]),
+    BYTES96,
+]);
+const SIGNED_BEACON_BLOCK_ELECTRA: Ssz = Ssz::Container(&[
+    Ssz::Container(&[U64, U64, BYTES32, BYTES32, BEACON_BLOCK_BODY_ELECTRA]),
+    BYTES96,
+]);
+
+/// Path from a `SignedBeaconBlock` to its `BeaconBlockBody`.
+pub(crate) const BLOCK_BODY_PATH: [usize; 2] = [0, 4];
+
+const U8: Ssz = Ssz::Uint(1);
+const BYTES4: Ssz = Ssz::ByteVector(4);
+const FORK: Ssz = Ssz::Container(&[BYTES4, BYTES4, U64]);
+const VALIDATOR: Ssz = Ssz::Container(&[BYTES48, BYTES32, U64, Ssz::Bool, U64, U64, U64, U64]);
+const SYNC_COMMITTEE: Ssz = Ssz::Container(&[Ssz::Vector(&BYTES48, 512), BYTES48]);
+const HISTORICAL_SUMMARY: Ssz = Ssz::Container(&[BYTES32, BYTES32]);
+const PENDING_DEPOSIT: Ssz = Ssz::Container(&[BYTES48, BYTES32, U64, BYTES96, U64]);
+const PENDING_PARTIAL_WITHDRAWAL: Ssz = Ssz::Container(&[U64, U64, U64]);
+const PENDING_CONSOLIDATION: Ssz = Ssz::Container(&[U64, U64]);
+
+/// `ExecutionPayloadHeader` from Deneb onwards.
+const EXECUTION_PAYLOAD_HEADER: Ssz = Ssz::Container(&[
+    BYTES32,              // parent_hash
+    BYTES20,              // fee_recipient
+    BYTES32,              // state_root
+    BYTES32,              // receipts_root
+    Ssz::ByteVector(256), // logs_bloom
+    BYTES32,              // prev_randao
+    U64,                  // block_number
+    U64,                  // gas_limit
+    U64,                  // gas_used
+    U64,                  // timestamp
+    Ssz::ByteList(32),    // extra_data
+    U256,                 // base_fee_per_gas
+    BYTES32,              // block_hash
+    BYTES32,              // transactions_root
+    BYTES32,              // withdrawals_root
+    U64,                  // blob_gas_used
+    U64,                  // excess_blob_gas
+]);
+
+const BEACON_STATE_DENEB: Ssz = Ssz::Container(&[
+    U64,                                     // genesis_time
+    BYTES32,                                 // genesis_validators_root
+    U64,                                     // slot
+    FORK,                                    // fork
+    BEACON_BLOCK_HEADER,                     // latest_block_header
+    Ssz::Vector(&BYTES32, 8192),             // block_roots
+    Ssz::Vector(&BYTES32, 8192),             // state_roots
+    Ssz::List(&BYTES32, 1 << 24),            // historical_roots
+    ETH1_DATA,                               // eth1_data
+    Ssz::List(&ETH1_DATA, 2048),             // eth1_data_votes
+    U64,                                     // eth1_deposit_index
+    Ssz::List(&VALIDATOR, 1 << 40),          // validators
+    Ssz::List(&U64, 1 << 40),                // balances
+    Ssz::Vector(&BYTES32, 65_536),           // randao_mixes
+    Ssz::Vector(&U64, 8192),                 // slashings
+    Ssz::List(&U8, 1 << 40),                 // previous_epoch_participation
+    Ssz::List(&U8, 1 << 40),                 // current_epoch_participation
+    Ssz::Bitvector(4),                       // justification_bits
+    CHECKPOINT,                              // previous_justified_checkpoint
+    CHECKPOINT,                              // current_justified_checkpoint
+    CHECKPOINT,                              // finalized_checkpoint
+    Ssz::List(&U64, 1 << 40),                // inactivity_scores
+    SYNC_COMMITTEE,                          // current_sync_committee
+    SYNC_COMMITTEE,                          // next_sync_committee
+    EXECUTION_PAYLOAD_HEADER,                // latest_execution_payload_header
+    U64,                                     // next_withdrawal_index
+    U64,                                     // next_withdrawal_validator_index
+    Ssz::List(&HISTORICAL_SUMMARY, 1 << 24), // historical_summaries
+]);
+
+/// Electra `BeaconState`: the Deneb fields followed by the Electra additions.
+const BEACON_STATE_ELECTRA: Ssz = Ssz::Container(&[
+    U64,
+    BYTES32,
+    U64,
+    FORK,
+    BEACON_BLOCK_HEADER,
+    Ssz::Vector(&BYTES32, 8192),
+    Ssz::Vector(&BYTES32, 8192),
+    Ssz::List(&BYTES32, 1 << 24),
+    ETH1_DATA,
+    Ssz::List(&ETH1_DATA, 2048),
+    U64,
+    Ssz::List(&VALIDATOR, 1 << 40),
+    Ssz::List(&U64, 1 << 40),
+    Ssz::Vector(&BYTES32, 65_536),
+    Ssz::Vector(&U64, 8192),
+    Ssz::List(&U8, 1 << 40),
+    Ssz::List(&U8, 1 << 40),
+    Ssz::Bitvector(4),
+    CHECKPOINT,
+    CHECKPOINT,
+    CHECKPOINT,
+    Ssz::List(&U64, 1 << 40),
+    SYNC_COMMITTEE,
+    SYNC_COMMITTEE,
+    EXECUTION_PAYLOAD_HEADER,
+    U64,
+    U64,
+    Ssz::List(&HISTORICAL_SUMMARY, 1 << 24),
+    U64,                                             // deposit_requests_start_index
+    U64,                                             // deposit_balance_to_consume
+    U64,                                             // exit_balance_to_consume
+    U64,                                             // earliest_exit_epoch
+    U64,                                             // consolidation_balance_to_consume
+    U64,                                             // earliest_consolidation_epoch
+    Ssz::List(&PENDING_DEPOSIT, 1 << 27),            // pending_deposits
+    Ssz::List(&PENDING_PARTIAL_WITHDRAWAL, 1 << 27), // pending_partial_withdrawals
+    Ssz::List(&PENDING_CONSOLIDATION, 1 << 18),      // pending_consolidations
+]);
+
+/// Fulu `BeaconState`: the Electra fields followed by `proposer_lookahead`.
+const BEACON_STATE_FULU: Ssz = Ssz::Container(&[
+    U64,
+    BYTES32,
+    U64,
+    FORK,
+    BEACON_BLOCK_HEADER,
+    Ssz::Vector(&BYTES32, 8192),
+    Ssz::Vector(&BYTES32, 8192),
+    Ssz::List(&BYTES32, 1 << 24),
+    ETH1_DATA,
+    Ssz::List(&ETH1_DATA, 2048),
+    U64,
+    Ssz::List(&VALIDATOR, 1 << 40),
+    Ssz::List(&U64, 1 << 40),
+    Ssz::Vector(&BYTES32, 65_536),
+    Ssz::Vector(&U64, 8192),
+    Ssz::List(&U8, 1 << 40),
+    Ssz::List(&U8, 1 << 40),
+    Ssz::Bitvector(4),
+    CHECKPOINT,
+    CHECKPOINT,
+    CHECKPOINT,
+    Ssz::List(&U64, 1 << 40),
+    SYNC_COMMITTEE,
+    SYNC_COMMITTEE,
+    EXECUTION_PAYLOAD_HEADER,
+    U64,
+    U64,
+    Ssz::List(&HISTORICAL_SUMMARY, 1 << 24),
+    U64,
+    U64,
+    U64,
+    U64,
+    U64,
+    U64,
+    Ssz::List(&PENDING_DEPOSIT, 1 << 27),
+    Ssz::List(&PENDING_PARTIAL_WITHDRAWAL, 1 << 27),
+    Ssz::List(&PENDING_CONSOLIDATION, 1 << 18),
+    Ssz::Vector(&U64, 64), // proposer_lookahead
+]);
+
+/// Parses the `Eth-Consensus-Version` reported by the beacon API.
+pub(crate) fn parse_fork(version: &str) -> SdkResult<BeaconFork> {
+    match version.to_ascii_lowercase().as_str() {
+        "deneb" => Ok(BeaconFork::Deneb),
+        "electra" => Ok(BeaconFork::Electra),
+        "fulu" => Ok(BeaconFork::Fulu),
+        other => Err(SdkError::Beacon(format!(
+            "unsupported consensus fork: {other}"
+        ))),
+    }
+}
+
+/// Returns the `SignedBeaconBlock` schema of `fork`.
+pub(crate) fn signed_block_schema(fork: BeaconFork) -> &'static Ssz {
+    match fork {
+        BeaconFork::Deneb => &SIGNED_BEACON_BLOCK_DENEB,
+        BeaconFork::Electra | BeaconFork::Fulu => &SIGNED_BEACON_BLOCK_ELECTRA,
+    }
+}
+
+/// Returns the `BeaconState` schema of `fork`.
+pub(crate) fn beacon_state_schema(fork: BeaconFork) -> &'static Ssz {
+    match fork {
+        BeaconFork::Deneb => &BEACON_STATE_DENEB,
+        BeaconFork::Electra => &BEACON_STATE_ELECTRA,
+        BeaconFork::Fulu => &BEACON_STATE_FULU,
+    }
+}
+
+/// Decodes a serialized `Validator` record.
+pub(crate) fn decode_validator(bytes: &[u8]) -> SdkResult<Validator> {
+    expect_size(&VALIDATOR, bytes)?;
+    let u64_at = |offset: usize| {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&bytes[offset..offset + 8]);
+        u64::from_le_bytes(value)
+    };
+    let slashed = match bytes[88] {
+        0 => false,
+        1 => true,
+        _ => return Err(invalid("validator has an invalid slashed flag")),
+    };
+
+    Ok(Validator {
+        pubkey: FixedBytes::from_slice(&bytes[..48]),
+        withdrawal_credentials: FixedBytes::from_slice(&bytes[48..80]),
+        effective_balance: u64_at(80),
+        slashed,
+        activation_eligibility_epoch: u64_at(89),
+        activation_epoch: u64_at(97),
+        exit_epoch: u64_at(105),
+        withdrawable_epoch: u64_at(113),
+    })
+}
+
+/// Field roots of a serialized container, keeping list leaves for element proofs.
+pub(crate) struct ContainerTree<'a> {
+    fields: &'static [Ssz],
+    items: Vec<&'a [u8]>,
+    roots: Vec<FixedBytes<32>>,
+    lists: Vec<Option<(Vec<FixedBytes<32>>, u64)>>,
+}
+
+impl<'a> ContainerTree<'a> {
+    /// Splits and merkleizes every field of a serialized container of type `ty`.
+    pub(crate) fn new(ty: &'static Ssz, bytes: &'a [u8]) -> SdkResult<Self> {
+        let fields = ty.fields()?;
+        let items = container_fields(fields, bytes)?;
+        let mut roots = Vec::with_capacity(fields.len());
+        let mut lists = Vec::with_capacity(fields.len());
+
+        for (field, item) in fields.iter().zip(&items) {
+            match field {
+                Ssz::List(element, length) => {
+                    let (leaves, count) = sequence_leaves(element, item)?;
+                    if count > *length {
+                        return Err(invalid("sequence exceeds its limit"));
+                    }
+                    let root = ssz::merkleize(&leaves, sequence_limit(element, *length));
+                    roots.push(ssz::mix_in_length(root, count));
+                    lists.push(Some((leaves, count)));
+                }
+                _ => {
+                    roots.push(hash_tree_root(field, item)?);
+                    lists.push(None);
+                }
+            }
+        }
+
+        Ok(Self {
+            fields,
+            items,
+            roots,
+            lists,
+        })
+    }
+
+    /// Returns the hash tree root of the container.
+    pub(crate) fn root(&self) -> FixedBytes<32> {
+        ssz::merkleize(&self.roots, self.fields.len() as u64)
+    }
+
+    /// Returns the serialized bytes of an element of a list field with fixed-size elements.
+    pub(crate) fn list_item(&self, field_index: usize, index: u64) -> SdkResult<&'a [u8]> {
+        let (Some(Ssz::List(element, _)), Some(item)) =
+            (self.fields.get(field_index), self.items.get(field_index))
+        else {
+            return Err(invalid("field is not a list"));
+        };
+        let size = element
+            .fixed_size()
+            .ok_or_else(|| invalid("list elements are variable-size"))?;
+        let start = usize::try_from(index)
+            .ok()
+            .and_then(|index| index.checked_mul(size))
+            .ok_or_else(|| invalid("list index out of range"))?;
+        item.get(start..start.saturating_add(size))
+            .ok_or_else(|| invalid("list index out of range"))
+    }
+
+    /// Returns a leaf of a list field and its branch up to the container root.
+    ///
+    /// `leaf_index` counts chunks for lists of basic types. The branch includes the list
+    /// length chunk between the list data tree and the container tree.
+    pub(crate) fn list_leaf_proof(
+        &self,
+        field_index: usize,
+        leaf_index: u64,
+    ) -> SdkResult<(FixedBytes<32>, Vec<FixedBytes<32>>)> {
+        let (Some(Ssz::List(element, length)), Some(Some((leaves, count)))) =
+            (self.fields.get(field_index), self.lists.get(field_index))
+        else {
+            return Err(invalid("field is not a list"));
+        };
+        let leaf = *leaves
+            .get(leaf_index as usize)
+            .ok_or_else(|| invalid("list index out of range"))?;
+
+        let mut branch = ssz::generate_branch(leaves, sequence_limit(element, *length), leaf_index);
+        branch.push(ssz::u64_leaf(*count));
+        branch.extend(ssz::generate_branch(
+            &self.roots,
+            self.fields.len() as u64,
+            field_index as u64,
+        ));
+        Ok((leaf, branch))
+    }
+}
+
+/// Returns the hash tree root of a serialized SSZ value.
+pub(crate) fn hash_tree_root(ty: &Ssz, bytes: &[u8]) -> SdkResult<FixedBytes<32>> {
+    match ty {
+        Ssz::Uint(_) | Ssz::Bool => {
+            expect_size(ty, bytes)?;
+            Ok(ssz::pack(bytes)[0])
+        }
+        Ssz::ByteVector(size) => {
+            expect_size(ty, bytes)?;
+            Ok(ssz::merkleize(&ssz::pack(bytes), chunk_count(*size as u64)))
+        }
+        Ssz::ByteList(max) => {
+            if bytes.len() as u64 > *max {
+                return Err(invalid("byte list exceeds its limit"));
+            }
+            let root = ssz::merkleize(&ssz::pack(bytes), chunk_count(*max));
+            Ok(ssz::mix_in_length(root, bytes.len() as u64))
+        }
+        Ssz::Bitvector(bits) => {
+            expect_size(ty, bytes)?;
+            Ok(ssz::merkleize(
+                &ssz::pack(bytes),
+                (*bits as u64).div_ceil(256),
+            ))
+        }
+        Ssz::Bitlist(max) => {
+            let (data, length) = split_bitlist(bytes)?;
+            if length > *max {
+                return Err(invalid("bitlist exceeds its limit"));
+            }
+            let root = ssz::merkleize(&ssz::pack(&data), max.div_ceil(256));
+            Ok(ssz::mix_in_length(root, length))
+        }
+        Ssz::Vector(element, length) => {
+            let (root, count) = sequence_root(element, *length, bytes)?;
+            if count != *length {
+                return Err(invalid("vector length mismatch"));
+            }
+            Ok(root)
+        }
+        Ssz::List(element, max) => {
+            let (root, count) = sequence_root(element, *max, bytes)?;
+            Ok(ssz::mix_in_length(root, count))
+        }
+        Ssz::Container(fields) => Ok(ssz::merkleize(
+            &field_roots(fields, bytes)?,
+            fields.len() as u64,
+        )),
+    }
+}
+
+/// Descends into nested container fields along `path`, returning the type and bytes reached.
+pub(crate) fn descend<'a>(
+    mut ty: &'static Ssz,
+    mut bytes: &'a [u8],
+    path: &[usize],
+) -> SdkResult<(&'static Ssz, &'a [u8])> {
+    for index in path {
+        let fields = ty.fields()?;
+        let field = fields
+            .get(*index)
+            .ok_or_else(|| invalid("field index out of range"))?;
+        bytes = container_fields(fields, bytes)?[*index];
+        ty = field;
+    }
+    Ok((ty, bytes))
+}
+
+/// Returns the hash tree roots of every field of a serialized container.
+pub(crate) fn field_roots(fields: &[Ssz], bytes: &[u8]) -> SdkResult<Vec<FixedBytes<32>>> {
+    container_fields(fields, bytes)?
+        .into_iter()
+        .zip(fields)
+        .map(|(field, ty)| hash_tree_root(ty, field))
+        .collect()
+}
+
+/// Splits a serialized container into the serialized bytes of each field.
+pub(crate) fn container_fields<'a>(fields: &[Ssz], bytes: &'a [u8]) -> SdkResult<Vec<&'a [u8]>> {
+    let mut fixed = Vec::with_capacity(fields.len());
+    let mut offsets = Vec::new();
+    let mut position = 0;
+
+    for (index, field) in fields.iter().enumerate() {
+        let size = field.embedded_size();
+        let end = position + size;
+        let slot = bytes
+            .get(position..end)
+            .ok_or_else(|| invalid("container is truncated"))?;
+        match field.fixed_size() {
+            Some(_) => fixed.push(Some(slot)),
+            None => {
+                fixed.push(None);
+                offsets.push((index, read_offset(slot)?));
+            }
+        }
+        position = end;
+    }
+
+    if offsets.is_empty() && position != bytes.len() {
+        return Err(invalid("container has trailing bytes"));
+    }
+    if let Some((_, first)) = offsets.first() {
+        if *first != position {
+            return Err(invalid("container has an invalid first offset"));
+        }
+    }
+
+    let mut result: Vec<&[u8]> = fixed.into_iter().map(|slot| slot.unwrap_or(&[])).collect();
+    for (i, (index, start)) in offsets.iter().enumerate() {
+        let end = offsets
+            .get(i + 1)
+            .map(|(_, offset)| *offset)
+            .unwrap_or(bytes.len());
+        result[*index] = bytes
+            .get(*start..end)
+            .ok_or_else(|| invalid("container has an invalid offset"))?;
+    }
+
+    Ok(result)
+}
+
+/// Splits a serialized list or vector of composite elements into its elements.
+pub(crate) fn sequence_items<'a>(element: &Ssz, bytes: &'a [u8]) -> SdkResult<Vec<&'a [u8]>> {
+    if let Some(size) = element.fixed_size() {
+        if size == 0 || bytes.len() % size != 0 {
+            return Err(invalid(
+                "sequence length is not a multiple of its element size",
+            ));
+        }
+        return Ok(bytes.chunks(size).collect());
+    }
+
+    if bytes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let first = read_offset(bytes.get(..OFFSET_SIZE).unwrap_or_default())?;
+    if first % OFFSET_SIZE != 0 || first == 0 {
+        return Err(invalid("sequence has an invalid first offset"));
+    }
+    let count = first / OFFSET_SIZE;
+    let offsets = (0..count)
+        .map(|i| {
+            bytes
+                .get(i * OFFSET_SIZE..(i + 1) * OFFSET_SIZE)
+                .ok_or_else(|| invalid("sequence is truncated"))
+                .and_then(read_offset)
+        })
+        .collect::<SdkResult<Vec<_>>>()?;
+
+    offsets
+        .iter()
+        .enumerate()
+        .map(|(i, start)| {
+            let end = offsets.get(i + 1).copied().unwrap_or(bytes.len());
+            bytes
+                .get(*start..end)
+                .ok_or_else(|| invalid("sequence has an invalid offset"))
+        })
+        .collect()
+}
+
+/// Returns the leaves of a sequence: packed chunks for basic elements, element roots otherwise.
+pub(crate) fn sequence_leaves(
+    element: &Ssz,
+    bytes: &[u8],
+) -> SdkResult<(Vec<FixedBytes<32>>, u64)> {
+    if element.is_basic() {
+        let size = element.embedded_size();
+        if bytes.len() % size != 0 {
+            return Err(invalid(
+                "sequence length is not a multiple of its element size",
+            ));
+        }
+        return Ok((ssz::pack(bytes), (bytes.len() / size) as u64));
+    }
+
+    let items = sequence_items(element, bytes)?;
+    let count = items.len() as u64;
+    let roots = items
+        .into_iter()
+        .map(|item| hash_tree_root(element, item))
+        .collect::<SdkResult<Vec<_>>>()?;
+    Ok((roots, count))
+}
+
+/// Returns the chunk limit of a sequence of `length` elements.
+pub(crate) fn sequence_limit(element: &Ssz, length: u64) -> u64 {
+    if element.is_basic() {
+        chunk_count(length * element.embedded_size() as u64)
+    } else {
+        length
+    }
+}
+
+fn sequence_root(element: &Ssz, length: u64, bytes: &[u8]) -> SdkResult<(FixedBytes<32>, u64)> {
+    let (leaves, count) = sequence_leaves(element, bytes)?;
+    if count > length {
+        return Err(invalid("sequence exceeds its limit"));
+    }
+    Ok((
+        ssz::merkleize(&leaves, sequence_limit(element, length)),
+        count,
+    ))
+}
+
+fn split_bitlist(bytes: &[u8]) -> SdkResult<(Vec<u8>, u64)> {
+    let last = *bytes
+        .last()
+        .filter(|byte| **byte != 0)
+        .ok_or_else(|| invalid("bitlist is missing its length bit"))?;
+    let top_bit = 7 - last.leading_zeros() as usize;
+    let length = (bytes.len() - 1) * 8 + top_bit;
+
+    let mut data = bytes.to_vec();
+    let last_index = data.len() - 1;
+    data[last_index] &= !(1 << top_bit);
+    data.truncate(length.div_ceil(8));
+    Ok((data, length as u64))
+}
+
+fn read_offset(bytes: &[u8]) -> SdkResult<usize> {
+    let bytes: [u8; OFFSET_SIZE] = bytes
+        .try_into()
+        .map_err(|_| invalid("offset is truncated"))?;
+    Ok(u32::from_le_bytes(bytes) as usize)
+}
+
+fn expect_size(ty: &Ssz, bytes: &[u8]) -> SdkResult<()> {
+    if Some(bytes.len()) != ty.fixed_size() {
+        return Err(invalid("fixed-size value has an invalid length"));
+    }
+    Ok(())
+}
+
+fn chunk_count(bytes: u64) -> u64 {
+    bytes.div_ceil(BYTES_PER_CHUNK as u64)
+}
+
+fn invalid(message: &str) -> SdkError {
+    SdkError::Beacon(format!("invalid SSZ: {message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bankai_types::results::evm::beacon::BeaconHeader;
+    use tree_hash::TreeHash;
+
+    const VARIABLE: Ssz = Ssz::Container(&[U64, Ssz::List(&U64, 16), Ssz::ByteList(64)]);
+
+    #[test]
+    fn container_root_matches_tree_hash() {
+        let header = BeaconHeader {
+            slot: 12,
+            proposer_index: 3,
+            parent_root: FixedBytes::from([1u8; 32]),
+            state_root: FixedBytes::from([2u8; 32]),
+            body_root: FixedBytes::from([3u8; 32]),
+        };
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&header.slot.to_le_bytes());
+        bytes.extend_from_slice(&header.proposer_index.to_le_bytes());
+        bytes.extend_from_slice(header.parent_root.as_slice());
+        bytes.extend_from_slice(header.state_root.as_slice());
+        bytes.extend_from_slice(header.body_root.as_slice());
+
+        assert_eq!(
+            hash_tree_root(&BEACON_BLOCK_HEADER, &bytes).unwrap(),
+            header.tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn splits_variable_size_fields() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&7u64.to_le_bytes());
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&32u32.to_le_bytes());
+        bytes.extend_from_slice(&1u64.to_le_bytes());
+        bytes.extend_from_slice(&2u64.to_le_bytes());
+        bytes.extend_from_slice(&[0xab, 0xcd]);
+
+        let Ssz::Container(fields) = VARIABLE else {
+            unreachable!()
+        };
+        let split = container_fields(fields, &bytes).unwrap();
+
+        assert_eq!(split[0], 7u64.to_le_bytes());
+        assert_eq!(split[1].len(), 16);
+        assert_eq!(split[2], [0xab, 0xcd]);
+    }
+
+    #[test]
+    fn descends_into_block_body() {
+        let body_fields = BEACON_BLOCK_BODY_DENEB.fields().unwrap();
+        assert_eq!(body_fields.len(), 12);
+
+        // SignedBeaconBlock { message: BeaconBlock { .., body: [0xaa] }, signature }
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&100u32.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 96]);
+        bytes.extend_from_slice(&[0u8; 16 + 64]);
+        bytes.extend_from_slice(&84u32.to_le_bytes());
+        bytes.push(0xaa);
+
+        let (ty, body) =
+            descend(BeaconFork::Deneb.signed_block(), &bytes, &BLOCK_BODY_PATH).unwrap();
+
+        assert_eq!(ty.fields().unwrap().len(), 12);
+        assert_eq!(body, [0xaa]);
+    }
+
+    #[test]
+    fn decodes_validator_record() {
+        let validator = Validator {
+            pubkey: FixedBytes::from([0x0a; 48]),
+            withdrawal_credentials: FixedBytes::from([0x01; 32]),
+            effective_balance: 32_000_000_000,
+            slashed: true,
+            activation_eligibility_epoch: 3,
+            activation_epoch: 4,
+            exit_epoch: 5,
+            withdrawable_epoch: 6,
+        };
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(validator.pubkey.as_slice());
+        bytes.extend_from_slice(validator.withdrawal_credentials.as_slice());
+        bytes.extend_from_slice(&validator.effective_balance.to_le_bytes());
+        bytes.push(1);
+        for epoch in [3u64, 4, 5, 6] {
+            bytes.extend_from_slice(&epoch.to_le_bytes());
+        }
+
+        assert_eq!(decode_validator(&bytes).unwrap(), validator);
+        assert_eq!(
+            hash_tree_root(&VALIDATOR, &bytes).unwrap(),
+            validator.tree_hash_root()
+        );
+    }
+
+    #[test]
+    fn list_leaf_proof_reaches_container_root() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&7u64.to_le_bytes());
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&40u32.to_le_bytes());
+        for value in [1u64, 2, 3] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&[0xab, 0xcd]);
+
+        let tree = ContainerTree::new(&VARIABLE, &bytes).unwrap();
+        assert_eq!(tree.root(), hash_tree_root(&VARIABLE, &bytes).unwrap());
+
+        let (leaf, branch) = tree.list_leaf_proof(1, 0).unwrap();
+        // 2 list levels, the length chunk and 2 container levels
+        assert_eq!(branch.len(), 5);
+        assert_eq!(ssz::leaf_u64(&branch[2]), Some(3));
+        assert!(ssz::verify_branch(leaf, &branch, 1 << 3, tree.root()));
+        assert_eq!(tree.list_item(1, 2).unwrap(), 3u64.to_le_bytes());
+        assert!(tree.list_item(1, 3).is_err());
+        assert!(tree.list_item(1, u64::MAX).is_err());
+    }
+
+    #[test]
+    fn parses_supported_forks() {
+        assert_eq!(
+            BeaconFork::from_version("Electra").unwrap(),
+            BeaconFork::Electra
+        );
+        assert!(BeaconFork::from_version("capella").is_err());
+    }
+
+    #[test]
+    fn basic_list_root_mixes_in_length() {
+        let values = [1u64, 2, 3];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let expected = ssz::mix_in_length(ssz::merkleize(&ssz::pack(&bytes), 4), 3);
+
+        assert_eq!(
+            hash_tree_root(&Ssz::List(&U64, 16), &bytes).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn bitlist_strips_length_bit() {
+        let (data, length) = split_bitlist(&[0b0000_0101, 0b0000_0010]).unwrap();
+
+        assert_eq!(length, 9);
+        assert_eq!(data, vec![0b0000_0101, 0]);
+        assert!(split_bitlist(&[0b0000_0101, 0]).is_err());
+    }
+
+    #[test]
+    fn fixed_size_composite_vector_splits_evenly() {
+        let bytes = [0u8; 80];
+        let items = sequence_items(&CHECKPOINT, &bytes).unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert!(sequence_items(&CHECKPOINT, &bytes[..79]).is_err());
+    }
+}
@@ -1,22 +1,32 @@
 use alloy_primitives::{Address, FixedBytes, U256};
+use bankai_types::inputs::evm::execution::LogFilter;
 
 #[derive(Debug, Default)]
 pub struct EvmProofsRequest {
     pub execution_header: Vec<ExecutionHeaderProofRequest>,
     pub beacon_header: Vec<BeaconHeaderProofRequest>,
+    pub execution_header_range: Vec<ExecutionHeaderRangeRequest>,
+    pub beacon_header_range: Vec<BeaconHeaderRangeRequest>,
     pub account: Vec<AccountProofRequest>,
     pub storage_slot: Vec<StorageSlotProofRequest>,
     pub tx_proof: Vec<TxProofRequest>,
     pub receipt_proof: Vec<ReceiptProofRequest>,
+    pub code: Vec<CodeProofRequest>,
+    pub execution_payload: Vec<ExecutionPayloadProofRequest>,
+    pub validator: Vec<BeaconValidatorProofRequest>,
+    pub logs: Vec<LogsProofRequest>,
 }
 
 #[derive(Debug, Default)]
 pub struct OpStackProofsRequest {
     pub header: Vec<OpStackHeaderProofRequest>,
+    pub header_range: Vec<OpStackHeaderRangeRequest>,
     pub account: Vec<OpStackAccountProofRequest>,
     pub storage_slot: Vec<OpStackStorageSlotProofRequest>,
     pub tx_proof: Vec<OpStackTxProofRequest>,
     pub receipt_proof: Vec<OpStackReceiptProofRequest>,
+    pub code: Vec<OpStackCodeProofRequest>,
+    pub logs: Vec<OpStackLogsProofRequest>,
 }
 
 #[derive(Debug)]
@@ -31,6 +41,35 @@ pub struct BeaconHeaderProofRequest {
     pub slot: u64,
 }
 
+/// Inclusive range of execution blocks.
+#[derive(Debug)]
+pub struct ExecutionHeaderRangeRequest {
+    pub network_id: u64,
+    pub start: u64,
+    pub end: u64,
+}
+
+/// Inclusive range of beacon slots.
+#[derive(Debug)]
+pub struct BeaconHeaderRangeRequest {
+    pub network_id: u64,
+    pub start: u64,
+    pub end: u64,
+}
+
+#[derive(Debug)]
+pub struct ExecutionPayloadProofRequest {
+    pub network_id: u64,
+    pub slot: u64,
+}
+
+#[derive(Debug)]
+pub struct BeaconValidatorProofRequest {
+    pub network_id: u64,
+    pub slot: u64,
+    pub validator_index: u64,
+}
+
 #[derive(Debug)]
 pub struct AccountProofRequest {
     pub network_id: u64,
@@ -44,6 +83,15 @@ pub struct StorageSlotProofRequest {
     pub block_number: u64,
     pub address: Address,
     pub slot_keys: Vec<U256>,
+    /// Slots of `bytes`/`string` values whose data slots are resolved at fetch time.
+    pub bytes_slots: Vec<U256>,
+}
+
+#[derive(Debug)]
+pub struct CodeProofRequest {
+    pub network_id: u64,
+    pub block_number: u64,
+    pub address: Address,
 }
 
 #[derive(Debug)]
@@ -58,6 +106,13 @@ pub struct ReceiptProofRequest {
     pub tx_hash: FixedBytes<32>,
 }
 
+#[derive(Debug)]
+pub struct LogsProofRequest {
+    pub network_id: u64,
+    pub tx_hash: FixedBytes<32>,
+    pub filter: LogFilter,
+}
+
 #[derive(Debug)]
 pub struct OpStackHeaderProofRequest {
     pub chain_name: String,
@@ -65,6 +120,14 @@ pub struct OpStackHeaderProofRequest {
     pub header_hash: Option<FixedBytes<32>>,
 }
 
+/// Inclusive range of OP Stack blocks.
+#[derive(Debug)]
+pub struct OpStackHeaderRangeRequest {
+    pub chain_name: String,
+    pub start: u64,
+    pub end: u64,
+}
+
 #[derive(Debug)]
 pub struct OpStackAccountProofRequest {
     pub chain_name: String,
@@ -78,6 +141,15 @@ pub struct OpStackStorageSlotProofRequest {
     pub block_number: u64,
     pub address: Address,
     pub slot_keys: Vec<U256>,
+    /// Slots of `bytes`/`string` values whose data slots are resolved at fetch time.
+    pub bytes_slots: Vec<U256>,
+}
+
+#[derive(Debug)]
+pub struct OpStackCodeProofRequest {
+    pub chain_name: String,
+    pub block_number: u64,
+    pub address: Address,
 }
 
 #[derive(Debug)]
@@ -91,3 +163,10 @@ pub struct OpStackReceiptProofRequest {
     pub chain_name: String,
     pub tx_hash: FixedBytes<32>,
 }
+
+#[derive(Debug)]
+pub struct OpStackLogsProofRequest {
+    pub chain_name: String,
+    pub tx_hash: FixedBytes<32>,
+    pub filter: LogFilter,
+}
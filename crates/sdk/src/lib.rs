@@ -8,9 +8,11 @@
 //! 4. verify the bundle with `bankai-verify`
 //!
 //! ```no_run
-//! use alloy_primitives::Address;
+//! use alloy_primitives::{Address, FixedBytes};
 //! use bankai_sdk::{Bankai, HashingFunction, Network};
-//! use bankai_verify::verify_batch_proof;
+//! use bankai_verify::{verify_batch_proof, VerifierConfig};
+//!
+//! const BANKAI_PROGRAM_HASH: FixedBytes<32> = FixedBytes::ZERO;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -29,7 +31,8 @@
 //!         .execute()
 //!         .await?;
 //!
-//!     let results = verify_batch_proof(proof_bundle)?;
+//!     let config = VerifierConfig::new(Network::Sepolia).with_program_hash(BANKAI_PROGRAM_HASH);
+//!     let results = verify_batch_proof(proof_bundle, &config)?;
 //!     println!("Verified block {}", results.evm.execution_header[0].number);
 //!     println!(
 //!         "Verified balance {} at block {}",
@@ -55,47 +58,11 @@ use std::collections::BTreeMap;
 
 // Re-export common types from bankai_types
 pub use crate::fetch::evm::op_stack::OpStackChainFetcher;
-pub use bankai_types::common::HashingFunction;
+pub use bankai_types::common::{Finality, HashingFunction, Network};
 pub use bankai_types::inputs::ProofBundle;
 
 pub use crate::fetch::api::blocks::parse_block_proof_payload;
 
-// ============================================================================
-// Network Configuration
-// ============================================================================
-
-/// Supported blockchain networks
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Network {
-    /// Ethereum Sepolia testnet
-    Sepolia,
-    /// Local API
-    Local,
-}
-
-impl Network {
-    /// Returns the API base URL for this network
-    pub fn api_url(&self) -> &'static str {
-        match self {
-            Network::Sepolia => "https://sepolia.api.bankai.xyz",
-            Network::Local => "http://localhost:8080",
-        }
-    }
-
-    /// Returns the beacon chain network ID (always 0)
-    pub const fn beacon_network_id(&self) -> u64 {
-        0
-    }
-
-    /// Returns the default execution layer chain ID for this network.
-    pub const fn execution_network_id(&self) -> u64 {
-        match self {
-            Network::Sepolia => 11155111,
-            Network::Local => 11155111,
-        }
-    }
-}
-
 // ============================================================================
 // Public API Components
 // ============================================================================
@@ -108,7 +75,8 @@ pub use crate::fetch::api::ApiClient;
 /// All requests in a batch share the same Bankai block and block proof.
 pub mod batch {
 
-    pub use crate::fetch::batch::ProofBatchBuilder;
+    pub use crate::fetch::batch::{ProofBatchBuilder, DEFAULT_RPC_CONCURRENCY};
+    pub use bankai_types::inputs::evm::execution::LogFilter;
 }
 
 // Keep fetch module private (internal implementation details)
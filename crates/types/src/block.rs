@@ -5,6 +5,8 @@ use alloc::vec::Vec;
 use alloy_primitives::{keccak256, FixedBytes};
 use bankai_core::merkle::op_stack;
 
+use crate::common::Finality;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -237,6 +239,31 @@ impl BankaiBlock {
 
         FixedBytes::from_slice(keccak256(preimage).as_slice())
     }
+
+    /// Returns the finality of execution block `block_number` in this Bankai block.
+    pub fn execution_finality(&self, block_number: u64) -> Finality {
+        Finality::of(
+            block_number,
+            self.execution.justified_height,
+            self.execution.finalized_height,
+        )
+    }
+
+    /// Returns the finality of beacon `slot` in this Bankai block.
+    pub fn beacon_finality(&self, slot: u64) -> Finality {
+        Finality::of(
+            slot,
+            self.beacon.justified_height,
+            self.beacon.finalized_height,
+        )
+    }
+
+    /// Returns the finality of the OP Stack headers committed by `client`.
+    ///
+    /// OP Stack headers are as final as the L1 block that submitted the snapshot.
+    pub fn op_finality(&self, client: &OpChainClient) -> Finality {
+        self.execution_finality(client.l1_submission_block)
+    }
 }
 
 impl OpChainClient {
@@ -325,9 +352,10 @@ impl BankaiBlockHashOutput {
 #[cfg(test)]
 mod tests {
     use super::{
-        empty_op_chains_root, BankaiBlockFull, BeaconClient, ExecutionClient, IndexedOpChainClient,
-        OpChainClient, OpChainsCommitment,
+        empty_op_chains_root, BankaiBlock, BankaiBlockFull, BeaconClient, ExecutionClient,
+        IndexedOpChainClient, OpChainClient, OpChainsCommitment,
     };
+    use crate::common::Finality;
     use alloy_primitives::{hex::FromHex, keccak256, FixedBytes};
     use bankai_core::merkle::op_stack;
 
@@ -556,4 +584,37 @@ mod tests {
 
         assert_eq!(empty_op_chains_root(), expected);
     }
+
+    #[test]
+    fn classifies_header_finality_against_committed_heights() {
+        let block = BankaiBlock {
+            beacon: BeaconClient {
+                justified_height: 96,
+                finalized_height: 64,
+                ..Default::default()
+            },
+            execution: ExecutionClient {
+                justified_height: 120,
+                finalized_height: 100,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let op_client = |l1_submission_block| OpChainClient {
+            chain_id: 10,
+            block_number: 42,
+            header_hash: FixedBytes::ZERO,
+            l1_submission_block,
+            mmr_root_keccak: FixedBytes::ZERO,
+            mmr_root_poseidon: FixedBytes::ZERO,
+        };
+
+        assert_eq!(block.execution_finality(100), Finality::Finalized);
+        assert_eq!(block.execution_finality(101), Finality::Justified);
+        assert_eq!(block.execution_finality(121), Finality::Latest);
+        assert_eq!(block.beacon_finality(64), Finality::Finalized);
+        assert_eq!(block.beacon_finality(97), Finality::Latest);
+        assert_eq!(block.op_finality(&op_client(120)), Finality::Justified);
+        assert!(Finality::Finalized < Finality::Justified);
+    }
 }
@@ -28,3 +28,69 @@ pub enum HashingFunction {
     Keccak,
     Poseidon,
 }
+
+/// Supported Bankai networks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+pub enum Network {
+    /// Ethereum Sepolia testnet
+    Sepolia,
+    /// Local API
+    Local,
+}
+
+impl Network {
+    /// Returns the API base URL for this network
+    pub fn api_url(&self) -> &'static str {
+        match self {
+            Network::Sepolia => "https://sepolia.api.bankai.xyz",
+            Network::Local => "http://localhost:8080",
+        }
+    }
+
+    /// Returns the beacon chain network ID (always 0)
+    pub const fn beacon_network_id(&self) -> u64 {
+        0
+    }
+
+    /// Returns the default execution layer chain ID for this network.
+    pub const fn execution_network_id(&self) -> u64 {
+        match self {
+            Network::Sepolia => 11155111,
+            Network::Local => 11155111,
+        }
+    }
+}
+
+/// Finality of a header relative to the heights committed in a Bankai block.
+///
+/// Variants are ordered from most to least final, so `finality <= Finality::Justified` holds
+/// for justified and finalized headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+pub enum Finality {
+    /// At or below the finalized height
+    Finalized,
+    /// At or below the justified height
+    Justified,
+    /// Above the justified height
+    #[default]
+    Latest,
+}
+
+impl Finality {
+    /// Classifies `height` against a justified and a finalized height.
+    pub const fn of(height: u64, justified_height: u64, finalized_height: u64) -> Self {
+        if height <= finalized_height {
+            Self::Finalized
+        } else if height <= justified_height {
+            Self::Justified
+        } else {
+            Self::Latest
+        }
+    }
+}
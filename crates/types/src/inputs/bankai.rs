@@ -0,0 +1,114 @@
+//! Proofs for historical Bankai blocks committed in a newer block's Bankai MMR.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "api")]
+use alloy_primitives::hex::FromHex;
+use alloy_primitives::FixedBytes;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "api")]
+use crate::api::proofs::BankaiMmrProofDto;
+use crate::block::BankaiBlock;
+use crate::common::HashingFunction;
+
+/// Inclusion proof for a Bankai block hash in a reference block's Bankai MMR.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BankaiMmrProof {
+    /// Bankai block whose `bankai_mmr_root_*` commits the target block
+    pub reference_block_number: u64,
+    /// Bankai block number being proven
+    pub target_block_number: u64,
+    pub hashing_function: HashingFunction,
+    /// Keccak block hash of the target Bankai block
+    pub block_hash: FixedBytes<32>,
+    pub root: FixedBytes<32>,
+    pub elements_index: u64,
+    pub elements_count: u64,
+    pub path: Vec<FixedBytes<32>>,
+    pub peaks: Vec<FixedBytes<32>>,
+}
+
+/// A historical Bankai block together with its inclusion proof in a reference block's MMR.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct HistoricalBankaiBlockProof {
+    pub block: BankaiBlock,
+    pub mmr_proof: BankaiMmrProof,
+}
+
+#[cfg(feature = "api")]
+impl TryFrom<BankaiMmrProofDto> for BankaiMmrProof {
+    type Error = alloy_primitives::hex::FromHexError;
+
+    fn try_from(mmr_proof: BankaiMmrProofDto) -> Result<Self, Self::Error> {
+        Ok(BankaiMmrProof {
+            reference_block_number: mmr_proof.reference_block_number,
+            target_block_number: mmr_proof.target_block_number,
+            hashing_function: mmr_proof.hashing_function,
+            block_hash: FixedBytes::from_hex(mmr_proof.block_hash)?,
+            root: FixedBytes::from_hex(mmr_proof.root)?,
+            elements_index: mmr_proof.elements_index,
+            elements_count: mmr_proof.elements_count,
+            path: mmr_proof
+                .path
+                .iter()
+                .map(FixedBytes::from_hex)
+                .collect::<Result<Vec<_>, _>>()?,
+            peaks: mmr_proof
+                .peaks
+                .iter()
+                .map(FixedBytes::from_hex)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "api"))]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    #[test]
+    fn converts_bankai_mmr_proof_dto() {
+        let dto = BankaiMmrProofDto {
+            reference_block_number: 20,
+            target_block_number: 7,
+            hashing_function: HashingFunction::Poseidon,
+            block_hash: FixedBytes::<32>::from([1u8; 32]).to_string(),
+            root: FixedBytes::<32>::from([2u8; 32]).to_string(),
+            elements_index: 8,
+            elements_count: 20,
+            path: vec![FixedBytes::<32>::from([3u8; 32]).to_string()],
+            peaks: vec![FixedBytes::<32>::from([4u8; 32]).to_string()],
+        };
+
+        let proof = BankaiMmrProof::try_from(dto).expect("valid dto");
+        assert_eq!(proof.reference_block_number, 20);
+        assert_eq!(proof.target_block_number, 7);
+        assert_eq!(proof.block_hash, FixedBytes::from([1u8; 32]));
+        assert_eq!(proof.path, vec![FixedBytes::from([3u8; 32])]);
+        assert_eq!(proof.peaks, vec![FixedBytes::from([4u8; 32])]);
+    }
+
+    #[test]
+    fn rejects_invalid_hex_in_dto() {
+        let dto = BankaiMmrProofDto {
+            reference_block_number: 20,
+            target_block_number: 7,
+            hashing_function: HashingFunction::Keccak,
+            block_hash: "0xzz".to_string(),
+            root: FixedBytes::<32>::ZERO.to_string(),
+            elements_index: 1,
+            elements_count: 1,
+            path: vec![],
+            peaks: vec![],
+        };
+
+        assert!(BankaiMmrProof::try_from(dto).is_err());
+    }
+}
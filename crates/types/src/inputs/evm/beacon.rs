@@ -1,7 +1,13 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::FixedBytes;
 use alloy_rpc_types_beacon::header::HeaderResponse;
 use serde::{Deserialize, Serialize};
 
-use crate::inputs::evm::MmrProof;
+use crate::inputs::evm::{MmrMultiProof, MmrProof};
+use crate::results::evm::beacon::Validator;
 
 #[cfg_attr(feature = "std", derive(Debug, Clone))]
 #[derive(Serialize, Deserialize)]
@@ -9,3 +15,88 @@ pub struct BeaconHeaderProof {
     pub header: HeaderResponse,
     pub mmr_proof: MmrProof,
 }
+
+/// Beacon headers proven by one MMR proof, in the order of its leaves.
+#[cfg_attr(feature = "std", derive(Debug, Clone))]
+#[derive(Serialize, Deserialize)]
+pub struct BeaconHeaderBatchProof {
+    pub headers: Vec<HeaderResponse>,
+    pub mmr_proof: MmrMultiProof,
+}
+
+/// SSZ proof linking a beacon block body to its execution payload header.
+///
+/// `payload_fields` are the hash tree roots of the `ExecutionPayloadHeader` fields in SSZ
+/// order. `body_branch` proves the payload header root at the `execution_payload` field of
+/// the beacon block body committed by the header at `slot`.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ExecutionPayloadProof {
+    pub network_id: u64,
+    pub slot: u64,
+    pub payload_fields: Vec<FixedBytes<32>>,
+    pub body_branch: Vec<FixedBytes<32>>,
+}
+
+impl ExecutionPayloadProof {
+    /// Field index of `execution_payload` in the beacon block body (Bellatrix onwards).
+    pub const BODY_FIELD_INDEX: u64 = 9;
+    /// Depth of the beacon block body tree.
+    pub const BODY_TREE_DEPTH: usize = 4;
+    /// Maximum number of fields of the execution payload header tree.
+    pub const MAX_PAYLOAD_FIELDS: u64 = 32;
+}
+
+/// Consensus forks with a supported `BeaconState` layout.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BeaconFork {
+    Deneb,
+    Electra,
+    Fulu,
+}
+
+impl BeaconFork {
+    /// Depth of the `BeaconState` field tree.
+    pub fn state_tree_depth(self) -> usize {
+        match self {
+            // 28 fields
+            BeaconFork::Deneb => 5,
+            // 37 fields in Electra, 38 in Fulu
+            BeaconFork::Electra | BeaconFork::Fulu => 6,
+        }
+    }
+}
+
+/// SSZ proofs of a validator record and its balance against a beacon state root.
+///
+/// `validator_branch` proves the validator root in the `validators` list and
+/// `balance_branch` proves the `balances` chunk holding its balance (four balances per
+/// chunk). Both branches run from the leaf up to the state root of the header at `slot`,
+/// including the list length mixed into each list root.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BeaconValidatorProof {
+    pub network_id: u64,
+    pub slot: u64,
+    pub fork: BeaconFork,
+    pub validator_index: u64,
+    pub validator: Validator,
+    pub validator_branch: Vec<FixedBytes<32>>,
+    pub balance_chunk: FixedBytes<32>,
+    pub balance_branch: Vec<FixedBytes<32>>,
+}
+
+impl BeaconValidatorProof {
+    /// Field index of `validators` in the beacon state.
+    pub const VALIDATORS_FIELD_INDEX: u64 = 11;
+    /// Field index of `balances` in the beacon state.
+    pub const BALANCES_FIELD_INDEX: u64 = 12;
+    /// Depth of the `validators` data tree (`VALIDATOR_REGISTRY_LIMIT` is `2**40`).
+    pub const VALIDATORS_TREE_DEPTH: usize = 40;
+    /// Depth of the `balances` data tree (four `uint64` balances per chunk).
+    pub const BALANCES_TREE_DEPTH: usize = 38;
+    /// Number of balances packed into one chunk.
+    pub const BALANCES_PER_CHUNK: u64 = 4;
+}
@@ -2,12 +2,16 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
-use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+use alloy_primitives::{b256, Address, Bytes, FixedBytes, U256};
 use alloy_rpc_types_eth::{Account, Header as ExecutionHeader};
 use serde::{Deserialize, Serialize};
 
-use crate::inputs::evm::header_serde::{deserialize_execution_header, serialize_execution_header};
-use crate::inputs::evm::MmrProof;
+use crate::inputs::evm::header_serde::{
+    deserialize_execution_header, deserialize_execution_headers, serialize_execution_header,
+    serialize_execution_headers,
+};
+use crate::inputs::evm::{MmrMultiProof, MmrProof};
+pub use crate::results::evm::LogFilter;
 
 #[cfg_attr(feature = "std", derive(Debug))]
 #[derive(Clone, Serialize, Deserialize)]
@@ -20,6 +24,30 @@ pub struct ExecutionHeaderProof {
     pub mmr_proof: MmrProof,
 }
 
+/// Execution headers proven by one MMR proof, in the order of its leaves.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ExecutionHeaderBatchProof {
+    #[serde(
+        serialize_with = "serialize_execution_headers",
+        deserialize_with = "deserialize_execution_headers"
+    )]
+    pub headers: Vec<ExecutionHeader>,
+    pub mmr_proof: MmrMultiProof,
+}
+
+/// Keccak hash of empty code.
+const KECCAK_EMPTY: FixedBytes<32> =
+    b256!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+
+/// Root hash of an empty trie.
+const EMPTY_ROOT_HASH: FixedBytes<32> =
+    b256!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+
+/// Account state proof.
+///
+/// For an address that is not in the state trie (never used or self-destructed), `account`
+/// is [`AccountProof::empty_account`] and `mpt_proof` is an exclusion proof.
 #[cfg_attr(feature = "std", derive(Debug))]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct AccountProof {
@@ -31,6 +59,37 @@ pub struct AccountProof {
     pub mpt_proof: Vec<Bytes>,
 }
 
+impl AccountProof {
+    /// Account state reported for addresses that are not in the state trie.
+    pub const fn empty_account() -> Account {
+        Account {
+            nonce: 0,
+            balance: U256::ZERO,
+            storage_root: EMPTY_ROOT_HASH,
+            code_hash: KECCAK_EMPTY,
+        }
+    }
+
+    /// Normalizes an `eth_getProof` account for an address that may be absent.
+    ///
+    /// Some clients report zero hashes instead of the empty code hash and empty storage root
+    /// for missing accounts.
+    pub fn normalize_account(mut account: Account) -> Account {
+        if account.code_hash == FixedBytes::ZERO {
+            account.code_hash = KECCAK_EMPTY;
+        }
+        if account.storage_root == FixedBytes::ZERO {
+            account.storage_root = EMPTY_ROOT_HASH;
+        }
+        account
+    }
+
+    /// Returns `true` if `account` is the empty account, so the proof may be an exclusion proof.
+    pub fn is_empty_account(&self) -> bool {
+        self.account == Self::empty_account()
+    }
+}
+
 #[cfg_attr(feature = "std", derive(Debug))]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct TxProof {
@@ -53,6 +112,18 @@ pub struct ReceiptProof {
     pub encoded_receipt: Vec<u8>,
 }
 
+/// Receipt proof used to extract filtered logs.
+///
+/// The transaction at the same index is proven too, so every log carries a verified
+/// transaction hash.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LogProof {
+    pub tx: TxProof,
+    pub receipt: ReceiptProof,
+    pub filter: LogFilter,
+}
+
 #[cfg_attr(feature = "std", derive(Debug))]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct StorageSlotEntry {
@@ -72,3 +143,48 @@ pub struct StorageSlotProof {
     pub account_mpt_proof: Vec<Bytes>,
     pub slots: Vec<StorageSlotEntry>,
 }
+
+/// Contract bytecode with the account proof that commits its code hash.
+///
+/// `code` is verified by hashing it against `account.code_hash`, so an account without code
+/// (an EOA or an absent address) carries empty `code`.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CodeProof {
+    pub account: Account,
+    pub address: Address,
+    pub network_id: u64,
+    pub block_number: u64,
+    pub state_root: FixedBytes<32>,
+    pub account_mpt_proof: Vec<Bytes>,
+    pub code: Bytes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_zero_hashes_to_empty_account() {
+        let account = AccountProof::normalize_account(Account {
+            nonce: 0,
+            balance: U256::ZERO,
+            storage_root: FixedBytes::ZERO,
+            code_hash: FixedBytes::ZERO,
+        });
+
+        assert_eq!(account, AccountProof::empty_account());
+    }
+
+    #[test]
+    fn keeps_non_empty_account_hashes() {
+        let account = Account {
+            nonce: 1,
+            balance: U256::from(5u64),
+            storage_root: FixedBytes::from([1u8; 32]),
+            code_hash: FixedBytes::from([2u8; 32]),
+        };
+
+        assert_eq!(AccountProof::normalize_account(account), account);
+    }
+}
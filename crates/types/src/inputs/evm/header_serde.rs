@@ -1,4 +1,5 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 
 use alloy_rpc_types_eth::Header as ExecutionHeader;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -21,3 +22,24 @@ where
     let json_str = String::deserialize(deserializer)?;
     serde_json::from_str(&json_str).map_err(serde::de::Error::custom)
 }
+
+pub fn serialize_execution_headers<S>(
+    headers: &[ExecutionHeader],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let json_str = serde_json::to_string(headers).map_err(serde::ser::Error::custom)?;
+    json_str.serialize(serializer)
+}
+
+pub fn deserialize_execution_headers<'de, D>(
+    deserializer: D,
+) -> Result<Vec<ExecutionHeader>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let json_str = String::deserialize(deserializer)?;
+    serde_json::from_str(&json_str).map_err(serde::de::Error::custom)
+}
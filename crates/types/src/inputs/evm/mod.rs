@@ -1,5 +1,7 @@
 extern crate alloc;
 
+use alloc::collections::btree_map::{BTreeMap, Entry};
+use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 
 #[cfg(feature = "api")]
@@ -10,14 +12,24 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "api")]
 use crate::api::proofs::MmrProofDto;
 use crate::common::HashingFunction;
+use crate::inputs::evm::node_pool::{pool_all, resolve_all, NodePool};
 use crate::inputs::evm::{
-    beacon::BeaconHeaderProof,
-    execution::{AccountProof, ExecutionHeaderProof, ReceiptProof, StorageSlotProof, TxProof},
+    beacon::{
+        BeaconHeaderBatchProof, BeaconHeaderProof, BeaconValidatorProof, ExecutionPayloadProof,
+    },
+    execution::{
+        AccountProof, CodeProof, ExecutionHeaderBatchProof, ExecutionHeaderProof, LogProof,
+        ReceiptProof, StorageSlotProof, TxProof,
+    },
+};
+use crate::utils::mmr::{
+    child_positions, hash_to_leaf, hash_to_parent, parent_position, sibling_positions,
 };
 
 pub mod beacon;
 pub mod execution;
 pub(crate) mod header_serde;
+pub mod node_pool;
 pub mod op_stack;
 
 #[cfg_attr(feature = "std", derive(Debug))]
@@ -28,6 +40,16 @@ pub struct EvmProofs {
     #[serde(default)]
     pub beacon_header_proof: Vec<BeaconHeaderProof>,
     #[serde(default)]
+    pub execution_header_batch_proof: Vec<ExecutionHeaderBatchProof>,
+    #[serde(default)]
+    pub beacon_header_batch_proof: Vec<BeaconHeaderBatchProof>,
+    /// Contiguous execution header ranges, in ascending block order.
+    #[serde(default)]
+    pub execution_header_range_proof: Vec<ExecutionHeaderBatchProof>,
+    /// Beacon header ranges, in ascending slot order. Missed slots have no header.
+    #[serde(default)]
+    pub beacon_header_range_proof: Vec<BeaconHeaderBatchProof>,
+    #[serde(default)]
     pub account_proof: Vec<AccountProof>,
     #[serde(default)]
     pub storage_slot_proof: Vec<StorageSlotProof>,
@@ -35,24 +57,136 @@ pub struct EvmProofs {
     pub tx_proof: Vec<TxProof>,
     #[serde(default)]
     pub receipt_proof: Vec<ReceiptProof>,
+    #[serde(default)]
+    pub code_proof: Vec<CodeProof>,
+    #[serde(default)]
+    pub execution_payload_proof: Vec<ExecutionPayloadProof>,
+    #[serde(default)]
+    pub beacon_validator_proof: Vec<BeaconValidatorProof>,
+    #[serde(default)]
+    pub log_proof: Vec<LogProof>,
+    /// Shared MPT nodes. When set, the account, storage and code proofs hold node hashes.
+    #[serde(default)]
+    pub node_pool: Option<NodePool>,
 }
 
 impl EvmProofs {
     pub fn is_empty(&self) -> bool {
         self.execution_header_proof.is_empty()
             && self.beacon_header_proof.is_empty()
+            && self.execution_header_batch_proof.is_empty()
+            && self.beacon_header_batch_proof.is_empty()
+            && self.execution_header_range_proof.is_empty()
+            && self.beacon_header_range_proof.is_empty()
             && self.account_proof.is_empty()
             && self.storage_slot_proof.is_empty()
             && self.tx_proof.is_empty()
             && self.receipt_proof.is_empty()
+            && self.code_proof.is_empty()
+            && self.execution_payload_proof.is_empty()
+            && self.beacon_validator_proof.is_empty()
+            && self.log_proof.is_empty()
+    }
+
+    /// Moves the execution and beacon header proofs into one batch proof per MMR.
+    ///
+    /// Header proofs whose MMR proofs cannot be merged are kept as they are.
+    pub fn batch_header_proofs(&mut self) {
+        let (single, batches) = batch_header_proofs(
+            core::mem::take(&mut self.execution_header_proof),
+            |proof| &proof.mmr_proof,
+            |_, _| true,
+            |proofs, mmr_proof| ExecutionHeaderBatchProof {
+                headers: proofs.into_iter().map(|proof| proof.header).collect(),
+                mmr_proof,
+            },
+        );
+        self.execution_header_proof = single;
+        self.execution_header_batch_proof.extend(batches);
+
+        let (single, batches) = batch_header_proofs(
+            core::mem::take(&mut self.beacon_header_proof),
+            |proof| &proof.mmr_proof,
+            |_, _| true,
+            |proofs, mmr_proof| BeaconHeaderBatchProof {
+                headers: proofs.into_iter().map(|proof| proof.header).collect(),
+                mmr_proof,
+            },
+        );
+        self.beacon_header_proof = single;
+        self.beacon_header_batch_proof.extend(batches);
+    }
+
+    /// Moves the MPT nodes of account, storage and code proofs into [`EvmProofs::node_pool`].
+    ///
+    /// Does nothing if the proofs are already pooled.
+    pub fn pool_nodes(&mut self) {
+        if self.node_pool.is_some() {
+            return;
+        }
+        let pool = self.node_pool.insert(NodePool::default());
+        pool_all(&mut self.account_proof, pool);
+        pool_all(&mut self.storage_slot_proof, pool);
+        pool_all(&mut self.code_proof, pool);
+    }
+
+    /// Restores the pooled MPT nodes into their proofs and drops the pool.
+    ///
+    /// Returns `false`, leaving the proofs partially resolved, if a node is missing.
+    pub fn resolve_nodes(&mut self) -> bool {
+        let Some(pool) = self.node_pool.take() else {
+            return true;
+        };
+        resolve_all(&mut self.account_proof, &pool).is_some()
+            && resolve_all(&mut self.storage_slot_proof, &pool).is_some()
+            && resolve_all(&mut self.code_proof, &pool).is_some()
+    }
+}
+
+/// Groups header proofs by MMR and merges the MMR proofs of every group.
+///
+/// `same_batch` adds conditions on top of a shared MMR. Returns the proofs of groups that could
+/// not be merged, and the batches built by `batch`.
+pub(crate) fn batch_header_proofs<P, B>(
+    proofs: Vec<P>,
+    mmr_proof: impl Fn(&P) -> &MmrProof,
+    same_batch: impl Fn(&P, &P) -> bool,
+    batch: impl Fn(Vec<P>, MmrMultiProof) -> B,
+) -> (Vec<P>, Vec<B>) {
+    let mut groups: Vec<Vec<P>> = Vec::new();
+    for proof in proofs {
+        let group = groups.iter_mut().find(|group| {
+            let (first, other) = (mmr_proof(&group[0]), mmr_proof(&proof));
+            first.network_id == other.network_id
+                && first.hashing_function == other.hashing_function
+                && first.root == other.root
+                && first.elements_count == other.elements_count
+                && same_batch(&group[0], &proof)
+        });
+        match group {
+            Some(group) => group.push(proof),
+            None => groups.push(alloc::vec![proof]),
+        }
     }
+
+    let mut single = Vec::new();
+    let mut batches = Vec::new();
+    for group in groups {
+        let mmr_proofs: Vec<MmrProof> = group.iter().map(|p| mmr_proof(p).clone()).collect();
+        match MmrMultiProof::from_proofs(&mmr_proofs) {
+            Some(multi_proof) => batches.push(batch(group, multi_proof)),
+            None => single.extend(group),
+        }
+    }
+    (single, batches)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{EvmProofs, MmrProof};
+    use super::{EvmProofs, MmrMultiProof, MmrProof};
     use crate::common::HashingFunction;
     use crate::inputs::evm::execution::{ExecutionHeaderProof, StorageSlotEntry, StorageSlotProof};
+    use crate::utils::mmr::{hash_to_leaf, hash_to_parent};
     use alloy_primitives::{Address, Bytes, FixedBytes, U256};
     use alloy_rpc_types_eth::{Account, Header as ExecutionHeader};
 
@@ -107,10 +241,149 @@ mod tests {
 
         assert_eq!(decoded.execution_header_proof.len(), 1);
         assert!(decoded.beacon_header_proof.is_empty());
+        assert!(decoded.execution_header_batch_proof.is_empty());
+        assert!(decoded.beacon_header_batch_proof.is_empty());
+        assert!(decoded.execution_header_range_proof.is_empty());
+        assert!(decoded.beacon_header_range_proof.is_empty());
         assert!(decoded.account_proof.is_empty());
         assert_eq!(decoded.storage_slot_proof.len(), 1);
         assert!(decoded.tx_proof.is_empty());
         assert!(decoded.receipt_proof.is_empty());
+        assert!(decoded.code_proof.is_empty());
+        assert!(decoded.execution_payload_proof.is_empty());
+        assert!(decoded.beacon_validator_proof.is_empty());
+        assert!(decoded.log_proof.is_empty());
+        assert!(decoded.node_pool.is_none());
+    }
+
+    /// Proofs for the leaves of an MMR with 3 leaves at positions 1, 2 and 4.
+    fn three_leaf_proofs() -> Vec<MmrProof> {
+        let hashes = [1u8, 2, 3].map(|byte| FixedBytes::from([byte; 32]));
+        let leaf = |index: usize| hash_to_leaf(hashes[index], &HashingFunction::Keccak);
+        let peaks = vec![FixedBytes::from([9u8; 32]), leaf(2)];
+        [(1, vec![leaf(1)]), (2, vec![leaf(0)]), (4, vec![])]
+            .into_iter()
+            .zip(hashes)
+            .enumerate()
+            .map(
+                |(block_number, ((elements_index, path), header_hash))| MmrProof {
+                    network_id: 1,
+                    block_number: block_number as u64,
+                    hashing_function: HashingFunction::Keccak,
+                    header_hash,
+                    root: FixedBytes::from([8u8; 32]),
+                    elements_index,
+                    elements_count: 4,
+                    path,
+                    peaks: peaks.clone(),
+                },
+            )
+            .collect()
+    }
+
+    #[test]
+    fn mmr_multi_proof_hashes_merged_proofs_to_peaks() {
+        let proofs = three_leaf_proofs();
+        let leaf = |index: usize| hash_to_leaf(proofs[index].header_hash, &HashingFunction::Keccak);
+
+        let multi = MmrMultiProof::from_proofs(&proofs).unwrap();
+        assert_eq!(multi.leaves.len(), 3);
+        assert!(multi.nodes.is_empty());
+        assert_eq!(
+            multi.compute_peaks().unwrap(),
+            vec![
+                (
+                    3,
+                    hash_to_parent(leaf(0), leaf(1), &HashingFunction::Keccak)
+                ),
+                (4, leaf(2)),
+            ]
+        );
+
+        let single = MmrMultiProof::from_proofs(&proofs[..1]).unwrap();
+        assert_eq!(single.nodes.len(), 1);
+        assert_eq!(single.nodes[0].elements_index, 2);
+        assert_eq!(
+            single.compute_peaks().unwrap(),
+            vec![(
+                3,
+                hash_to_parent(leaf(0), leaf(1), &HashingFunction::Keccak)
+            )]
+        );
+    }
+
+    #[test]
+    fn mmr_multi_proof_rejects_inconsistent_proofs() {
+        assert!(MmrMultiProof::from_proofs(&[]).is_none());
+
+        let mut proofs = three_leaf_proofs();
+        proofs[2].peaks.pop();
+        assert!(MmrMultiProof::from_proofs(&proofs).is_none());
+
+        let mut proofs = three_leaf_proofs();
+        proofs[0].path.push(FixedBytes::ZERO);
+        assert!(MmrMultiProof::from_proofs(&proofs).is_none());
+
+        let mut multi = MmrMultiProof::from_proofs(&three_leaf_proofs()[..1]).unwrap();
+        multi.nodes.clear();
+        assert!(multi.compute_peaks().is_none());
+
+        let mut multi = MmrMultiProof::from_proofs(&three_leaf_proofs()).unwrap();
+        multi.leaves[1].elements_index = 3;
+        assert!(multi.compute_peaks().is_none());
+    }
+
+    #[test]
+    fn batches_header_proofs_per_mmr() {
+        let mut proofs = EvmProofs {
+            execution_header_proof: three_leaf_proofs()
+                .into_iter()
+                .map(|mmr_proof| ExecutionHeaderProof {
+                    header: ExecutionHeader::default(),
+                    mmr_proof,
+                })
+                .chain([sample_execution_header_proof()])
+                .collect(),
+            ..Default::default()
+        };
+
+        proofs.batch_header_proofs();
+
+        assert_eq!(proofs.execution_header_batch_proof.len(), 1);
+        let batch = &proofs.execution_header_batch_proof[0];
+        assert_eq!(batch.headers.len(), 3);
+        assert_eq!(batch.mmr_proof.leaves.len(), 3);
+        // The sample proof is against another root, at a position that is not a leaf
+        assert_eq!(proofs.execution_header_proof.len(), 1);
+        assert_eq!(
+            proofs.execution_header_proof[0].mmr_proof.root,
+            FixedBytes::from([2u8; 32])
+        );
+    }
+
+    #[test]
+    fn pooled_evm_proofs_bincode_roundtrip() {
+        let mut proofs = EvmProofs {
+            storage_slot_proof: vec![sample_storage_slot_proof(), sample_storage_slot_proof()],
+            ..Default::default()
+        };
+        proofs.pool_nodes();
+        proofs.pool_nodes();
+        assert_eq!(proofs.node_pool.as_ref().map(|pool| pool.len()), Some(2));
+
+        let bytes = bincode::serialize(&proofs).expect("failed to serialize EvmProofs");
+        let mut decoded: EvmProofs =
+            bincode::deserialize(&bytes).expect("failed to deserialize EvmProofs");
+
+        assert!(decoded.resolve_nodes());
+        assert!(decoded.node_pool.is_none());
+        for proof in &decoded.storage_slot_proof {
+            assert_eq!(proof.account_mpt_proof, vec![Bytes::from(vec![1u8, 2, 3])]);
+            assert_eq!(
+                proof.slots[0].storage_mpt_proof,
+                vec![Bytes::from(vec![4u8, 5, 6])]
+            );
+        }
     }
 }
 
@@ -154,3 +427,149 @@ pub struct MmrProof {
     pub path: Vec<FixedBytes<32>>,
     pub peaks: Vec<FixedBytes<32>>,
 }
+
+/// MMR proof for several headers committed in the same MMR.
+///
+/// Each path node and the peaks are stored once, however many leaves share them. Nodes that can
+/// be hashed from the proven leaves, the leaves themselves and their ancestors, are left out.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MmrMultiProof {
+    pub network_id: u64,
+    pub hashing_function: HashingFunction,
+    pub root: FixedBytes<32>,
+    pub elements_count: u64,
+    pub leaves: Vec<MmrLeaf>,
+    /// Path nodes of all leaves that are not derivable from the leaves, ordered by position.
+    pub nodes: Vec<MmrNode>,
+    pub peaks: Vec<FixedBytes<32>>,
+}
+
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MmrLeaf {
+    pub block_number: u64,
+    pub header_hash: FixedBytes<32>,
+    pub elements_index: u64,
+}
+
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MmrNode {
+    pub elements_index: u64,
+    pub hash: FixedBytes<32>,
+}
+
+impl MmrMultiProof {
+    /// Merges single-leaf proofs against the same MMR into one proof.
+    ///
+    /// Returns `None` if `proofs` is empty, if the proofs are against different MMRs, or if a
+    /// path does not match the position of its leaf or contradicts another path.
+    pub fn from_proofs(proofs: &[MmrProof]) -> Option<Self> {
+        let first = proofs.first()?;
+        let mut nodes = BTreeMap::new();
+        let mut leaves = Vec::with_capacity(proofs.len());
+        for proof in proofs {
+            if proof.network_id != first.network_id
+                || proof.hashing_function != first.hashing_function
+                || proof.root != first.root
+                || proof.elements_count != first.elements_count
+                || proof.peaks != first.peaks
+            {
+                return None;
+            }
+            let positions = sibling_positions(proof.elements_index, proof.elements_count)?;
+            if positions.len() != proof.path.len() {
+                return None;
+            }
+            for (position, hash) in positions.into_iter().zip(&proof.path) {
+                match nodes.entry(position) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(*hash);
+                    }
+                    Entry::Occupied(entry) if entry.get() != hash => return None,
+                    Entry::Occupied(_) => {}
+                }
+            }
+            leaves.push(MmrLeaf {
+                block_number: proof.block_number,
+                header_hash: proof.header_hash,
+                elements_index: proof.elements_index,
+            });
+        }
+
+        // Leaves and their ancestors are recomputed by the verifier
+        for leaf in &leaves {
+            let mut position = Some(leaf.elements_index);
+            while let Some(covered) = position {
+                nodes.remove(&covered);
+                position = parent_position(covered, first.elements_count);
+            }
+        }
+
+        Some(Self {
+            network_id: first.network_id,
+            hashing_function: first.hashing_function,
+            root: first.root,
+            elements_count: first.elements_count,
+            leaves,
+            nodes: nodes
+                .into_iter()
+                .map(|(elements_index, hash)| MmrNode {
+                    elements_index,
+                    hash,
+                })
+                .collect(),
+            peaks: first.peaks.clone(),
+        })
+    }
+
+    /// Hashes the proven leaves and the stored nodes up to their peaks, each node once.
+    ///
+    /// Returns the reached peaks as `(position, hash)` pairs ordered by position, or `None` if
+    /// a leaf position is invalid, two leaves at the same position disagree, or a node needed
+    /// to hash a parent is missing.
+    pub fn compute_peaks(&self) -> Option<Vec<(u64, FixedBytes<32>)>> {
+        let stored: BTreeMap<u64, FixedBytes<32>> = self
+            .nodes
+            .iter()
+            .map(|node| (node.elements_index, node.hash))
+            .collect();
+        let mut computed = BTreeMap::new();
+        for leaf in &self.leaves {
+            sibling_positions(leaf.elements_index, self.elements_count)?;
+            let hash = hash_to_leaf(leaf.header_hash, &self.hashing_function);
+            match computed.entry(leaf.elements_index) {
+                Entry::Vacant(entry) => {
+                    entry.insert(hash);
+                }
+                Entry::Occupied(entry) if *entry.get() != hash => return None,
+                Entry::Occupied(_) => {}
+            }
+        }
+
+        // Children have lower positions than their parent, so every node is hashed after both
+        // of its children
+        let mut pending: BTreeSet<u64> = computed.keys().copied().collect();
+        let mut peaks = Vec::new();
+        while let Some(position) = pending.pop_first() {
+            let hash = match computed.get(&position) {
+                Some(hash) => *hash,
+                None => {
+                    let (left, right) = child_positions(position)?;
+                    let node = |position: u64| computed.get(&position).or(stored.get(&position));
+                    let hash = hash_to_parent(*node(left)?, *node(right)?, &self.hashing_function);
+                    computed.insert(position, hash);
+                    hash
+                }
+            };
+            match parent_position(position, self.elements_count) {
+                Some(parent) => {
+                    pending.insert(parent);
+                }
+                None => peaks.push((position, hash)),
+            }
+        }
+        Some(peaks)
+    }
+}
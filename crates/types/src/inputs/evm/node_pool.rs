@@ -0,0 +1,228 @@
+//! Content-addressed storage of the MPT nodes shared by state proofs.
+//!
+//! Account, storage and code proofs against the same state root repeat the upper trie nodes
+//! for every request. A pooled bundle stores each node once in a [`NodePool`] and replaces the
+//! node lists of those proofs with the Keccak hashes of their nodes.
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use alloy_primitives::{keccak256, Bytes, FixedBytes};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::inputs::evm::execution::{AccountProof, CodeProof, StorageSlotProof};
+
+/// MPT nodes keyed by their Keccak hash.
+///
+/// Serialized as the list of nodes. Keys are recomputed on load, so a pool cannot map a hash
+/// to a node with another hash.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct NodePool {
+    nodes: BTreeMap<FixedBytes<32>, Bytes>,
+}
+
+impl NodePool {
+    /// Adds `node` and returns its hash.
+    pub fn insert(&mut self, node: Bytes) -> FixedBytes<32> {
+        let hash = keccak256(&node);
+        self.nodes.entry(hash).or_insert(node);
+        hash
+    }
+
+    /// Returns the node with `hash`.
+    pub fn get(&self, hash: &FixedBytes<32>) -> Option<&Bytes> {
+        self.nodes.get(hash)
+    }
+
+    /// Returns the number of distinct nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Moves `nodes` into the pool, leaving their hashes in place.
+    pub fn pool(&mut self, nodes: &mut [Bytes]) {
+        for node in nodes {
+            let hash = self.insert(core::mem::take(node));
+            *node = Bytes::copy_from_slice(hash.as_slice());
+        }
+    }
+
+    /// Returns the nodes referenced by a pooled node list.
+    ///
+    /// Returns `None` if a reference is not a 32-byte hash or is missing from the pool.
+    pub fn resolve(&self, refs: &[Bytes]) -> Option<Vec<Bytes>> {
+        refs.iter()
+            .map(|hash| {
+                let hash = FixedBytes::<32>::try_from(hash.as_ref()).ok()?;
+                self.get(&hash).cloned()
+            })
+            .collect()
+    }
+}
+
+impl Serialize for NodePool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.nodes.values())
+    }
+}
+
+impl<'de> Deserialize<'de> for NodePool {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut pool = Self::default();
+        for node in Vec::<Bytes>::deserialize(deserializer)? {
+            pool.insert(node);
+        }
+        Ok(pool)
+    }
+}
+
+/// State proofs whose MPT node lists can be stored in a [`NodePool`].
+pub trait PooledNodes: Sized {
+    /// Moves every node list into `pool`, leaving node hashes in place.
+    fn pool_nodes(&mut self, pool: &mut NodePool);
+
+    /// Returns a copy with every node list resolved from `pool`.
+    fn resolve_nodes(&self, pool: &NodePool) -> Option<Self>;
+}
+
+impl PooledNodes for AccountProof {
+    fn pool_nodes(&mut self, pool: &mut NodePool) {
+        pool.pool(&mut self.mpt_proof);
+    }
+
+    fn resolve_nodes(&self, pool: &NodePool) -> Option<Self> {
+        Some(Self {
+            mpt_proof: pool.resolve(&self.mpt_proof)?,
+            ..self.clone()
+        })
+    }
+}
+
+impl PooledNodes for StorageSlotProof {
+    fn pool_nodes(&mut self, pool: &mut NodePool) {
+        pool.pool(&mut self.account_mpt_proof);
+        for slot in &mut self.slots {
+            pool.pool(&mut slot.storage_mpt_proof);
+        }
+    }
+
+    fn resolve_nodes(&self, pool: &NodePool) -> Option<Self> {
+        let mut resolved = self.clone();
+        resolved.account_mpt_proof = pool.resolve(&self.account_mpt_proof)?;
+        for slot in &mut resolved.slots {
+            slot.storage_mpt_proof = pool.resolve(&slot.storage_mpt_proof)?;
+        }
+        Some(resolved)
+    }
+}
+
+impl PooledNodes for CodeProof {
+    fn pool_nodes(&mut self, pool: &mut NodePool) {
+        pool.pool(&mut self.account_mpt_proof);
+    }
+
+    fn resolve_nodes(&self, pool: &NodePool) -> Option<Self> {
+        Some(Self {
+            account_mpt_proof: pool.resolve(&self.account_mpt_proof)?,
+            ..self.clone()
+        })
+    }
+}
+
+/// Pools the node lists of every proof in `proofs`.
+pub(crate) fn pool_all<P: PooledNodes>(proofs: &mut [P], pool: &mut NodePool) {
+    for proof in proofs {
+        proof.pool_nodes(pool);
+    }
+}
+
+/// Resolves every proof in `proofs` from `pool` in place.
+pub(crate) fn resolve_all<P: PooledNodes>(proofs: &mut [P], pool: &NodePool) -> Option<()> {
+    for proof in proofs {
+        *proof = proof.resolve_nodes(pool)?;
+    }
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{Address, U256};
+    use alloy_rpc_types_eth::Account;
+
+    use super::*;
+    use crate::inputs::evm::execution::StorageSlotEntry;
+
+    fn node(byte: u8) -> Bytes {
+        Bytes::from(vec![byte; 40])
+    }
+
+    fn storage_proof(slots: u64) -> StorageSlotProof {
+        StorageSlotProof {
+            account: Account::default(),
+            address: Address::repeat_byte(0x11),
+            network_id: 1,
+            block_number: 42,
+            state_root: FixedBytes::ZERO,
+            account_mpt_proof: vec![node(1), node(2)],
+            slots: (0..slots)
+                .map(|slot| StorageSlotEntry {
+                    slot_key: U256::from(slot),
+                    slot_value: U256::from(slot),
+                    storage_mpt_proof: vec![node(3), node(4 + slot as u8)],
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn stores_shared_nodes_once_and_resolves_them() {
+        let original = storage_proof(3);
+        let mut pooled = original.clone();
+        let mut pool = NodePool::default();
+        pooled.pool_nodes(&mut pool);
+
+        assert_eq!(pool.len(), 6);
+        assert_eq!(
+            pooled.account_mpt_proof[0].as_ref(),
+            keccak256(node(1)).as_slice()
+        );
+
+        let resolved = pooled.resolve_nodes(&pool).unwrap();
+        assert_eq!(resolved.account_mpt_proof, original.account_mpt_proof);
+        for (resolved, original) in resolved.slots.iter().zip(&original.slots) {
+            assert_eq!(resolved.storage_mpt_proof, original.storage_mpt_proof);
+        }
+    }
+
+    #[test]
+    fn rejects_references_missing_from_pool() {
+        let mut proof = storage_proof(1);
+        let mut pool = NodePool::default();
+        proof.pool_nodes(&mut pool);
+
+        assert!(proof.resolve_nodes(&NodePool::default()).is_none());
+        assert!(pool.resolve(&[node(1)]).is_none());
+    }
+
+    #[test]
+    fn recomputes_hashes_on_load() {
+        let mut pool = NodePool::default();
+        let hash = pool.insert(node(1));
+        pool.insert(node(2));
+
+        let json = serde_json::to_string(&pool).unwrap();
+        let decoded: NodePool = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded, pool);
+        assert_eq!(decoded.get(&hash), Some(&node(1)));
+    }
+}
@@ -11,10 +11,15 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "api")]
 use crate::api::op_stack::OpMerkleProofDto;
 use crate::block::OpChainClient;
-use crate::inputs::evm::header_serde::{deserialize_execution_header, serialize_execution_header};
+use crate::inputs::evm::header_serde::{
+    deserialize_execution_header, deserialize_execution_headers, serialize_execution_header,
+    serialize_execution_headers,
+};
+use crate::inputs::evm::node_pool::{pool_all, resolve_all, NodePool};
 use crate::inputs::evm::{
-    execution::{AccountProof, ReceiptProof, StorageSlotProof, TxProof},
-    MmrProof,
+    batch_header_proofs,
+    execution::{AccountProof, CodeProof, LogProof, ReceiptProof, StorageSlotProof, TxProof},
+    MmrMultiProof, MmrProof,
 };
 
 #[cfg_attr(feature = "std", derive(Debug))]
@@ -23,6 +28,11 @@ pub struct OpStackProofs {
     #[serde(default)]
     pub header_proof: Vec<OpStackHeaderProof>,
     #[serde(default)]
+    pub header_batch_proof: Vec<OpStackHeaderBatchProof>,
+    /// Contiguous header ranges, in ascending block order.
+    #[serde(default)]
+    pub header_range_proof: Vec<OpStackHeaderBatchProof>,
+    #[serde(default)]
     pub account_proof: Vec<AccountProof>,
     #[serde(default)]
     pub storage_slot_proof: Vec<StorageSlotProof>,
@@ -30,15 +40,79 @@ pub struct OpStackProofs {
     pub tx_proof: Vec<TxProof>,
     #[serde(default)]
     pub receipt_proof: Vec<ReceiptProof>,
+    #[serde(default)]
+    pub code_proof: Vec<CodeProof>,
+    #[serde(default)]
+    pub log_proof: Vec<LogProof>,
+    /// Shared MPT nodes. When set, the account, storage and code proofs hold node hashes.
+    #[serde(default)]
+    pub node_pool: Option<NodePool>,
 }
 
 impl OpStackProofs {
     pub fn is_empty(&self) -> bool {
         self.header_proof.is_empty()
+            && self.header_batch_proof.is_empty()
+            && self.header_range_proof.is_empty()
             && self.account_proof.is_empty()
             && self.storage_slot_proof.is_empty()
             && self.tx_proof.is_empty()
             && self.receipt_proof.is_empty()
+            && self.code_proof.is_empty()
+            && self.log_proof.is_empty()
+    }
+
+    /// Moves the header proofs into one batch proof per chain snapshot and MMR.
+    ///
+    /// Header proofs whose MMR proofs cannot be merged are kept as they are.
+    pub fn batch_header_proofs(&mut self) {
+        let (single, batches) = batch_header_proofs(
+            core::mem::take(&mut self.header_proof),
+            |proof| &proof.mmr_proof,
+            |first, other| {
+                first.snapshot.chain_id == other.snapshot.chain_id
+                    && first.merkle_proof.leaf_hash == other.merkle_proof.leaf_hash
+                    && first.merkle_proof.root == other.merkle_proof.root
+            },
+            |proofs, mmr_proof| {
+                let snapshot = proofs[0].snapshot.clone();
+                let merkle_proof = proofs[0].merkle_proof.clone();
+                OpStackHeaderBatchProof {
+                    headers: proofs.into_iter().map(|proof| proof.header).collect(),
+                    snapshot,
+                    merkle_proof,
+                    mmr_proof,
+                }
+            },
+        );
+        self.header_proof = single;
+        self.header_batch_proof.extend(batches);
+    }
+
+    /// Moves the MPT nodes of account, storage and code proofs into
+    /// [`OpStackProofs::node_pool`].
+    ///
+    /// Does nothing if the proofs are already pooled.
+    pub fn pool_nodes(&mut self) {
+        if self.node_pool.is_some() {
+            return;
+        }
+        let pool = self.node_pool.insert(NodePool::default());
+        pool_all(&mut self.account_proof, pool);
+        pool_all(&mut self.storage_slot_proof, pool);
+        pool_all(&mut self.code_proof, pool);
+    }
+
+    /// Restores the pooled MPT nodes into their proofs and drops the pool.
+    ///
+    /// Returns `false`, leaving the proofs partially resolved, if a node is missing.
+    pub fn resolve_nodes(&mut self) -> bool {
+        let Some(pool) = self.node_pool.take() else {
+            return true;
+        };
+        resolve_all(&mut self.account_proof, &pool).is_some()
+            && resolve_all(&mut self.storage_slot_proof, &pool).is_some()
+            && resolve_all(&mut self.code_proof, &pool).is_some()
     }
 }
 
@@ -117,10 +191,15 @@ mod roundtrip_tests {
             bincode::deserialize(&bytes).expect("failed to deserialize OpStackProofs");
 
         assert_eq!(decoded.header_proof.len(), 1);
+        assert!(decoded.header_batch_proof.is_empty());
+        assert!(decoded.header_range_proof.is_empty());
         assert!(decoded.account_proof.is_empty());
         assert_eq!(decoded.storage_slot_proof.len(), 1);
         assert!(decoded.tx_proof.is_empty());
         assert!(decoded.receipt_proof.is_empty());
+        assert!(decoded.code_proof.is_empty());
+        assert!(decoded.log_proof.is_empty());
+        assert!(decoded.node_pool.is_none());
     }
 }
 
@@ -137,6 +216,20 @@ pub struct OpStackHeaderProof {
     pub mmr_proof: MmrProof,
 }
 
+/// Headers of one OP Stack chain proven by one MMR proof, in the order of its leaves.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OpStackHeaderBatchProof {
+    #[serde(
+        serialize_with = "serialize_execution_headers",
+        deserialize_with = "deserialize_execution_headers"
+    )]
+    pub headers: Vec<ExecutionHeader>,
+    pub snapshot: OpChainClient,
+    pub merkle_proof: OpStackMerkleProof,
+    pub mmr_proof: MmrMultiProof,
+}
+
 #[cfg_attr(feature = "std", derive(Debug))]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct OpStackMerkleProof {
@@ -0,0 +1,509 @@
+//! Versioned on-disk container for [`ProofBundle`].
+//!
+//! A bundle file is laid out as:
+//!
+//! | field           | encoding                                   |
+//! |-----------------|--------------------------------------------|
+//! | magic           | the 8 bytes `BANKAIPB`                     |
+//! | schema version  | `u16`, little-endian                       |
+//! | metadata length | `u32`, little-endian, at most 64 KiB       |
+//! | metadata        | JSON [`BundleMetadata`]                    |
+//! | payload         | JSON [`ProofBundle`], compressed as stated |
+//!
+//! Readers accept every schema version up to [`BUNDLE_SCHEMA_VERSION`]. Unknown metadata
+//! fields are ignored and proof lists added to the payload later default to empty, so bundles
+//! written by older SDKs keep decoding after upgrades.
+
+use std::io::{Read, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use alloy_primitives::FixedBytes;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::block::BankaiBlock;
+use crate::common::{HashingFunction, Network};
+use crate::inputs::ProofBundle;
+
+/// Leading bytes of every bundle file.
+pub const BUNDLE_MAGIC: [u8; 8] = *b"BANKAIPB";
+
+/// Schema version written by this release.
+pub const BUNDLE_SCHEMA_VERSION: u16 = 1;
+
+/// Largest metadata section readers accept, in bytes.
+pub const MAX_METADATA_LEN: u32 = 64 * 1024;
+
+/// Compression applied to the bundle payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleCompression {
+    #[default]
+    None,
+    /// Gzip, available with the `compression` feature.
+    Gzip,
+}
+
+/// Metadata stored in front of the bundle payload.
+///
+/// Can be read without decoding the payload with [`BundleMetadata::read_from`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleMetadata {
+    /// Schema version of the file the metadata was read from.
+    #[serde(skip)]
+    pub schema_version: u16,
+    pub network: Network,
+    pub hashing_function: HashingFunction,
+    pub bankai_block_number: u64,
+    pub bankai_block_hash: FixedBytes<32>,
+    /// Unix time in seconds at which the file was written.
+    pub created_at: u64,
+    pub compression: BundleCompression,
+}
+
+/// Errors returned when writing or reading a bundle file.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BundleFileError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// The input does not start with [`BUNDLE_MAGIC`].
+    InvalidMagic,
+    /// The file was written with a newer schema than this release reads.
+    UnsupportedVersion(u16),
+    /// The payload compression is not enabled in this build.
+    UnsupportedCompression(BundleCompression),
+    /// The metadata section is longer than [`MAX_METADATA_LEN`].
+    MetadataTooLarge(u64),
+    /// The metadata does not describe the payload.
+    MetadataMismatch,
+}
+
+impl core::fmt::Display for BundleFileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "bundle file I/O error: {err}"),
+            Self::Json(err) => write!(f, "invalid bundle file JSON: {err}"),
+            Self::InvalidMagic => write!(f, "not a Bankai proof bundle file"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported bundle schema version {version}")
+            }
+            Self::UnsupportedCompression(compression) => {
+                write!(f, "unsupported bundle compression {compression:?}")
+            }
+            Self::MetadataTooLarge(len) => write!(
+                f,
+                "bundle metadata is {len} bytes, more than the {MAX_METADATA_LEN} byte limit"
+            ),
+            Self::MetadataMismatch => write!(f, "bundle metadata does not match its payload"),
+        }
+    }
+}
+
+impl std::error::Error for BundleFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for BundleFileError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for BundleFileError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl BundleMetadata {
+    /// Describes `bundle` for `network`, stamped with the current time.
+    pub fn new(bundle: &ProofBundle, network: Network, compression: BundleCompression) -> Self {
+        Self::describe(bundle, network, compression)
+    }
+
+    fn describe<T: BundlePayload>(
+        payload: &T,
+        network: Network,
+        compression: BundleCompression,
+    ) -> Self {
+        Self {
+            schema_version: BUNDLE_SCHEMA_VERSION,
+            network,
+            hashing_function: payload.hashing_function(),
+            bankai_block_number: payload.block().block_number,
+            bankai_block_hash: payload.block().compute_block_hash_keccak(),
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            compression,
+        }
+    }
+
+    /// Reads the metadata of a bundle file, leaving `reader` at the start of the payload.
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, BundleFileError> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if magic != BUNDLE_MAGIC {
+            return Err(BundleFileError::InvalidMagic);
+        }
+
+        let mut version = [0u8; 2];
+        reader.read_exact(&mut version)?;
+        let schema_version = u16::from_le_bytes(version);
+        if schema_version == 0 || schema_version > BUNDLE_SCHEMA_VERSION {
+            return Err(BundleFileError::UnsupportedVersion(schema_version));
+        }
+
+        let mut len = [0u8; 4];
+        reader.read_exact(&mut len)?;
+        let len = u32::from_le_bytes(len);
+        if len > MAX_METADATA_LEN {
+            return Err(BundleFileError::MetadataTooLarge(len.into()));
+        }
+        let mut json = vec![0u8; len as usize];
+        reader.read_exact(&mut json)?;
+
+        let mut metadata: Self = serde_json::from_slice(&json)?;
+        metadata.schema_version = schema_version;
+        Ok(metadata)
+    }
+
+    fn write_to<W: Write>(&self, mut writer: W) -> Result<(), BundleFileError> {
+        let json = serde_json::to_vec(self)?;
+        let len = u32::try_from(json.len()).unwrap_or(u32::MAX);
+        if len > MAX_METADATA_LEN {
+            return Err(BundleFileError::MetadataTooLarge(json.len() as u64));
+        }
+
+        writer.write_all(&BUNDLE_MAGIC)?;
+        writer.write_all(&BUNDLE_SCHEMA_VERSION.to_le_bytes())?;
+        writer.write_all(&len.to_le_bytes())?;
+        writer.write_all(&json)?;
+        Ok(())
+    }
+}
+
+impl ProofBundle {
+    /// Writes the bundle as a versioned bundle file and returns the written metadata.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use bankai_types::common::Network;
+    /// use bankai_types::inputs::file::BundleCompression;
+    /// use bankai_types::inputs::ProofBundle;
+    ///
+    /// # fn example(bundle: ProofBundle) -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = std::fs::File::create("bundle.bin")?;
+    /// bundle.write_to(file, Network::Sepolia, BundleCompression::None)?;
+    ///
+    /// let bundle = ProofBundle::read_from(std::fs::File::open("bundle.bin")?)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_to<W: Write>(
+        &self,
+        writer: W,
+        network: Network,
+        compression: BundleCompression,
+    ) -> Result<BundleMetadata, BundleFileError> {
+        write_bundle(self, writer, network, compression)
+    }
+
+    /// Reads a bundle file written by [`ProofBundle::write_to`].
+    pub fn read_from<R: Read>(reader: R) -> Result<Self, BundleFileError> {
+        Self::read_with_metadata(reader).map(|(_, bundle)| bundle)
+    }
+
+    /// Reads a bundle file and its metadata.
+    ///
+    /// # Errors
+    ///
+    /// - `MetadataTooLarge` - The metadata section is longer than [`MAX_METADATA_LEN`]
+    /// - `MetadataMismatch` - The metadata names another Bankai block or hashing function
+    pub fn read_with_metadata<R: Read>(
+        reader: R,
+    ) -> Result<(BundleMetadata, Self), BundleFileError> {
+        read_bundle(reader)
+    }
+}
+
+/// Payload of a bundle file, as described by its [`BundleMetadata`].
+trait BundlePayload: Serialize + DeserializeOwned {
+    fn hashing_function(&self) -> HashingFunction;
+    fn block(&self) -> &BankaiBlock;
+}
+
+impl BundlePayload for ProofBundle {
+    fn hashing_function(&self) -> HashingFunction {
+        self.hashing_function
+    }
+
+    fn block(&self) -> &BankaiBlock {
+        &self.block
+    }
+}
+
+fn write_bundle<W: Write, T: BundlePayload>(
+    payload: &T,
+    mut writer: W,
+    network: Network,
+    compression: BundleCompression,
+) -> Result<BundleMetadata, BundleFileError> {
+    let metadata = BundleMetadata::describe(payload, network, compression);
+    metadata.write_to(&mut writer)?;
+    write_payload(writer, payload, compression)?;
+    Ok(metadata)
+}
+
+fn read_bundle<R: Read, T: BundlePayload>(
+    mut reader: R,
+) -> Result<(BundleMetadata, T), BundleFileError> {
+    let metadata = BundleMetadata::read_from(&mut reader)?;
+    let payload: T = read_payload(reader, metadata.compression)?;
+
+    if payload.hashing_function() != metadata.hashing_function
+        || payload.block().block_number != metadata.bankai_block_number
+        || payload.block().compute_block_hash_keccak() != metadata.bankai_block_hash
+    {
+        return Err(BundleFileError::MetadataMismatch);
+    }
+    Ok((metadata, payload))
+}
+
+fn write_payload<W: Write, T: Serialize>(
+    writer: W,
+    payload: &T,
+    compression: BundleCompression,
+) -> Result<(), BundleFileError> {
+    match compression {
+        BundleCompression::None => serde_json::to_writer(writer, payload)?,
+        #[cfg(feature = "compression")]
+        BundleCompression::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+            serde_json::to_writer(&mut encoder, payload)?;
+            encoder.finish()?;
+        }
+        #[cfg(not(feature = "compression"))]
+        BundleCompression::Gzip => {
+            return Err(BundleFileError::UnsupportedCompression(compression));
+        }
+    }
+    Ok(())
+}
+
+fn read_payload<R: Read, T: DeserializeOwned>(
+    reader: R,
+    compression: BundleCompression,
+) -> Result<T, BundleFileError> {
+    let payload = match compression {
+        BundleCompression::None => serde_json::from_reader(std::io::BufReader::new(reader))?,
+        #[cfg(feature = "compression")]
+        BundleCompression::Gzip => serde_json::from_reader(std::io::BufReader::new(
+            flate2::read::GzDecoder::new(reader),
+        ))?,
+        #[cfg(not(feature = "compression"))]
+        BundleCompression::Gzip => {
+            return Err(BundleFileError::UnsupportedCompression(compression));
+        }
+    };
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inputs::evm::EvmProofs;
+
+    /// Stands in for [`ProofBundle`], whose STWO proof cannot be built in unit tests.
+    ///
+    /// Shares its JSON layout apart from `block_proof`.
+    #[derive(Serialize, Deserialize)]
+    struct TestBundle {
+        hashing_function: HashingFunction,
+        block: BankaiBlock,
+        evm_proofs: Option<EvmProofs>,
+    }
+
+    impl BundlePayload for TestBundle {
+        fn hashing_function(&self) -> HashingFunction {
+            self.hashing_function
+        }
+
+        fn block(&self) -> &BankaiBlock {
+            &self.block
+        }
+    }
+
+    fn bundle(block_number: u64) -> TestBundle {
+        TestBundle {
+            hashing_function: HashingFunction::Keccak,
+            block: BankaiBlock {
+                block_number,
+                ..Default::default()
+            },
+            evm_proofs: Some(EvmProofs::default()),
+        }
+    }
+
+    fn metadata(compression: BundleCompression) -> BundleMetadata {
+        BundleMetadata {
+            schema_version: BUNDLE_SCHEMA_VERSION,
+            network: Network::Sepolia,
+            hashing_function: HashingFunction::Keccak,
+            bankai_block_number: 42,
+            bankai_block_hash: FixedBytes::from([0x42; 32]),
+            created_at: 1_700_000_000,
+            compression,
+        }
+    }
+
+    fn file(metadata: &BundleMetadata, payload: &EvmProofs) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        metadata.write_to(&mut bytes).unwrap();
+        write_payload(&mut bytes, payload, metadata.compression).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn round_trips_metadata_and_payload() {
+        let metadata = metadata(BundleCompression::None);
+        let bytes = file(&metadata, &EvmProofs::default());
+
+        let mut reader = bytes.as_slice();
+        assert_eq!(BundleMetadata::read_from(&mut reader).unwrap(), metadata);
+        let payload: EvmProofs = read_payload(reader, metadata.compression).unwrap();
+        assert!(payload.is_empty());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn round_trips_gzip_payload() {
+        let metadata = metadata(BundleCompression::Gzip);
+        let bytes = file(&metadata, &EvmProofs::default());
+
+        let mut reader = bytes.as_slice();
+        let read = BundleMetadata::read_from(&mut reader).unwrap();
+        let payload: EvmProofs = read_payload(reader, read.compression).unwrap();
+        assert!(payload.is_empty());
+    }
+
+    #[test]
+    fn ignores_unknown_metadata_fields_and_missing_proof_lists() {
+        let json = br#"{"network":"sepolia","hashing_function":"keccak","bankai_block_number":42,"bankai_block_hash":"0x4242424242424242424242424242424242424242424242424242424242424242","created_at":1700000000,"compression":"none","producer":"archiver"}"#;
+        let mut bytes = BUNDLE_MAGIC.to_vec();
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&(json.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(json);
+        bytes.extend_from_slice(br#"{"account_proof":[]}"#);
+
+        let mut reader = bytes.as_slice();
+        assert_eq!(
+            BundleMetadata::read_from(&mut reader).unwrap(),
+            metadata(BundleCompression::None)
+        );
+        let payload: EvmProofs = read_payload(reader, BundleCompression::None).unwrap();
+        assert!(payload.is_empty());
+    }
+
+    #[test]
+    fn rejects_foreign_files_and_newer_schemas() {
+        let mut bytes = file(&metadata(BundleCompression::None), &EvmProofs::default());
+        bytes[8..10].copy_from_slice(&(BUNDLE_SCHEMA_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            BundleMetadata::read_from(bytes.as_slice()),
+            Err(BundleFileError::UnsupportedVersion(version)) if version == BUNDLE_SCHEMA_VERSION + 1
+        ));
+
+        bytes[0] = b'X';
+        assert!(matches!(
+            BundleMetadata::read_from(bytes.as_slice()),
+            Err(BundleFileError::InvalidMagic)
+        ));
+    }
+
+    #[test]
+    fn round_trips_bundle_with_metadata() {
+        let bundle = bundle(42);
+        let mut bytes = Vec::new();
+        let written = write_bundle(
+            &bundle,
+            &mut bytes,
+            Network::Sepolia,
+            BundleCompression::None,
+        )
+        .unwrap();
+
+        let (metadata, read): (_, TestBundle) = read_bundle(bytes.as_slice()).unwrap();
+        assert_eq!(metadata, written);
+        assert_eq!(metadata.bankai_block_number, 42);
+        assert_eq!(
+            metadata.bankai_block_hash,
+            bundle.block.compute_block_hash_keccak()
+        );
+        assert_eq!(read.block.block_number, 42);
+        assert!(read.evm_proofs.unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_metadata_of_another_bundle() {
+        let mut bytes = Vec::new();
+        BundleMetadata::describe(&bundle(41), Network::Sepolia, BundleCompression::None)
+            .write_to(&mut bytes)
+            .unwrap();
+        write_payload(&mut bytes, &bundle(42), BundleCompression::None).unwrap();
+
+        assert!(matches!(
+            read_bundle::<_, TestBundle>(bytes.as_slice()),
+            Err(BundleFileError::MetadataMismatch)
+        ));
+    }
+
+    #[test]
+    fn rejects_oversized_metadata() {
+        let mut bytes = BUNDLE_MAGIC.to_vec();
+        bytes.extend_from_slice(&BUNDLE_SCHEMA_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(MAX_METADATA_LEN + 1).to_le_bytes());
+
+        assert!(matches!(
+            BundleMetadata::read_from(bytes.as_slice()),
+            Err(BundleFileError::MetadataTooLarge(len)) if len == u64::from(MAX_METADATA_LEN) + 1
+        ));
+    }
+
+    /// Golden bundle file with a real block proof, written by the CLI with
+    ///
+    /// ```sh
+    /// cargo run -p bankai-cli -- --network sepolia fetch \
+    ///     --out crates/types/tests/fixtures/proof_bundle.bin --execution-header <block>
+    /// ```
+    const GOLDEN_BUNDLE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/proof_bundle.bin"
+    );
+
+    #[test]
+    #[ignore = "needs the golden bundle file, see GOLDEN_BUNDLE"]
+    fn reads_golden_proof_bundle() {
+        let file = std::fs::File::open(GOLDEN_BUNDLE).unwrap();
+        let (metadata, bundle) = ProofBundle::read_with_metadata(file).unwrap();
+
+        assert_eq!(metadata.schema_version, BUNDLE_SCHEMA_VERSION);
+        assert_eq!(metadata.network, Network::Sepolia);
+        assert_eq!(metadata.hashing_function, bundle.hashing_function);
+        assert_eq!(metadata.bankai_block_number, bundle.block.block_number);
+        assert_eq!(
+            metadata.bankai_block_hash,
+            bundle.block.compute_block_hash_keccak()
+        );
+        assert!(bundle
+            .evm_proofs
+            .is_some_and(|evm| !evm.execution_header_proof.is_empty()));
+    }
+}
@@ -8,7 +8,10 @@ use crate::block::BankaiBlock;
 use crate::common::HashingFunction;
 use crate::inputs::evm::{op_stack::OpStackProofs, EvmProofs};
 
+pub mod bankai;
 pub mod evm;
+#[cfg(feature = "std")]
+pub mod file;
 
 #[derive(Serialize, Deserialize)]
 pub struct ProofBundle {
@@ -18,3 +21,31 @@ pub struct ProofBundle {
     pub evm_proofs: Option<EvmProofs>,
     pub op_stack_proofs: Option<OpStackProofs>,
 }
+
+impl ProofBundle {
+    /// Stores the MPT nodes shared by state proofs once per chain.
+    ///
+    /// Bundles with many account or storage proofs shrink considerably. The verifier resolves
+    /// the nodes from the pool.
+    pub fn pool_nodes(&mut self) {
+        if let Some(evm) = &mut self.evm_proofs {
+            evm.pool_nodes();
+        }
+        if let Some(op_stack) = &mut self.op_stack_proofs {
+            op_stack.pool_nodes();
+        }
+    }
+
+    /// Proves the headers of each MMR with one multi-leaf MMR proof.
+    ///
+    /// Bundles with many headers from the same chain shrink considerably, as shared path
+    /// nodes and the peaks are stored once.
+    pub fn batch_header_proofs(&mut self) {
+        if let Some(evm) = &mut self.evm_proofs {
+            evm.batch_header_proofs();
+        }
+        if let Some(op_stack) = &mut self.op_stack_proofs {
+            op_stack.batch_header_proofs();
+        }
+    }
+}
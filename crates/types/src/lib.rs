@@ -14,6 +14,7 @@
 //! - [`block`] - Bankai block representations with beacon and execution client data
 //! - [`inputs`] - Typed verification inputs assembled by the SDK
 //! - [`results`] - Verified outputs returned by the verifier
+//! - [`storage`] - Solidity storage slot computation and decoding
 //!
 //! ## Feature Flags
 //!
@@ -21,6 +22,7 @@
 //! - `api` - Enable API types (requires `std`)
 //! - `inputs` - Enable typed verifier input types
 //! - `results` - Enable typed verification result types
+//! - `sol-types` - Decode verified logs and storage slots with `alloy-sol-types`
 //! - `serde` - Enable serde serialization support
 //! - `utoipa` - Enable OpenAPI schema generation
 
@@ -61,5 +63,11 @@ pub mod inputs;
 #[cfg(feature = "results")]
 pub mod results;
 
+/// Solidity storage layout helpers
+///
+/// Computes storage slots of mappings, arrays, structs and packed values, and reads them
+/// back from verified storage results.
+pub mod storage;
+
 #[cfg(feature = "inputs")]
 pub use inputs::ProofBundle;
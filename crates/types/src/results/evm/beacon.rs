@@ -1,6 +1,7 @@
 use alloy_primitives::FixedBytes;
 #[cfg(feature = "inputs")]
 use alloy_rpc_types_beacon::header::HeaderResponse;
+use bankai_core::ssz;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use tree_hash_derive::TreeHash;
@@ -33,3 +34,50 @@ impl From<HeaderResponse> for BeaconHeader {
         }
     }
 }
+
+/// Beacon state validator record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Validator {
+    /// BLS public key.
+    pub pubkey: FixedBytes<48>,
+    /// Withdrawal credentials (`0x00` BLS, `0x01` execution or `0x02` compounding prefix).
+    pub withdrawal_credentials: FixedBytes<32>,
+    /// Effective balance in Gwei.
+    pub effective_balance: u64,
+    /// Whether the validator has been slashed.
+    pub slashed: bool,
+    /// Epoch when the validator became eligible for activation.
+    pub activation_eligibility_epoch: u64,
+    /// Epoch when the validator was activated.
+    pub activation_epoch: u64,
+    /// Epoch when the validator exited, `u64::MAX` if it has not.
+    pub exit_epoch: u64,
+    /// Epoch when the validator can withdraw, `u64::MAX` if it cannot yet.
+    pub withdrawable_epoch: u64,
+}
+
+impl Validator {
+    /// Returns the SSZ hash tree root of the validator record.
+    pub fn tree_hash_root(&self) -> FixedBytes<32> {
+        let pubkey_root = ssz::merkleize(&ssz::pack(self.pubkey.as_slice()), 2);
+        ssz::merkleize(
+            &[
+                pubkey_root,
+                self.withdrawal_credentials,
+                ssz::u64_leaf(self.effective_balance),
+                ssz::bool_leaf(self.slashed),
+                ssz::u64_leaf(self.activation_eligibility_epoch),
+                ssz::u64_leaf(self.activation_epoch),
+                ssz::u64_leaf(self.exit_epoch),
+                ssz::u64_leaf(self.withdrawable_epoch),
+            ],
+            8,
+        )
+    }
+
+    /// Returns `true` if the validator is active at `epoch`.
+    pub fn is_active(&self, epoch: u64) -> bool {
+        self.activation_epoch <= epoch && epoch < self.exit_epoch
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use alloy_eips::eip2718::{Decodable2718, Encodable2718};
+use alloy_primitives::{Address, Bloom, Bytes, B256, B64, U256};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::results::evm::beacon::BeaconHeader;
+use crate::results::evm::execution::{ExecutionHeader, TrieAccount};
+use crate::results::evm::VerifiedHeaderRange;
+
+/// JSON shape of a verified execution header: `snake_case` fields, `u64` quantities as
+/// numbers and the difficulty as a decimal string.
+#[derive(Serialize, Deserialize)]
+struct HeaderJson {
+    parent_hash: B256,
+    ommers_hash: B256,
+    beneficiary: Address,
+    state_root: B256,
+    transactions_root: B256,
+    receipts_root: B256,
+    logs_bloom: Bloom,
+    difficulty: String,
+    number: u64,
+    gas_limit: u64,
+    gas_used: u64,
+    timestamp: u64,
+    extra_data: Bytes,
+    mix_hash: B256,
+    nonce: B64,
+    base_fee_per_gas: Option<u64>,
+    withdrawals_root: Option<B256>,
+    blob_gas_used: Option<u64>,
+    excess_blob_gas: Option<u64>,
+    parent_beacon_block_root: Option<B256>,
+    requests_hash: Option<B256>,
+}
+
+impl From<&ExecutionHeader> for HeaderJson {
+    fn from(header: &ExecutionHeader) -> Self {
+        Self {
+            parent_hash: header.parent_hash,
+            ommers_hash: header.ommers_hash,
+            beneficiary: header.beneficiary,
+            state_root: header.state_root,
+            transactions_root: header.transactions_root,
+            receipts_root: header.receipts_root,
+            logs_bloom: header.logs_bloom,
+            difficulty: header.difficulty.to_string(),
+            number: header.number,
+            gas_limit: header.gas_limit,
+            gas_used: header.gas_used,
+            timestamp: header.timestamp,
+            extra_data: header.extra_data.clone(),
+            mix_hash: header.mix_hash,
+            nonce: header.nonce,
+            base_fee_per_gas: header.base_fee_per_gas,
+            withdrawals_root: header.withdrawals_root,
+            blob_gas_used: header.blob_gas_used,
+            excess_blob_gas: header.excess_blob_gas,
+            parent_beacon_block_root: header.parent_beacon_block_root,
+            requests_hash: header.requests_hash,
+        }
+    }
+}
+
+impl HeaderJson {
+    fn into_header<E: serde::de::Error>(self) -> Result<ExecutionHeader, E> {
+        Ok(ExecutionHeader {
+            parent_hash: self.parent_hash,
+            ommers_hash: self.ommers_hash,
+            beneficiary: self.beneficiary,
+            state_root: self.state_root,
+            transactions_root: self.transactions_root,
+            receipts_root: self.receipts_root,
+            logs_bloom: self.logs_bloom,
+            difficulty: parse_decimal(&self.difficulty)?,
+            number: self.number,
+            gas_limit: self.gas_limit,
+            gas_used: self.gas_used,
+            timestamp: self.timestamp,
+            extra_data: self.extra_data,
+            mix_hash: self.mix_hash,
+            nonce: self.nonce,
+            base_fee_per_gas: self.base_fee_per_gas,
+            withdrawals_root: self.withdrawals_root,
+            blob_gas_used: self.blob_gas_used,
+            excess_blob_gas: self.excess_blob_gas,
+            parent_beacon_block_root: self.parent_beacon_block_root,
+            requests_hash: self.requests_hash,
+        })
+    }
+}
+
+/// JSON shape of a verified account, with the balance as a decimal string.
+#[derive(Serialize, Deserialize)]
+struct AccountJson {
+    nonce: u64,
+    balance: String,
+    storage_root: B256,
+    code_hash: B256,
+}
+
+/// JSON shape of a verified header range.
+#[derive(Serialize, Deserialize)]
+struct HeaderRangeJson<H> {
+    network_id: u64,
+    start: u64,
+    end: u64,
+    headers: Vec<H>,
+}
+
+fn parse_decimal<E: serde::de::Error>(value: &str) -> Result<U256, E> {
+    U256::from_str_radix(value, 10).map_err(E::custom)
+}
+
+pub fn serialize_headers<S>(headers: &[ExecutionHeader], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(headers.iter().map(HeaderJson::from))
+}
+
+pub fn deserialize_headers<'de, D>(deserializer: D) -> Result<Vec<ExecutionHeader>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<HeaderJson>::deserialize(deserializer)?
+        .into_iter()
+        .map(HeaderJson::into_header)
+        .collect()
+}
+
+pub fn serialize_account<S>(account: &TrieAccount, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    AccountJson {
+        nonce: account.nonce,
+        balance: account.balance.to_string(),
+        storage_root: account.storage_root,
+        code_hash: account.code_hash,
+    }
+    .serialize(serializer)
+}
+
+pub fn deserialize_account<'de, D>(deserializer: D) -> Result<TrieAccount, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let account = AccountJson::deserialize(deserializer)?;
+    Ok(TrieAccount {
+        nonce: account.nonce,
+        balance: parse_decimal(&account.balance)?,
+        storage_root: account.storage_root,
+        code_hash: account.code_hash,
+    })
+}
+
+/// Serializes a transaction or receipt as its EIP-2718 encoding in hex.
+pub fn serialize_envelope<T, S>(envelope: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Encodable2718,
+    S: Serializer,
+{
+    Bytes::from(envelope.encoded_2718()).serialize(serializer)
+}
+
+pub fn deserialize_envelope<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Decodable2718,
+    D: Deserializer<'de>,
+{
+    let encoded = Bytes::deserialize(deserializer)?;
+    let mut buf = encoded.as_ref();
+    let envelope = T::decode_2718(&mut buf).map_err(D::Error::custom)?;
+    if !buf.is_empty() {
+        return Err(D::Error::custom("trailing bytes after EIP-2718 envelope"));
+    }
+    Ok(envelope)
+}
+
+impl Serialize for VerifiedHeaderRange<ExecutionHeader> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HeaderRangeJson {
+            network_id: self.network_id,
+            start: self.start,
+            end: self.end,
+            headers: self.headers.iter().map(HeaderJson::from).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerifiedHeaderRange<ExecutionHeader> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let range = HeaderRangeJson::<HeaderJson>::deserialize(deserializer)?;
+        Ok(Self {
+            network_id: range.network_id,
+            start: range.start,
+            end: range.end,
+            headers: range
+                .headers
+                .into_iter()
+                .map(HeaderJson::into_header)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Serialize for VerifiedHeaderRange<BeaconHeader> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HeaderRangeJson {
+            network_id: self.network_id,
+            start: self.start,
+            end: self.end,
+            headers: self.headers.iter().collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for VerifiedHeaderRange<BeaconHeader> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let range = HeaderRangeJson::<BeaconHeader>::deserialize(deserializer)?;
+        Ok(Self {
+            network_id: range.network_id,
+            start: range.start,
+            end: range.end,
+            headers: range.headers,
+        })
+    }
+}
@@ -3,69 +3,371 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use alloy_consensus::{ReceiptEnvelope, TxEnvelope};
-use alloy_primitives::{Address, FixedBytes, U256};
+use alloy_primitives::{Address, Bytes, FixedBytes, U256};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
-use crate::results::evm::beacon::BeaconHeader;
+use crate::common::Finality;
+use crate::results::evm::beacon::{BeaconHeader, Validator};
 use crate::results::evm::execution::{ExecutionHeader, TrieAccount};
 
 pub mod beacon;
+#[cfg(feature = "serde")]
+pub(crate) mod consensus_serde;
 pub mod execution;
 pub mod op_stack;
+#[cfg(feature = "serde")]
+pub(crate) mod slots_serde;
+#[cfg(feature = "sol-types")]
+pub mod sol;
 
-/// Identifies a verified chain block by network and block number.
+/// Identifies a verified chain block and the Bankai block that anchored it.
+///
+/// Header fields are copied from the verified header, so results can be used without joining
+/// them back to the header list. For beacon blocks, `block_number` is the slot, `block_hash`
+/// the header root and `state_root` the beacon state root. Beacon headers carry no timestamp,
+/// so `timestamp` is 0.
+///
+/// Fields other than `network_id` and `block_number` default when missing, so results saved
+/// before they were added still deserialize.
 #[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BlockRef {
     pub network_id: u64,
     pub block_number: u64,
+    /// Hash of the verified header.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub block_hash: FixedBytes<32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub state_root: FixedBytes<32>,
+    /// Finality of the header in the anchoring Bankai block.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub finality: Finality,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bankai_block_number: u64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bankai_block_hash: FixedBytes<32>,
 }
 
 /// Verified account result with the request identity preserved.
 #[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VerifiedAccount {
     pub block: BlockRef,
     pub address: Address,
+    /// `false` if the address is verifiably absent from the state trie.
+    pub exists: bool,
+    /// Account state, or the empty account when `exists` is `false`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_account",
+            deserialize_with = "consensus_serde::deserialize_account"
+        )
+    )]
     pub account: TrieAccount,
 }
 
 /// Verified storage slot result with the request identity preserved.
 #[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VerifiedStorageSlots {
     pub block: BlockRef,
     pub address: Address,
+    /// `(slot, value)` pairs in request order.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "slots_serde::serialize_storage_slots",
+            deserialize_with = "slots_serde::deserialize_storage_slots"
+        )
+    )]
     pub slots: Vec<(U256, U256)>,
 }
 
+/// Verified contract bytecode with the request identity preserved.
+///
+/// `code` hashes to the verified account's `code_hash`. Accounts without code have empty `code`.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedCode {
+    pub block: BlockRef,
+    pub address: Address,
+    pub code_hash: FixedBytes<32>,
+    pub code: Bytes,
+}
+
+/// Execution payload fields verified against a beacon block body.
+///
+/// Links a beacon `slot` to the execution block it carried. `block_hash` authenticates the
+/// execution header and `state_root` its state without an execution MMR proof.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedExecutionPayload {
+    /// Beacon block carrying the payload.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub block: BlockRef,
+    /// Beacon network ID of the block carrying the payload.
+    pub network_id: u64,
+    pub slot: u64,
+    pub block_number: u64,
+    pub block_hash: FixedBytes<32>,
+    pub state_root: FixedBytes<32>,
+    pub timestamp: u64,
+}
+
+/// Validator record and balance verified against a beacon state root.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedValidator {
+    /// Beacon block whose state holds the validator.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub block: BlockRef,
+    /// Beacon network ID of the state.
+    pub network_id: u64,
+    pub slot: u64,
+    pub validator_index: u64,
+    pub validator: Validator,
+    /// Actual balance in Gwei.
+    pub balance: u64,
+}
+
 /// Verified transaction result with the request identity preserved.
+///
+/// `tx_hash` is recomputed from the verified transaction payload.
 #[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VerifiedTransaction {
     pub block: BlockRef,
     pub tx_hash: FixedBytes<32>,
     pub tx_index: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_envelope",
+            deserialize_with = "consensus_serde::deserialize_envelope"
+        )
+    )]
     pub tx: TxEnvelope,
 }
 
 /// Verified receipt result with the request identity preserved.
+///
+/// Receipt proofs only prove a receipt at `tx_index`. `tx_hash` is set only when the same
+/// batch also verified the transaction at that index; otherwise the receipt is index-only.
 #[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VerifiedReceipt {
     pub block: BlockRef,
-    pub tx_hash: FixedBytes<32>,
+    pub tx_hash: Option<FixedBytes<32>>,
     pub tx_index: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_envelope",
+            deserialize_with = "consensus_serde::deserialize_envelope"
+        )
+    )]
     pub receipt: ReceiptEnvelope,
 }
 
+/// Maximum number of topics of a log.
+pub const MAX_LOG_TOPICS: usize = 4;
+
+/// Selects the logs of a proven receipt.
+///
+/// An empty `address` list matches any emitter. `topics[i]` lists the accepted values of
+/// topic `i`, and an empty entry matches any value, as in `eth_getLogs`.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogFilter {
+    pub address: Vec<Address>,
+    pub topics: Vec<Vec<FixedBytes<32>>>,
+}
+
+impl LogFilter {
+    /// Returns a filter matching every log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accepts logs emitted by `address`.
+    pub fn address(mut self, address: Address) -> Self {
+        self.address.push(address);
+        self
+    }
+
+    /// Accepts logs whose event signature hash (topic 0) is `signature_hash`.
+    pub fn event(self, signature_hash: FixedBytes<32>) -> Self {
+        self.topic(0, signature_hash)
+    }
+
+    /// Accepts logs whose topic at `index` is `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below [`MAX_LOG_TOPICS`], since no log has such a topic.
+    pub fn topic(mut self, index: usize, value: FixedBytes<32>) -> Self {
+        assert!(
+            index < MAX_LOG_TOPICS,
+            "log topic index {index} out of range, logs have at most {MAX_LOG_TOPICS} topics"
+        );
+        if self.topics.len() <= index {
+            self.topics.resize(index + 1, Vec::new());
+        }
+        self.topics[index].push(value);
+        self
+    }
+
+    /// Returns a filter matching the logs of the `sol!` event `E`.
+    ///
+    /// Anonymous events have no signature topic, so the filter matches every log.
+    #[cfg(feature = "sol-types")]
+    pub fn sol_event<E: alloy_sol_types::SolEvent>() -> Self {
+        if E::ANONYMOUS {
+            Self::new()
+        } else {
+            Self::new().event(E::SIGNATURE_HASH)
+        }
+    }
+
+    /// Returns `true` if a log emitted by `address` with `topics` passes the filter.
+    pub fn matches(&self, address: &Address, topics: &[FixedBytes<32>]) -> bool {
+        (self.address.is_empty() || self.address.contains(address))
+            && self.topics.iter().enumerate().all(|(index, accepted)| {
+                accepted.is_empty()
+                    || topics
+                        .get(index)
+                        .is_some_and(|topic| accepted.contains(topic))
+            })
+    }
+}
+
+/// Log from a verified receipt that matched the requested log filter.
+///
+/// `tx_hash` is verified by a transaction proof at the same index as the receipt.
+///
+/// The filter is chosen by whoever built the bundle, not by the verifier, so a bundle can
+/// drop logs by narrowing it. Check `filter` against the filter you expect. A receipt with no
+/// matching log yields no entry at all, so request the receipt itself to see every log.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedLog {
+    pub block: BlockRef,
+    pub tx_hash: FixedBytes<32>,
+    pub tx_index: u64,
+    /// Position of the log within its receipt.
+    pub log_index: u64,
+    pub address: Address,
+    pub topics: Vec<FixedBytes<32>>,
+    pub data: Bytes,
+    /// Filter the log was selected with.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub filter: LogFilter,
+}
+
+/// Verified header range, in ascending order and linked by parent hash.
+///
+/// Execution and OP Stack ranges hold every block from `start` to `end`. Beacon ranges skip
+/// missed slots, so they can hold fewer headers than slots.
+///
+/// `start` and `end` are taken from the proven headers, not from the request. A bundle can
+/// prove a shorter range than was asked for, so callers compare them with the bounds they
+/// requested.
+///
+/// With the `serde` feature, ranges of execution and beacon headers implement `Serialize` and
+/// `Deserialize`.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+pub struct VerifiedHeaderRange<H> {
+    pub network_id: u64,
+    /// First block number or slot of the range.
+    pub start: u64,
+    /// Last block number or slot of the range.
+    pub end: u64,
+    pub headers: Vec<H>,
+}
+
 /// Verified Ethereum data returned from batch verification.
 #[cfg_attr(feature = "std", derive(Debug, Default))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EvmResults {
     /// Verified execution headers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_headers",
+            deserialize_with = "consensus_serde::deserialize_headers"
+        )
+    )]
     pub execution_header: Vec<ExecutionHeader>,
     /// Verified beacon headers.
     pub beacon_header: Vec<BeaconHeader>,
+    /// Verified execution header ranges.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub execution_header_range: Vec<VerifiedHeaderRange<ExecutionHeader>>,
+    /// Verified beacon header ranges.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub beacon_header_range: Vec<VerifiedHeaderRange<BeaconHeader>>,
     /// Verified accounts with block and address identity.
     pub account: Vec<VerifiedAccount>,
     /// Verified storage slot values grouped by request with block and address identity.
     pub storage_slot: Vec<VerifiedStorageSlots>,
     /// Verified transactions with block and transaction identity.
     pub tx: Vec<VerifiedTransaction>,
-    /// Verified receipts with block and transaction identity.
+    /// Verified receipts with block and transaction index identity.
     pub receipt: Vec<VerifiedReceipt>,
+    /// Verified contract bytecode with block and address identity.
+    pub code: Vec<VerifiedCode>,
+    /// Verified execution payloads with their beacon slot.
+    pub execution_payload: Vec<VerifiedExecutionPayload>,
+    /// Verified validator records and balances with their beacon slot.
+    pub validator: Vec<VerifiedValidator>,
+    /// Receipt logs that matched their request filter, in request order.
+    pub log: Vec<VerifiedLog>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_filter_matches_addresses_and_topic_positions() {
+        let emitter = Address::repeat_byte(0x11);
+        let transfer = FixedBytes::from([0xdd; 32]);
+        let recipient = FixedBytes::from([0x22; 32]);
+        let topics = [transfer, FixedBytes::from([0x33; 32]), recipient];
+
+        assert!(LogFilter::new().matches(&emitter, &topics));
+        assert!(LogFilter::new()
+            .address(emitter)
+            .event(transfer)
+            .topic(2, recipient)
+            .matches(&emitter, &topics));
+        assert!(!LogFilter::new()
+            .address(Address::ZERO)
+            .matches(&emitter, &topics));
+        assert!(!LogFilter::new()
+            .topic(3, recipient)
+            .matches(&emitter, &topics));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn log_filter_rejects_topic_index_above_three() {
+        let _ = LogFilter::new().topic(MAX_LOG_TOPICS, FixedBytes::ZERO);
+    }
 }
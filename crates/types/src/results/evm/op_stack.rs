@@ -2,22 +2,220 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 
+use alloy_primitives::{FixedBytes, B256};
+pub use op_alloy_consensus::{OpReceiptEnvelope, OpTxEnvelope, TxDeposit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::results::evm::consensus_serde;
 use crate::results::evm::{
-    execution::ExecutionHeader, VerifiedAccount, VerifiedReceipt, VerifiedStorageSlots,
-    VerifiedTransaction,
+    execution::ExecutionHeader, BlockRef, VerifiedAccount, VerifiedCode, VerifiedHeaderRange,
+    VerifiedLog, VerifiedStorageSlots,
 };
 
+/// Verified OP Stack transaction result with the request identity preserved.
+///
+/// Unlike Ethereum transactions, OP Stack blocks contain deposit transactions (type `0x7e`).
+/// Use the deposit accessors to read their fields.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedOpTransaction {
+    pub block: BlockRef,
+    /// Transaction hash recomputed from the verified transaction payload.
+    pub tx_hash: FixedBytes<32>,
+    pub tx_index: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_envelope",
+            deserialize_with = "consensus_serde::deserialize_envelope"
+        )
+    )]
+    pub tx: OpTxEnvelope,
+}
+
+impl VerifiedOpTransaction {
+    /// Returns the deposit transaction, if this is a deposit.
+    pub fn deposit(&self) -> Option<&TxDeposit> {
+        match &self.tx {
+            OpTxEnvelope::Deposit(tx) => Some(tx.inner()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` for deposit transactions.
+    pub fn is_deposit(&self) -> bool {
+        self.deposit().is_some()
+    }
+
+    /// Returns the deposit source hash.
+    pub fn source_hash(&self) -> Option<B256> {
+        self.deposit().map(|tx| tx.source_hash)
+    }
+
+    /// Returns the ETH value minted on L2 by the deposit.
+    pub fn mint(&self) -> Option<u128> {
+        self.deposit().map(|tx| tx.mint)
+    }
+
+    /// Returns whether the deposit is a system transaction.
+    pub fn is_system_tx(&self) -> Option<bool> {
+        self.deposit().map(|tx| tx.is_system_transaction)
+    }
+}
+
+/// Verified OP Stack receipt result with the request identity preserved.
+///
+/// `tx_hash` follows the same rules as [`crate::results::evm::VerifiedReceipt`].
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VerifiedOpReceipt {
+    pub block: BlockRef,
+    pub tx_hash: Option<FixedBytes<32>>,
+    pub tx_index: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_envelope",
+            deserialize_with = "consensus_serde::deserialize_envelope"
+        )
+    )]
+    pub receipt: OpReceiptEnvelope,
+}
+
+impl VerifiedOpReceipt {
+    /// Returns `true` for deposit receipts.
+    pub fn is_deposit(&self) -> bool {
+        matches!(self.receipt, OpReceiptEnvelope::Deposit(_))
+    }
+
+    /// Returns the deposit nonce (post-Regolith deposit receipts only).
+    pub fn deposit_nonce(&self) -> Option<u64> {
+        match &self.receipt {
+            OpReceiptEnvelope::Deposit(receipt) => receipt.receipt.deposit_nonce,
+            _ => None,
+        }
+    }
+
+    /// Returns the deposit receipt version (post-Canyon deposit receipts only).
+    pub fn deposit_receipt_version(&self) -> Option<u64> {
+        match &self.receipt {
+            OpReceiptEnvelope::Deposit(receipt) => receipt.receipt.deposit_receipt_version,
+            _ => None,
+        }
+    }
+}
+
 /// Verified OP Stack data returned from batch verification.
 #[cfg_attr(feature = "std", derive(Debug, Default))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OpStackResults {
     /// Verified OP Stack headers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "consensus_serde::serialize_headers",
+            deserialize_with = "consensus_serde::deserialize_headers"
+        )
+    )]
     pub header: Vec<ExecutionHeader>,
+    /// Verified OP Stack header ranges.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub header_range: Vec<VerifiedHeaderRange<ExecutionHeader>>,
     /// Verified OP Stack accounts with block and address identity.
     pub account: Vec<VerifiedAccount>,
     /// Verified OP Stack storage slot values grouped by request with block and address identity.
     pub storage_slot: Vec<VerifiedStorageSlots>,
-    /// Verified OP Stack transactions with block and transaction identity.
-    pub tx: Vec<VerifiedTransaction>,
-    /// Verified OP Stack receipts with block and transaction identity.
-    pub receipt: Vec<VerifiedReceipt>,
+    /// Verified OP Stack transactions, including deposits, with block and transaction identity.
+    pub tx: Vec<VerifiedOpTransaction>,
+    /// Verified OP Stack receipts, including deposits, with block and transaction index identity.
+    pub receipt: Vec<VerifiedOpReceipt>,
+    /// Verified OP Stack contract bytecode with block and address identity.
+    pub code: Vec<VerifiedCode>,
+    /// OP Stack receipt logs that matched their request filter, in request order.
+    pub log: Vec<VerifiedLog>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, Bytes, TxKind, U256};
+    use op_alloy_consensus::OpTxType;
+
+    fn block() -> BlockRef {
+        BlockRef {
+            network_id: 10,
+            block_number: 12,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn exposes_deposit_transaction_fields() {
+        let result = VerifiedOpTransaction {
+            block: block(),
+            tx_hash: FixedBytes::ZERO,
+            tx_index: 0,
+            tx: OpTxEnvelope::from(TxDeposit {
+                source_hash: B256::repeat_byte(0xbb),
+                from: Address::repeat_byte(0x33),
+                to: TxKind::Call(Address::repeat_byte(0x44)),
+                mint: 5,
+                value: U256::from(7_u64),
+                gas_limit: 50_000,
+                is_system_transaction: true,
+                input: Bytes::new(),
+            }),
+        };
+
+        assert!(result.is_deposit());
+        assert_eq!(result.source_hash(), Some(B256::repeat_byte(0xbb)));
+        assert_eq!(result.mint(), Some(5));
+        assert_eq!(result.is_system_tx(), Some(true));
+    }
+
+    #[test]
+    fn exposes_deposit_receipt_fields() {
+        let result = VerifiedOpReceipt {
+            block: block(),
+            tx_hash: None,
+            tx_index: 0,
+            receipt: OpReceiptEnvelope::from_parts(
+                true,
+                21_000,
+                [].iter(),
+                OpTxType::Deposit,
+                Some(1),
+                Some(2),
+            ),
+        };
+
+        assert!(result.is_deposit());
+        assert_eq!(result.deposit_nonce(), Some(1));
+        assert_eq!(result.deposit_receipt_version(), Some(2));
+    }
+
+    #[test]
+    fn non_deposit_receipt_has_no_deposit_fields() {
+        let result = VerifiedOpReceipt {
+            block: block(),
+            tx_hash: None,
+            tx_index: 0,
+            receipt: OpReceiptEnvelope::from_parts(
+                true,
+                21_000,
+                [].iter(),
+                OpTxType::Eip1559,
+                None,
+                None,
+            ),
+        };
+
+        assert!(!result.is_deposit());
+        assert_eq!(result.deposit_nonce(), None);
+    }
 }
@@ -0,0 +1,36 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use alloy_primitives::{FixedBytes, U256};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// JSON shape of a verified storage slot: the slot key as a 32-byte hex word and the value as
+/// a decimal string.
+#[derive(Serialize, Deserialize)]
+struct StorageSlotJson {
+    slot: FixedBytes<32>,
+    value: String,
+}
+
+pub fn serialize_storage_slots<S>(slots: &[(U256, U256)], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(slots.iter().map(|(slot, value)| StorageSlotJson {
+        slot: FixedBytes::from(slot.to_be_bytes::<32>()),
+        value: value.to_string(),
+    }))
+}
+
+pub fn deserialize_storage_slots<'de, D>(deserializer: D) -> Result<Vec<(U256, U256)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<StorageSlotJson>::deserialize(deserializer)?
+        .into_iter()
+        .map(|entry| {
+            let value = U256::from_str_radix(&entry.value, 10).map_err(serde::de::Error::custom)?;
+            Ok((U256::from_be_bytes(entry.slot.0), value))
+        })
+        .collect()
+}
@@ -0,0 +1,191 @@
+//! Typed ABI decoding of verified logs and storage slots.
+//!
+//! Requires the `sol-types` feature. Events and values are declared with the `sol!` macro from
+//! `alloy-sol-types`.
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use alloy_primitives::U256;
+use alloy_sol_types::{SolEvent, SolType};
+
+use crate::results::evm::op_stack::{OpStackResults, VerifiedOpReceipt};
+use crate::results::evm::{EvmResults, VerifiedLog, VerifiedReceipt, VerifiedStorageSlots};
+use crate::results::BatchResults;
+
+/// Event decoded from a verified log.
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct DecodedEvent<'a, E> {
+    /// The verified log the event was decoded from.
+    pub log: &'a VerifiedLog,
+    pub event: E,
+}
+
+impl VerifiedLog {
+    /// Decodes the log as `E`, or returns `None` if it is not an `E` event.
+    pub fn decode<E: SolEvent>(&self) -> Option<E> {
+        E::decode_raw_log(self.topics.iter().copied(), &self.data).ok()
+    }
+}
+
+impl VerifiedReceipt {
+    /// Decodes every log of the receipt that is an `E` event, in log order.
+    pub fn decode_events<E: SolEvent>(&self) -> Vec<E> {
+        self.receipt
+            .logs()
+            .iter()
+            .filter_map(|log| E::decode_log_data(&log.data).ok())
+            .collect()
+    }
+}
+
+impl VerifiedOpReceipt {
+    /// Decodes every log of the receipt that is an `E` event, in log order.
+    pub fn decode_events<E: SolEvent>(&self) -> Vec<E> {
+        self.receipt
+            .logs()
+            .iter()
+            .filter_map(|log| E::decode_log_data(&log.data).ok())
+            .collect()
+    }
+}
+
+impl VerifiedStorageSlots {
+    /// Decodes the value of the slot `key` as a single-word Solidity type.
+    ///
+    /// Returns `None` if the slot was not requested or the word is not a valid `T` value,
+    /// for example when several variables are packed into the slot.
+    pub fn decode_slot<T: SolType>(&self, key: U256) -> Option<T::RustType> {
+        let (_, value) = self.slots.iter().find(|(slot_key, _)| *slot_key == key)?;
+        T::abi_decode_validate(&value.to_be_bytes::<32>()).ok()
+    }
+}
+
+impl EvmResults {
+    /// Decodes every verified log that is an `E` event, in result order.
+    pub fn decode_events<E: SolEvent>(&self) -> Vec<DecodedEvent<'_, E>> {
+        decode_logs(&self.log)
+    }
+}
+
+impl OpStackResults {
+    /// Decodes every verified OP Stack log that is an `E` event, in result order.
+    pub fn decode_events<E: SolEvent>(&self) -> Vec<DecodedEvent<'_, E>> {
+        decode_logs(&self.log)
+    }
+}
+
+impl BatchResults {
+    /// Decodes every verified Ethereum and OP Stack log that is an `E` event.
+    ///
+    /// Ethereum events come first, followed by OP Stack events.
+    pub fn decode_events<E: SolEvent>(&self) -> Vec<DecodedEvent<'_, E>> {
+        let mut events = self.evm.decode_events();
+        events.extend(self.op_stack.decode_events());
+        events
+    }
+}
+
+fn decode_logs<E: SolEvent>(logs: &[VerifiedLog]) -> Vec<DecodedEvent<'_, E>> {
+    logs.iter()
+        .filter_map(|log| log.decode().map(|event| DecodedEvent { log, event }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{Address, Bytes, FixedBytes};
+    use alloy_sol_types::{sol, sol_data};
+
+    use super::*;
+    use crate::results::evm::{BlockRef, LogFilter};
+
+    sol! {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+    }
+
+    fn transfer_log(value: u64) -> VerifiedLog {
+        let event = Transfer {
+            from: Address::repeat_byte(0x11),
+            to: Address::repeat_byte(0x22),
+            value: U256::from(value),
+        };
+        let data = event.encode_log_data();
+        VerifiedLog {
+            block: BlockRef {
+                network_id: 1,
+                block_number: 7,
+                ..Default::default()
+            },
+            tx_hash: FixedBytes::from([3u8; 32]),
+            tx_index: 0,
+            log_index: 1,
+            address: Address::repeat_byte(0x44),
+            topics: data.topics().to_vec(),
+            data: data.data,
+            filter: LogFilter::sol_event::<Transfer>(),
+        }
+    }
+
+    #[test]
+    fn decodes_matching_events_only() {
+        let results = EvmResults {
+            log: vec![
+                transfer_log(5),
+                VerifiedLog {
+                    topics: vec![Approval::SIGNATURE_HASH],
+                    data: Bytes::new(),
+                    ..transfer_log(0)
+                },
+                transfer_log(9),
+            ],
+            ..Default::default()
+        };
+
+        let events = results.decode_events::<Transfer>();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event.value, U256::from(5u64));
+        assert_eq!(events[0].event.to, Address::repeat_byte(0x22));
+        assert_eq!(events[1].log.log_index, 1);
+        assert!(results.log[1].decode::<Approval>().is_none());
+    }
+
+    #[test]
+    fn decodes_single_word_storage_values() {
+        let owner = Address::repeat_byte(0x55);
+        let slots = VerifiedStorageSlots {
+            block: BlockRef {
+                network_id: 1,
+                block_number: 7,
+                ..Default::default()
+            },
+            address: Address::ZERO,
+            slots: vec![
+                (U256::ZERO, U256::from_be_slice(owner.as_slice())),
+                (U256::from(1u64), U256::MAX),
+            ],
+        };
+
+        assert_eq!(
+            slots.decode_slot::<sol_data::Address>(U256::ZERO),
+            Some(owner)
+        );
+        assert_eq!(
+            slots.decode_slot::<sol_data::Uint<256>>(U256::from(1u64)),
+            Some(U256::MAX)
+        );
+        assert_eq!(
+            slots.decode_slot::<sol_data::Address>(U256::from(1u64)),
+            None
+        );
+        assert_eq!(
+            slots.decode_slot::<sol_data::Address>(U256::from(2u64)),
+            None
+        );
+    }
+}
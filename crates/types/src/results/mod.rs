@@ -1,4 +1,22 @@
 //! Verified outputs returned by the verification crate.
+//!
+//! With the `serde` feature, every result type implements `Serialize` and `Deserialize` with a
+//! stable JSON shape:
+//!
+//! - field names are `snake_case`, as in the Rust types, including the fields of execution
+//!   headers and accounts
+//! - hashes, addresses, public keys and byte strings are `0x`-prefixed hex strings
+//! - `u64` quantities (block numbers, slots, indices, gas, balances in Gwei) are JSON numbers
+//! - 256-bit quantities (storage values, account balances, header difficulty) are decimal
+//!   strings
+//! - finality is `"finalized"`, `"justified"` or `"latest"`
+//! - storage slots are objects with a 32-byte hex `slot` and a decimal string `value`
+//! - transactions and receipts are their EIP-2718 encoding, as a hex byte string
+//!
+//! New fields may be added. Existing fields are not renamed or re-encoded.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::results::evm::{op_stack::OpStackResults, EvmResults};
 
@@ -6,9 +24,230 @@ pub mod evm;
 
 /// Verified results returned by [`bankai_verify::verify_batch_proof`].
 #[cfg_attr(feature = "std", derive(Debug, Default))]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct BatchResults {
     /// Verified Ethereum execution, beacon, and state data.
     pub evm: EvmResults,
     /// Verified OP Stack headers and state data.
     pub op_stack: OpStackResults,
 }
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use alloc::vec;
+
+    use alloy_consensus::{Signed, TxLegacy};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_primitives::{Address, Bytes, FixedBytes, Signature, U256};
+    use serde_json::json;
+
+    use super::*;
+    use crate::common::Finality;
+    use crate::results::evm::execution::{ExecutionHeader, TrieAccount, TxEnvelope};
+    use crate::results::evm::{
+        BlockRef, LogFilter, VerifiedAccount, VerifiedLog, VerifiedStorageSlots,
+        VerifiedTransaction,
+    };
+
+    fn block() -> BlockRef {
+        BlockRef {
+            network_id: 1,
+            block_number: 21_000_000,
+            block_hash: FixedBytes::from([0xaa; 32]),
+            timestamp: 1_700_000_000,
+            state_root: FixedBytes::from([0xbb; 32]),
+            finality: Finality::Finalized,
+            bankai_block_number: 42,
+            bankai_block_hash: FixedBytes::from([0xcc; 32]),
+        }
+    }
+
+    #[test]
+    fn storage_slots_use_hex_keys_and_decimal_values() {
+        let slots = VerifiedStorageSlots {
+            block: block(),
+            address: Address::repeat_byte(0x11),
+            slots: vec![(U256::from(2u64), U256::from(1_000_000u64))],
+        };
+
+        let value = serde_json::to_value(&slots).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "block": {
+                    "network_id": 1,
+                    "block_number": 21_000_000,
+                    "block_hash": "0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+                    "timestamp": 1_700_000_000,
+                    "state_root": "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+                    "finality": "finalized",
+                    "bankai_block_number": 42,
+                    "bankai_block_hash": "0xcccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccc"
+                },
+                "address": "0x1111111111111111111111111111111111111111",
+                "slots": [{
+                    "slot": "0x0000000000000000000000000000000000000000000000000000000000000002",
+                    "value": "1000000"
+                }]
+            })
+        );
+
+        let decoded: VerifiedStorageSlots = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded.slots, slots.slots);
+    }
+
+    #[test]
+    fn embedded_consensus_types_use_result_shape() {
+        let header = ExecutionHeader {
+            number: 21_000_000,
+            difficulty: U256::from(131_072u64),
+            base_fee_per_gas: Some(7),
+            ..Default::default()
+        };
+        let account = TrieAccount {
+            nonce: 1,
+            balance: U256::from(10u128.pow(20)),
+            storage_root: FixedBytes::from([3u8; 32]),
+            code_hash: FixedBytes::from([4u8; 32]),
+        };
+        let tx = TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy {
+                nonce: 3,
+                gas_limit: 21_000,
+                ..Default::default()
+            },
+            Signature::new(U256::from(1u64), U256::from(2u64), false),
+            FixedBytes::ZERO,
+        ));
+        let mut results = BatchResults::default();
+        results.evm.execution_header.push(header.clone());
+        results.evm.account.push(VerifiedAccount {
+            block: block(),
+            address: Address::repeat_byte(0x11),
+            exists: true,
+            account,
+        });
+        results.evm.tx.push(VerifiedTransaction {
+            block: block(),
+            tx_hash: FixedBytes::from([2u8; 32]),
+            tx_index: 0,
+            tx: tx.clone(),
+        });
+
+        let value = serde_json::to_value(&results).unwrap();
+        let header_json = &value["evm"]["execution_header"][0];
+        assert_eq!(header_json["number"], json!(21_000_000));
+        assert_eq!(header_json["difficulty"], json!("131072"));
+        assert_eq!(header_json["base_fee_per_gas"], json!(7));
+        assert!(header_json.get("baseFeePerGas").is_none());
+        assert_eq!(
+            value["evm"]["account"][0]["account"]["balance"],
+            json!("100000000000000000000")
+        );
+        assert_eq!(
+            value["evm"]["tx"][0]["tx"],
+            json!(Bytes::from(tx.encoded_2718()))
+        );
+
+        let decoded: BatchResults = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(decoded.evm.execution_header[0], header);
+        assert_eq!(decoded.evm.account[0].account, account);
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), value);
+    }
+
+    #[test]
+    fn batch_results_round_trip() {
+        let mut results = BatchResults::default();
+        results.op_stack.log.push(VerifiedLog {
+            block: block(),
+            tx_hash: FixedBytes::from([2u8; 32]),
+            tx_index: 4,
+            log_index: 0,
+            address: Address::repeat_byte(0x22),
+            topics: vec![FixedBytes::from([3u8; 32])],
+            data: Bytes::from(vec![0xab]),
+            filter: LogFilter::new().address(Address::repeat_byte(0x22)),
+        });
+
+        let json = serde_json::to_string(&results).unwrap();
+        let decoded: BatchResults = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(decoded.op_stack.log.len(), 1);
+        assert_eq!(decoded.op_stack.log[0].data, Bytes::from(vec![0xab]));
+        assert!(decoded.evm.execution_header.is_empty());
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+    }
+
+    #[test]
+    fn results_without_later_fields_still_deserialize() {
+        // Shape written before header ranges, header context and log filters were added.
+        let value = json!({
+            "evm": {
+                "execution_header": [],
+                "beacon_header": [],
+                "account": [],
+                "storage_slot": [{
+                    "block": { "network_id": 1, "block_number": 21_000_000 },
+                    "address": "0x1111111111111111111111111111111111111111",
+                    "slots": [{
+                        "slot": "0x0000000000000000000000000000000000000000000000000000000000000002",
+                        "value": "1000000"
+                    }]
+                }],
+                "tx": [],
+                "receipt": [],
+                "code": [],
+                "execution_payload": [{
+                    "network_id": 0,
+                    "slot": 100,
+                    "block_number": 21_000_000,
+                    "block_hash": "0x1212121212121212121212121212121212121212121212121212121212121212",
+                    "state_root": "0x2222222222222222222222222222222222222222222222222222222222222222",
+                    "timestamp": 1_700_000_000
+                }],
+                "validator": [],
+                "log": []
+            },
+            "op_stack": {
+                "header": [],
+                "account": [],
+                "storage_slot": [],
+                "tx": [],
+                "receipt": [],
+                "code": [],
+                "log": [{
+                    "block": { "network_id": 10, "block_number": 12 },
+                    "tx_hash": "0x0202020202020202020202020202020202020202020202020202020202020202",
+                    "tx_index": 4,
+                    "log_index": 0,
+                    "address": "0x2222222222222222222222222222222222222222",
+                    "topics": [],
+                    "data": "0xab"
+                }]
+            }
+        });
+
+        let decoded: BatchResults = serde_json::from_value(value).unwrap();
+
+        let slots = &decoded.evm.storage_slot[0];
+        assert_eq!(
+            slots.block,
+            BlockRef {
+                network_id: 1,
+                block_number: 21_000_000,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            slots.slots,
+            vec![(U256::from(2u64), U256::from(1_000_000u64))]
+        );
+        assert_eq!(decoded.evm.execution_payload[0].slot, 100);
+        assert_eq!(decoded.evm.execution_payload[0].block, BlockRef::default());
+        assert!(decoded.evm.execution_header_range.is_empty());
+        assert!(decoded.op_stack.header_range.is_empty());
+        assert_eq!(decoded.op_stack.log[0].block.network_id, 10);
+        assert_eq!(decoded.op_stack.log[0].filter, LogFilter::new());
+    }
+}
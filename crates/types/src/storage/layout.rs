@@ -0,0 +1,273 @@
+//! Storage locations resolved from the solc `storageLayout` output.
+//!
+//! Compile with `solc --storage-layout` (or `forge inspect <Contract> storageLayout`) and load
+//! the JSON with [`StorageLayout::from_json`].
+
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use alloy_primitives::U256;
+use serde::{Deserialize, Deserializer};
+
+use crate::storage::{MappingKey, StorageLocation};
+
+/// Contract storage layout as emitted by solc.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StorageLayout {
+    pub storage: Vec<StorageEntry>,
+    #[serde(default)]
+    pub types: Option<BTreeMap<String, StorageType>>,
+}
+
+/// A state variable or struct member.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StorageEntry {
+    pub label: String,
+    pub offset: u8,
+    #[serde(deserialize_with = "from_decimal")]
+    pub slot: U256,
+    #[serde(rename = "type")]
+    pub type_id: String,
+}
+
+/// How a type is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageEncoding {
+    /// Stored in consecutive slots at the variable location, possibly packed.
+    Inplace,
+    /// Values are stored at the hash of the key and the mapping slot.
+    Mapping,
+    /// The length is stored at the variable location and elements at its hash.
+    DynamicArray,
+    /// `bytes` or `string`.
+    Bytes,
+}
+
+/// A type of the storage layout.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageType {
+    pub encoding: StorageEncoding,
+    pub label: String,
+    #[serde(deserialize_with = "from_decimal")]
+    pub number_of_bytes: u64,
+    /// Mapping key type.
+    pub key: Option<String>,
+    /// Mapping value type.
+    pub value: Option<String>,
+    /// Array element type.
+    pub base: Option<String>,
+    /// Struct members, with slots relative to the struct.
+    pub members: Option<Vec<StorageEntry>>,
+}
+
+impl StorageLayout {
+    /// Parses the solc `storageLayout` JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the path to the state variable `label`.
+    pub fn variable(&self, label: &str) -> Option<LayoutPath<'_>> {
+        let entry = self.storage.iter().find(|entry| entry.label == label)?;
+        Some(LayoutPath {
+            layout: self,
+            type_id: &entry.type_id,
+            location: StorageLocation::packed(entry.slot, entry.offset),
+        })
+    }
+
+    /// Returns the type `type_id`.
+    pub fn storage_type(&self, type_id: &str) -> Option<&StorageType> {
+        self.types.as_ref()?.get(type_id)
+    }
+}
+
+/// A typed location in a [`StorageLayout`].
+///
+/// Navigate into mappings, arrays and structs until the value of interest, then request its
+/// [`LayoutPath::location`] slot.
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutPath<'a> {
+    layout: &'a StorageLayout,
+    type_id: &'a str,
+    location: StorageLocation,
+}
+
+impl<'a> LayoutPath<'a> {
+    /// Returns the storage location of the value.
+    pub fn location(&self) -> StorageLocation {
+        self.location
+    }
+
+    /// Returns the type of the value.
+    pub fn storage_type(&self) -> Option<&'a StorageType> {
+        self.layout.storage_type(self.type_id)
+    }
+
+    /// Returns the value stored under `key`, if this is a mapping.
+    pub fn key(self, key: impl Into<MappingKey>) -> Option<Self> {
+        let ty = self.storage_type()?;
+        if ty.encoding != StorageEncoding::Mapping {
+            return None;
+        }
+        Some(self.step(ty.value.as_deref()?, self.location.mapping(key)))
+    }
+
+    /// Returns element `index`, if this is a static or dynamic array.
+    ///
+    /// Static array elements must fall within the slots of the array. Dynamic array lengths are
+    /// only known on chain and are not checked.
+    pub fn index(self, index: u64) -> Option<Self> {
+        let ty = self.storage_type()?;
+        let base = ty.base.as_deref()?;
+        let element_bytes = self.layout.storage_type(base)?.number_of_bytes;
+
+        let location = match ty.encoding {
+            StorageEncoding::DynamicArray => self.location.dynamic_index(index, element_bytes),
+            StorageEncoding::Inplace => {
+                let location = self.location.index(index, element_bytes);
+                let end = self.location.slot + U256::from(ty.number_of_bytes.div_ceil(32));
+                if location.slot >= end {
+                    return None;
+                }
+                location
+            }
+            _ => return None,
+        };
+        Some(self.step(base, location))
+    }
+
+    /// Returns the struct member `label`, if this is a struct.
+    pub fn member(self, label: &str) -> Option<Self> {
+        let member = self
+            .storage_type()?
+            .members
+            .as_ref()?
+            .iter()
+            .find(|member| member.label == label)?;
+        let location = StorageLocation::packed(self.location.slot + member.slot, member.offset);
+        Some(self.step(&member.type_id, location))
+    }
+
+    fn step(self, type_id: &'a str, location: StorageLocation) -> Self {
+        Self {
+            layout: self.layout,
+            type_id,
+            location,
+        }
+    }
+}
+
+fn from_decimal<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: core::fmt::Display,
+{
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+
+    use super::*;
+
+    // contract Vault {
+    //     address owner; bool paused; mapping(address => uint256) balances;
+    //     struct Position { uint128 size; uint64 opened; uint256 debt; }
+    //     mapping(address => Position[]) positions; uint32[10] limits; string name;
+    // }
+    const LAYOUT: &str = r#"{
+        "storage": [
+            {"astId": 3, "contract": "Vault", "label": "owner", "offset": 0, "slot": "0", "type": "t_address"},
+            {"astId": 5, "contract": "Vault", "label": "paused", "offset": 20, "slot": "0", "type": "t_bool"},
+            {"astId": 9, "contract": "Vault", "label": "balances", "offset": 0, "slot": "1", "type": "t_mapping(t_address,t_uint256)"},
+            {"astId": 21, "contract": "Vault", "label": "positions", "offset": 0, "slot": "2", "type": "t_mapping(t_address,t_array(t_struct(Position)17_storage)dyn_storage)"},
+            {"astId": 25, "contract": "Vault", "label": "limits", "offset": 0, "slot": "3", "type": "t_array(t_uint32)10_storage"},
+            {"astId": 27, "contract": "Vault", "label": "name", "offset": 0, "slot": "5", "type": "t_string_storage"}
+        ],
+        "types": {
+            "t_address": {"encoding": "inplace", "label": "address", "numberOfBytes": "20"},
+            "t_bool": {"encoding": "inplace", "label": "bool", "numberOfBytes": "1"},
+            "t_uint32": {"encoding": "inplace", "label": "uint32", "numberOfBytes": "4"},
+            "t_uint64": {"encoding": "inplace", "label": "uint64", "numberOfBytes": "8"},
+            "t_uint128": {"encoding": "inplace", "label": "uint128", "numberOfBytes": "16"},
+            "t_uint256": {"encoding": "inplace", "label": "uint256", "numberOfBytes": "32"},
+            "t_string_storage": {"encoding": "bytes", "label": "string", "numberOfBytes": "32"},
+            "t_array(t_uint32)10_storage": {"base": "t_uint32", "encoding": "inplace", "label": "uint32[10]", "numberOfBytes": "64"},
+            "t_array(t_struct(Position)17_storage)dyn_storage": {"base": "t_struct(Position)17_storage", "encoding": "dynamic_array", "label": "struct Vault.Position[]", "numberOfBytes": "32"},
+            "t_mapping(t_address,t_uint256)": {"encoding": "mapping", "key": "t_address", "label": "mapping(address => uint256)", "numberOfBytes": "32", "value": "t_uint256"},
+            "t_mapping(t_address,t_array(t_struct(Position)17_storage)dyn_storage)": {"encoding": "mapping", "key": "t_address", "label": "mapping(address => struct Vault.Position[])", "numberOfBytes": "32", "value": "t_array(t_struct(Position)17_storage)dyn_storage"},
+            "t_struct(Position)17_storage": {"encoding": "inplace", "label": "struct Vault.Position", "numberOfBytes": "64", "members": [
+                {"astId": 12, "contract": "Vault", "label": "size", "offset": 0, "slot": "0", "type": "t_uint128"},
+                {"astId": 14, "contract": "Vault", "label": "opened", "offset": 16, "slot": "0", "type": "t_uint64"},
+                {"astId": 16, "contract": "Vault", "label": "debt", "offset": 0, "slot": "1", "type": "t_uint256"}
+            ]}
+        }
+    }"#;
+
+    #[test]
+    fn resolves_packed_variables_and_mappings() {
+        let layout = StorageLayout::from_json(LAYOUT).unwrap();
+        let user = Address::repeat_byte(0x11);
+
+        assert_eq!(
+            layout.variable("paused").unwrap().location(),
+            StorageLocation::packed(0u64, 20)
+        );
+        assert_eq!(
+            layout
+                .variable("balances")
+                .and_then(|path| path.key(user))
+                .unwrap()
+                .location(),
+            StorageLocation::new(1u64).mapping(user)
+        );
+        assert!(layout.variable("owner").unwrap().key(user).is_none());
+    }
+
+    #[test]
+    fn resolves_struct_members_in_dynamic_arrays() {
+        let layout = StorageLayout::from_json(LAYOUT).unwrap();
+        let user = Address::repeat_byte(0x11);
+        let position = layout
+            .variable("positions")
+            .and_then(|path| path.key(user))
+            .and_then(|path| path.index(2))
+            .unwrap();
+        let start = StorageLocation::new(2u64).mapping(user).data_slot();
+
+        assert_eq!(
+            position.member("opened").unwrap().location(),
+            StorageLocation::packed(start + U256::from(4u64), 16)
+        );
+        assert_eq!(
+            position.member("debt").unwrap().location(),
+            StorageLocation::new(start + U256::from(5u64))
+        );
+    }
+
+    #[test]
+    fn bounds_checks_static_arrays() {
+        let layout = StorageLayout::from_json(LAYOUT).unwrap();
+        let limits = layout.variable("limits").unwrap();
+
+        assert_eq!(
+            limits.index(9).unwrap().location(),
+            StorageLocation::packed(4u64, 4)
+        );
+        assert!(limits.index(16).is_none());
+        assert_eq!(
+            limits.storage_type().unwrap().encoding,
+            StorageEncoding::Inplace
+        );
+    }
+}
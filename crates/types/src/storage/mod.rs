@@ -0,0 +1,421 @@
+//! Solidity storage layout helpers.
+//!
+//! Computes the storage slots of state variables, mapping values, array elements and struct
+//! members, following the layout rules of the Solidity compiler. The resulting slot keys can be
+//! requested with `ProofBatchBuilder::ethereum_storage_slot` and read back from verified
+//! storage results.
+//!
+//! With the `serde_json` feature, [`layout::StorageLayout`] resolves locations from the solc
+//! `storageLayout` output instead of hand-written slot numbers.
+
+extern crate alloc;
+
+#[cfg(feature = "results")]
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "results")]
+use alloy_primitives::Bytes;
+use alloy_primitives::{keccak256, Address, FixedBytes, U256};
+
+#[cfg(feature = "results")]
+use crate::results::evm::VerifiedStorageSlots;
+
+#[cfg(feature = "serde_json")]
+pub mod layout;
+
+/// Number of bytes in a storage slot.
+pub const SLOT_BYTES: u64 = 32;
+
+/// Longest `bytes`/`string` value read from storage, in bytes.
+///
+/// Bounds the data slots requested for a value, whose length comes from an untrusted word.
+pub const MAX_BYTES_LENGTH: u64 = 32 * 1024;
+
+/// Location of a value in contract storage.
+///
+/// `offset` is the byte offset of a packed value within `slot`, counted from the low-order end
+/// of the word as in the solc storage layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageLocation {
+    pub slot: U256,
+    pub offset: u8,
+}
+
+impl StorageLocation {
+    /// Returns the location of a state variable that starts its own slot.
+    pub fn new(slot: impl Into<U256>) -> Self {
+        Self::packed(slot, 0)
+    }
+
+    /// Returns the location of a state variable packed at `offset` within `slot`.
+    pub fn packed(slot: impl Into<U256>, offset: u8) -> Self {
+        Self {
+            slot: slot.into(),
+            offset,
+        }
+    }
+
+    /// Returns the location of the value stored under `key` in the mapping at this location.
+    pub fn mapping(self, key: impl Into<MappingKey>) -> Self {
+        let mut preimage = key.into().encode();
+        preimage.extend_from_slice(&self.slot.to_be_bytes::<32>());
+        Self::new(U256::from_be_bytes(keccak256(preimage).0))
+    }
+
+    /// Returns the location of element `index` of the static array at this location.
+    ///
+    /// `element_bytes` is the size of one element. Elements smaller than a slot are packed.
+    pub fn index(self, index: u64, element_bytes: u64) -> Self {
+        let element_bytes = element_bytes.max(1);
+        if element_bytes >= SLOT_BYTES {
+            let element_slots = element_bytes.div_ceil(SLOT_BYTES);
+            return Self::new(self.slot + U256::from(index) * U256::from(element_slots));
+        }
+
+        let per_slot = SLOT_BYTES / element_bytes;
+        Self::packed(
+            self.slot + U256::from(index / per_slot),
+            ((index % per_slot) * element_bytes) as u8,
+        )
+    }
+
+    /// Returns the location of element `index` of the dynamic array at this location.
+    ///
+    /// The slot at this location holds the array length. Elements start at its hash.
+    pub fn dynamic_index(self, index: u64, element_bytes: u64) -> Self {
+        Self::new(self.data_slot()).index(index, element_bytes)
+    }
+
+    /// Returns the location of a struct member at `slot` and `offset` relative to this struct.
+    pub fn member(self, slot: u64, offset: u8) -> Self {
+        Self::packed(self.slot + U256::from(slot), offset)
+    }
+
+    /// Returns the first data slot of a dynamic array or long `bytes`/`string` value here.
+    pub fn data_slot(&self) -> U256 {
+        U256::from_be_bytes(keccak256(self.slot.to_be_bytes::<32>()).0)
+    }
+}
+
+/// Key of a Solidity mapping.
+///
+/// Value types are hashed as a padded 32-byte word. `bytes` and `string` keys are hashed as
+/// their raw bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingKey {
+    Word(FixedBytes<32>),
+    Bytes(Vec<u8>),
+}
+
+impl MappingKey {
+    /// Returns the bytes hashed together with the mapping slot.
+    pub fn encode(self) -> Vec<u8> {
+        match self {
+            Self::Word(word) => word.to_vec(),
+            Self::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+impl From<FixedBytes<32>> for MappingKey {
+    fn from(word: FixedBytes<32>) -> Self {
+        Self::Word(word)
+    }
+}
+
+impl From<U256> for MappingKey {
+    fn from(value: U256) -> Self {
+        Self::Word(FixedBytes::from(value.to_be_bytes::<32>()))
+    }
+}
+
+impl From<u64> for MappingKey {
+    fn from(value: u64) -> Self {
+        U256::from(value).into()
+    }
+}
+
+impl From<Address> for MappingKey {
+    fn from(address: Address) -> Self {
+        Self::Word(address.into_word())
+    }
+}
+
+impl From<bool> for MappingKey {
+    fn from(value: bool) -> Self {
+        U256::from(value as u8).into()
+    }
+}
+
+impl From<&str> for MappingKey {
+    fn from(value: &str) -> Self {
+        Self::Bytes(value.as_bytes().to_vec())
+    }
+}
+
+impl From<&[u8]> for MappingKey {
+    fn from(value: &[u8]) -> Self {
+        Self::Bytes(value.to_vec())
+    }
+}
+
+/// Reads a value of `bytes` bytes packed at `offset` within a storage word.
+pub fn extract(word: U256, offset: u8, bytes: u8) -> U256 {
+    let shifted = word >> (usize::from(offset) * 8);
+    if bytes >= 32 {
+        shifted
+    } else {
+        shifted & ((U256::from(1u8) << (usize::from(bytes) * 8)) - U256::from(1u8))
+    }
+}
+
+/// Returns the length of a `bytes`/`string` value from the word at its slot.
+///
+/// Returns `None` if `word` is not a valid head: a short value longer than 31 bytes, a long
+/// value of 31 bytes or less, or one longer than [`MAX_BYTES_LENGTH`].
+pub fn bytes_length(word: U256) -> Option<u64> {
+    if is_long_bytes(word) {
+        let length = (word - U256::from(1u8)) >> 1;
+        (length >= U256::from(SLOT_BYTES) && length <= U256::from(MAX_BYTES_LENGTH))
+            .then(|| length.to::<u64>())
+    } else {
+        let length = u64::from(word.byte(0) >> 1);
+        (length < SLOT_BYTES).then_some(length)
+    }
+}
+
+/// Returns the data slots of a `bytes`/`string` value stored at `slot` whose slot holds `word`.
+///
+/// Short values (up to 31 bytes) are stored in `slot` itself and have no data slots. Returns
+/// `None` if `word` is not a valid head, see [`bytes_length`].
+pub fn bytes_data_slots(slot: U256, word: U256) -> Option<Vec<U256>> {
+    let length = bytes_length(word)?;
+    if !is_long_bytes(word) {
+        return Some(Vec::new());
+    }
+
+    let start = StorageLocation::new(slot).data_slot();
+    let count = length.div_ceil(SLOT_BYTES);
+    Some((0..count).map(|index| start + U256::from(index)).collect())
+}
+
+/// Decodes a `bytes`/`string` value from the word at its slot and its data slot words.
+///
+/// Returns `None` if `word` is not a valid head or `data` holds fewer words than the value
+/// needs.
+pub fn decode_bytes(word: U256, data: &[U256]) -> Option<Vec<u8>> {
+    let length = bytes_length(word)? as usize;
+    if !is_long_bytes(word) {
+        return Some(word.to_be_bytes::<32>()[..length].to_vec());
+    }
+
+    if data.len() < length.div_ceil(SLOT_BYTES as usize) {
+        return None;
+    }
+    let mut bytes: Vec<u8> = data
+        .iter()
+        .flat_map(|word| word.to_be_bytes::<32>())
+        .collect();
+    bytes.truncate(length);
+    Some(bytes)
+}
+
+fn is_long_bytes(word: U256) -> bool {
+    word.bit(0)
+}
+
+#[cfg(feature = "results")]
+impl VerifiedStorageSlots {
+    /// Returns the verified word of `slot`, if it was requested.
+    pub fn value(&self, slot: U256) -> Option<U256> {
+        self.slots
+            .iter()
+            .find(|(key, _)| *key == slot)
+            .map(|(_, value)| *value)
+    }
+
+    /// Reads the value of `bytes` bytes at `location`.
+    pub fn read(&self, location: StorageLocation, bytes: u8) -> Option<U256> {
+        Some(extract(self.value(location.slot)?, location.offset, bytes))
+    }
+
+    /// Reads the `address` at `location`.
+    pub fn read_address(&self, location: StorageLocation) -> Option<Address> {
+        let value = self.read(location, 20)?;
+        Some(Address::from_word(FixedBytes::from(
+            value.to_be_bytes::<32>(),
+        )))
+    }
+
+    /// Reads the `bool` at `location`.
+    pub fn read_bool(&self, location: StorageLocation) -> Option<bool> {
+        Some(!self.read(location, 1)?.is_zero())
+    }
+
+    /// Reads the `bytes` value at `slot`.
+    ///
+    /// Long values need their data slots in the same result, see
+    /// `ProofBatchBuilder::ethereum_storage_bytes`.
+    pub fn read_bytes(&self, slot: U256) -> Option<Bytes> {
+        let word = self.value(slot)?;
+        let data = bytes_data_slots(slot, word)?
+            .into_iter()
+            .map(|data_slot| self.value(data_slot))
+            .collect::<Option<Vec<_>>>()?;
+        decode_bytes(word, &data).map(Bytes::from)
+    }
+
+    /// Reads the `string` value at `slot`, or `None` if it is not valid UTF-8.
+    pub fn read_string(&self, slot: U256) -> Option<String> {
+        String::from_utf8(self.read_bytes(slot)?.to_vec()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use alloy_primitives::{address, b256, uint};
+
+    use super::*;
+
+    #[test]
+    fn mapping_slot_matches_solidity() {
+        // keccak256(abi.encode(address(0x...01), uint256(0)))
+        let location = StorageLocation::new(0u64)
+            .mapping(address!("0000000000000000000000000000000000000001"));
+
+        assert_eq!(
+            FixedBytes::from(location.slot.to_be_bytes::<32>()),
+            b256!("ada5013122d395ba3c54772283fb069b10426056ef8ca54750cb9bb552a59e7d")
+        );
+    }
+
+    #[test]
+    fn nested_mapping_hashes_each_key() {
+        let owner = Address::repeat_byte(0x11);
+        let spender = Address::repeat_byte(0x22);
+        let inner = StorageLocation::new(1u64).mapping(owner);
+
+        assert_eq!(
+            inner.mapping(spender),
+            StorageLocation::new(inner.slot).mapping(spender)
+        );
+        assert_ne!(inner.mapping(spender), inner.mapping(owner));
+    }
+
+    #[test]
+    fn packed_array_elements_share_slots() {
+        let array = StorageLocation::new(3u64);
+
+        assert_eq!(array.index(0, 8), StorageLocation::packed(3u64, 0));
+        assert_eq!(array.index(3, 8), StorageLocation::packed(3u64, 24));
+        assert_eq!(array.index(4, 8), StorageLocation::packed(4u64, 0));
+        assert_eq!(array.index(2, 64), StorageLocation::new(7u64));
+        assert_eq!(
+            array.dynamic_index(1, 32),
+            StorageLocation::new(array.data_slot() + U256::from(1u64))
+        );
+    }
+
+    #[test]
+    fn extracts_packed_values() {
+        let word = uint!(0x0102030405060708090a0b0c0d0e0f10_U256);
+
+        assert_eq!(extract(word, 0, 1), U256::from(0x10u64));
+        assert_eq!(extract(word, 2, 2), U256::from(0x0d0eu64));
+        assert_eq!(extract(word, 0, 32), word);
+    }
+
+    #[test]
+    fn decodes_short_and_long_bytes() {
+        let mut short = [0u8; 32];
+        short[..5].copy_from_slice(b"hello");
+        short[31] = 10;
+        let short = U256::from_be_bytes(short);
+
+        assert_eq!(bytes_data_slots(U256::ZERO, short), Some(Vec::new()));
+        assert_eq!(decode_bytes(short, &[]), Some(b"hello".to_vec()));
+
+        let value: Vec<u8> = (0..40).collect();
+        let word = U256::from(value.len() as u64 * 2 + 1);
+        let mut first = [0u8; 32];
+        first.copy_from_slice(&value[..32]);
+        let mut second = [0u8; 32];
+        second[..8].copy_from_slice(&value[32..]);
+        let data = vec![U256::from_be_bytes(first), U256::from_be_bytes(second)];
+
+        assert_eq!(bytes_length(word), Some(40));
+        assert_eq!(bytes_data_slots(U256::from(2u64), word).unwrap().len(), 2);
+        assert_eq!(decode_bytes(word, &data), Some(value));
+        assert_eq!(decode_bytes(word, &data[..1]), None);
+    }
+
+    #[test]
+    fn reads_verified_packed_values_and_strings() {
+        let owner = Address::repeat_byte(0x11);
+        let head = U256::from(5u64);
+        let mut packed = [0u8; 32];
+        packed[11] = 1;
+        packed[12..].copy_from_slice(owner.as_slice());
+        let text = "a string that does not fit in a single slot";
+        let word = U256::from(text.len() as u64 * 2 + 1);
+        let data_slots = bytes_data_slots(head, word).unwrap();
+
+        let mut slots = vec![(U256::ZERO, U256::from_be_bytes(packed)), (head, word)];
+        for (data_slot, chunk) in data_slots.iter().zip(text.as_bytes().chunks(32)) {
+            let mut value = [0u8; 32];
+            value[..chunk.len()].copy_from_slice(chunk);
+            slots.push((*data_slot, U256::from_be_bytes(value)));
+        }
+        let result = VerifiedStorageSlots {
+            block: crate::results::evm::BlockRef {
+                network_id: 1,
+                block_number: 7,
+                ..Default::default()
+            },
+            address: Address::ZERO,
+            slots,
+        };
+
+        assert_eq!(result.read_address(StorageLocation::new(0u64)), Some(owner));
+        assert_eq!(
+            result.read_bool(StorageLocation::packed(0u64, 20)),
+            Some(true)
+        );
+        assert_eq!(result.read_string(head).as_deref(), Some(text));
+        assert_eq!(result.read_bytes(U256::from(6u64)), None);
+    }
+
+    #[test]
+    fn rejects_words_that_are_not_bytes_heads() {
+        // A short head claiming 50 bytes, as a `uint` holding 100 reads.
+        let uint = U256::from(100u64);
+        assert_eq!(bytes_length(uint), None);
+        assert_eq!(bytes_data_slots(U256::ZERO, uint), None);
+        assert_eq!(decode_bytes(uint, &[]), None);
+
+        // Long heads of 31 bytes or less, or beyond the length cap.
+        assert_eq!(bytes_length(U256::from(31u64 * 2 + 1)), None);
+        let oversized = U256::from(MAX_BYTES_LENGTH * 2 + 3);
+        assert_eq!(bytes_data_slots(U256::ZERO, oversized), None);
+        let huge = (U256::from(1u8) << 255) + U256::from(1u8);
+        assert_eq!(bytes_data_slots(U256::ZERO, huge), None);
+        assert_eq!(decode_bytes(huge, &[]), None);
+
+        let max = U256::from(MAX_BYTES_LENGTH * 2 + 1);
+        assert_eq!(
+            bytes_data_slots(U256::ZERO, max).map(|slots| slots.len() as u64),
+            Some(MAX_BYTES_LENGTH / SLOT_BYTES)
+        );
+
+        let result = VerifiedStorageSlots {
+            block: Default::default(),
+            address: Address::ZERO,
+            slots: vec![(U256::ZERO, uint), (U256::from(1u64), huge)],
+        };
+        assert_eq!(result.read_bytes(U256::ZERO), None);
+        assert_eq!(result.read_string(U256::from(1u64)), None);
+    }
+}
@@ -1,9 +1,15 @@
 //! MMR (Merkle Mountain Range) utility functions
 //!
 //! This module provides utilities for working with Merkle Mountain Ranges,
-//! including hashing functions for both Keccak and Poseidon.
+//! including hashing functions for both Keccak and Poseidon and node position
+//! arithmetic.
+//!
+//! Positions are 1-based indexes into the MMR nodes, as used by
+//! `elements_index`, and `elements_count` is the number of nodes.
 
 extern crate alloc;
+use alloc::vec::Vec;
+
 use alloy_primitives::keccak256;
 use alloy_primitives::FixedBytes;
 use starknet_crypto::{poseidon_hash, Felt};
@@ -37,6 +43,136 @@ pub fn hash_to_leaf(hash: FixedBytes<32>, hashing_function: &HashingFunction) ->
     }
 }
 
+/// Hashes two child nodes into their parent node
+///
+/// Keccak hashes the concatenation of both children, Poseidon hashes them as two felts.
+pub fn hash_to_parent(
+    left: FixedBytes<32>,
+    right: FixedBytes<32>,
+    hashing_function: &HashingFunction,
+) -> FixedBytes<32> {
+    match hashing_function {
+        HashingFunction::Keccak => keccak256([left.as_slice(), right.as_slice()].concat()),
+        HashingFunction::Poseidon => {
+            let left = Felt::from_bytes_be_slice(left.as_slice());
+            let right = Felt::from_bytes_be_slice(right.as_slice());
+            FixedBytes::from_slice(poseidon_hash(left, right).to_bytes_be().as_slice())
+        }
+    }
+}
+
+/// Returns the positions of the siblings on the path from `elements_index` to its peak
+///
+/// The positions are ordered from the leaf upwards and line up with the `path`
+/// of a single-leaf MMR proof.
+///
+/// # Returns
+///
+/// `None` if `elements_index` is not a leaf of an MMR with `elements_count` nodes, or if a
+/// position on its path does not fit in a `u64`
+pub fn sibling_positions(elements_index: u64, elements_count: u64) -> Option<Vec<u64>> {
+    if elements_index == 0 || elements_index > elements_count || node_height(elements_index)? != 0 {
+        return None;
+    }
+
+    let mut siblings = Vec::new();
+    let mut position = elements_index;
+    let mut height = 0u32;
+    loop {
+        // Size of a perfect subtree whose root has this height, up to `u64::MAX` at height 63
+        let subtree_size = u64::MAX >> 63u32.checked_sub(height)?;
+        let next = position.checked_add(1);
+        if next
+            .and_then(node_height)
+            .is_some_and(|next_height| next_height > height)
+        {
+            // Right child, the parent directly follows it
+            siblings.push(position.checked_sub(subtree_size)?);
+            position = next?;
+        } else if position
+            .checked_add(subtree_size)
+            .is_some_and(|end| end <= elements_count)
+        {
+            // Left child, the parent follows the right subtree
+            siblings.push(position + subtree_size);
+            position = position.checked_add(subtree_size)?.checked_add(1)?;
+        } else {
+            // Peak
+            return Some(siblings);
+        }
+        height += 1;
+    }
+}
+
+/// Returns the position of the parent of the node at `position`
+///
+/// # Returns
+///
+/// `None` if the node is a peak of an MMR with `elements_count` nodes, or if its parent
+/// position does not fit in a `u64`
+pub fn parent_position(position: u64, elements_count: u64) -> Option<u64> {
+    let height = node_height(position)?;
+    let next = position.checked_add(1)?;
+    let parent = if node_height(next)? > height {
+        // Right child, the parent directly follows it
+        next
+    } else {
+        // Left child, the parent follows the right subtree
+        let subtree_size = u64::MAX >> 63u32.checked_sub(height)?;
+        position.checked_add(subtree_size)?.checked_add(1)?
+    };
+    (parent <= elements_count).then_some(parent)
+}
+
+/// Returns the positions of the left and right children of the node at `position`
+///
+/// Returns `None` for leaves and position 0.
+pub fn child_positions(position: u64) -> Option<(u64, u64)> {
+    match node_height(position)? {
+        0 => None,
+        height => Some((position - (1 << height), position - 1)),
+    }
+}
+
+/// Returns the positions of the peaks of an MMR with `elements_count` nodes, left to right
+///
+/// Returns `None` if no MMR has `elements_count` nodes.
+pub fn peak_positions(elements_count: u64) -> Option<Vec<u64>> {
+    let mut peaks = Vec::new();
+    let mut position = 0u64;
+    let mut remaining = elements_count;
+    for height in (0..64u32).rev() {
+        // Size of a perfect subtree whose root has this height
+        let subtree_size = u64::MAX >> (63 - height);
+        if subtree_size <= remaining {
+            position += subtree_size;
+            remaining -= subtree_size;
+            peaks.push(position);
+        }
+    }
+    (elements_count != 0 && remaining == 0).then_some(peaks)
+}
+
+/// Returns the height of the node at `position`, 0 for leaves
+///
+/// Returns `None` for position 0, which is not a node.
+fn node_height(position: u64) -> Option<u32> {
+    if position == 0 {
+        return None;
+    }
+    let mut position = position;
+    while !all_ones(position) {
+        // `position` is at least its most significant bit, so it stays non-zero
+        let most_significant_bit = 1u64 << (63 - position.leading_zeros());
+        position -= most_significant_bit - 1;
+    }
+    Some(63 - position.leading_zeros())
+}
+
+fn all_ones(value: u64) -> bool {
+    value != 0 && value.count_zeros() == value.leading_zeros()
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::hex::FromHex;
@@ -68,4 +204,72 @@ mod tests {
         );
         assert_eq!(result, FixedBytes::from_hex(expected).unwrap());
     }
+
+    #[test]
+    fn test_node_height() {
+        // Positions 1..=11 of an MMR with 7 leaves
+        let heights = [0, 0, 1, 0, 0, 1, 2, 0, 0, 1, 0];
+        for (position, height) in (1..).zip(heights) {
+            assert_eq!(node_height(position), Some(height), "position {position}");
+        }
+        assert_eq!(node_height(0), None);
+        assert_eq!(node_height(u64::MAX), Some(63));
+    }
+
+    #[test]
+    fn test_sibling_positions() {
+        assert_eq!(sibling_positions(1, 1), Some(vec![]));
+        assert_eq!(sibling_positions(1, 3), Some(vec![2]));
+        assert_eq!(sibling_positions(2, 3), Some(vec![1]));
+        assert_eq!(sibling_positions(4, 4), Some(vec![]));
+        assert_eq!(sibling_positions(4, 7), Some(vec![5, 3]));
+        assert_eq!(sibling_positions(8, 11), Some(vec![9]));
+        assert_eq!(sibling_positions(11, 11), Some(vec![]));
+
+        assert_eq!(sibling_positions(0, 3), None);
+        assert_eq!(sibling_positions(4, 3), None);
+        assert_eq!(sibling_positions(3, 3), None);
+    }
+
+    #[test]
+    fn test_parent_and_child_positions() {
+        // Positions 1..=11 of an MMR with 7 leaves, peaks at 7, 10 and 11
+        let parents = [3, 3, 7, 6, 6, 7, 0, 10, 10, 0, 0];
+        for (position, parent) in (1..).zip(parents) {
+            let parent = (parent != 0).then_some(parent);
+            assert_eq!(parent_position(position, 11), parent, "position {position}");
+        }
+        assert_eq!(parent_position(7, u64::MAX), Some(15));
+        assert_eq!(parent_position(u64::MAX, u64::MAX), None);
+        assert_eq!(parent_position(0, 11), None);
+
+        assert_eq!(child_positions(7), Some((3, 6)));
+        assert_eq!(child_positions(10), Some((8, 9)));
+        assert_eq!(child_positions(11), None);
+        assert_eq!(child_positions(0), None);
+    }
+
+    #[test]
+    fn test_peak_positions() {
+        assert_eq!(peak_positions(1), Some(vec![1]));
+        assert_eq!(peak_positions(8), Some(vec![7, 8]));
+        assert_eq!(peak_positions(11), Some(vec![7, 10, 11]));
+        assert_eq!(peak_positions(u64::MAX), Some(vec![u64::MAX]));
+        assert_eq!(peak_positions(0), None);
+        assert_eq!(peak_positions(2), None);
+        assert_eq!(peak_positions(5), None);
+    }
+
+    #[test]
+    fn test_sibling_positions_at_u64_bounds() {
+        assert_eq!(sibling_positions(0, 0), None);
+        assert_eq!(sibling_positions(0, u64::MAX), None);
+        assert_eq!(sibling_positions(u64::MAX, u64::MAX), None);
+        assert_eq!(sibling_positions(u64::MAX - 1, u64::MAX), None);
+
+        // The MMR with `u64::MAX` nodes is one perfect tree of height 63
+        let path = sibling_positions(1, u64::MAX).unwrap();
+        assert_eq!(path.len(), 63);
+        assert_eq!(path[62], u64::MAX - 1);
+    }
 }
@@ -0,0 +1,171 @@
+use bankai_types::{
+    block::BankaiBlock, common::HashingFunction, inputs::bankai::HistoricalBankaiBlockProof,
+};
+
+use crate::bankai::mmr::MmrVerifier;
+use crate::bankai::stwo::{verify_block_proof, Blake2sMerkleHasher, CairoProof};
+use crate::{VerifierConfig, VerifyError};
+
+/// Verifies a reference Bankai block against `config` and its STWO proof, then verifies a
+/// historical Bankai block against the reference block's Bankai MMR.
+///
+/// Only one STWO proof is checked. The returned block is as trusted as the reference block,
+/// so its execution, beacon and OP roots can be used to verify older state.
+///
+/// # Errors
+///
+/// - `UntrustedProgram` - The reference block's program is not accepted by `config`
+/// - `InvalidStwoProof` / `InvalidBlockHash` - The reference block proof is invalid
+/// - Any error returned by [`verify_historical_block`]
+pub fn verify_historical_block_proof(
+    reference_proof: CairoProof<Blake2sMerkleHasher>,
+    reference_block: &BankaiBlock,
+    historical: &HistoricalBankaiBlockProof,
+    config: &VerifierConfig,
+) -> Result<BankaiBlock, VerifyError> {
+    config.check_block(reference_block)?;
+    verify_block_proof(reference_proof, reference_block)?;
+    verify_historical_block(reference_block, historical)
+}
+
+/// Verifies that a historical Bankai block is committed in a reference block's Bankai MMR.
+///
+/// `reference_block` must already be trusted, typically through
+/// [`crate::bankai::stwo::verify_block_proof`].
+///
+/// # Errors
+///
+/// - `InvalidMmrRoot` - The proof does not target the reference block's Bankai MMR root
+/// - `InvalidMmrProof` / `InvalidMmrTree` - The inclusion proof is invalid
+/// - `InvalidBlockHash` - The historical block does not match the proven block hash
+pub fn verify_historical_block(
+    reference_block: &BankaiBlock,
+    historical: &HistoricalBankaiBlockProof,
+) -> Result<BankaiBlock, VerifyError> {
+    let mmr_proof = &historical.mmr_proof;
+
+    if mmr_proof.reference_block_number != reference_block.block_number {
+        return Err(VerifyError::InvalidMmrRoot);
+    }
+    let expected_root = match mmr_proof.hashing_function {
+        HashingFunction::Keccak => reference_block.bankai_mmr_root_keccak,
+        HashingFunction::Poseidon => reference_block.bankai_mmr_root_poseidon,
+    };
+    if mmr_proof.root != expected_root {
+        return Err(VerifyError::InvalidMmrRoot);
+    }
+
+    MmrVerifier::verify_bankai_mmr_proof(mmr_proof)?;
+
+    if mmr_proof.target_block_number != historical.block.block_number
+        || mmr_proof.block_hash != historical.block.compute_block_hash_keccak()
+    {
+        return Err(VerifyError::InvalidBlockHash);
+    }
+
+    Ok(historical.block.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::FixedBytes;
+    use bankai_core::mmr;
+    use bankai_types::{inputs::bankai::BankaiMmrProof, utils::mmr::hash_to_leaf};
+
+    use super::*;
+
+    fn historical_block() -> BankaiBlock {
+        BankaiBlock {
+            version: 1,
+            block_number: 7,
+            program_hash: FixedBytes::from([1u8; 32]),
+            ..Default::default()
+        }
+    }
+
+    fn fixture(hashing_function: HashingFunction) -> (BankaiBlock, HistoricalBankaiBlockProof) {
+        let block = historical_block();
+        let block_hash = block.compute_block_hash_keccak();
+        let leaf = hash_to_leaf(block_hash, &hashing_function).0;
+        let root = match hashing_function {
+            HashingFunction::Keccak => {
+                mmr::calculate_root_hash(&mmr::KeccakHasher::new(), 1, &[leaf]).unwrap()
+            }
+            HashingFunction::Poseidon => {
+                mmr::calculate_root_hash(&mmr::PoseidonHasher::new(), 1, &[leaf]).unwrap()
+            }
+        };
+
+        let mut reference = BankaiBlock {
+            version: 1,
+            block_number: 20,
+            ..Default::default()
+        };
+        match hashing_function {
+            HashingFunction::Keccak => reference.bankai_mmr_root_keccak = FixedBytes::from(root),
+            HashingFunction::Poseidon => {
+                reference.bankai_mmr_root_poseidon = FixedBytes::from(root)
+            }
+        }
+
+        let historical = HistoricalBankaiBlockProof {
+            block,
+            mmr_proof: BankaiMmrProof {
+                reference_block_number: 20,
+                target_block_number: 7,
+                hashing_function,
+                block_hash,
+                root: FixedBytes::from(root),
+                elements_index: 1,
+                elements_count: 1,
+                path: vec![],
+                peaks: vec![FixedBytes::from(leaf)],
+            },
+        };
+
+        (reference, historical)
+    }
+
+    #[test]
+    fn verifies_historical_block_keccak() {
+        let (reference, historical) = fixture(HashingFunction::Keccak);
+        let block = verify_historical_block(&reference, &historical).expect("valid proof");
+        assert_eq!(block.block_number, 7);
+    }
+
+    #[test]
+    fn verifies_historical_block_poseidon() {
+        let (reference, historical) = fixture(HashingFunction::Poseidon);
+        assert!(verify_historical_block(&reference, &historical).is_ok());
+    }
+
+    #[test]
+    fn rejects_proof_for_other_reference_block() {
+        let (reference, mut historical) = fixture(HashingFunction::Keccak);
+        historical.mmr_proof.reference_block_number = 21;
+        assert_eq!(
+            verify_historical_block(&reference, &historical).unwrap_err(),
+            VerifyError::InvalidMmrRoot
+        );
+    }
+
+    #[test]
+    fn rejects_root_not_committed_by_reference_block() {
+        let (mut reference, historical) = fixture(HashingFunction::Keccak);
+        reference.bankai_mmr_root_keccak = FixedBytes::from([9u8; 32]);
+        assert_eq!(
+            verify_historical_block(&reference, &historical).unwrap_err(),
+            VerifyError::InvalidMmrRoot
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_historical_block() {
+        let (reference, mut historical) = fixture(HashingFunction::Keccak);
+        historical.block.execution.block_number = 99;
+        assert_eq!(
+            verify_historical_block(&reference, &historical).unwrap_err(),
+            VerifyError::InvalidBlockHash
+        );
+    }
+}
@@ -1,5 +1,15 @@
+use alloc::vec::Vec;
+
+use alloy_primitives::FixedBytes;
 use bankai_core::mmr;
-use bankai_types::{common::HashingFunction, inputs::evm::MmrProof, utils::mmr::hash_to_leaf};
+use bankai_types::{
+    common::HashingFunction,
+    inputs::{
+        bankai::BankaiMmrProof,
+        evm::{MmrMultiProof, MmrProof},
+    },
+    utils::mmr::{hash_to_leaf, peak_positions},
+};
 
 use crate::VerifyError;
 
@@ -7,45 +17,139 @@ pub struct MmrVerifier;
 
 impl MmrVerifier {
     pub fn verify_mmr_proof(proof: &MmrProof) -> Result<(), VerifyError> {
-        let leaf = hash_to_leaf(proof.header_hash, &proof.hashing_function).0;
-        let mmr_proof = mmr::Proof {
-            element_index: proof.elements_index,
-            element_hash: leaf,
-            siblings_hashes: proof.path.iter().map(|hash| hash.0).collect(),
-            peaks_hashes: proof.peaks.iter().map(|hash| hash.0).collect(),
-            elements_count: proof.elements_count,
-        };
-
-        // Ensure the merkle path recreates a specific peak hash
-        let valid = with_hasher(
+        verify_inclusion(
             proof.hashing_function,
-            |hasher| mmr::verify_proof_stateless(hasher, &mmr_proof, leaf),
-            |hasher| mmr::verify_proof_stateless(hasher, &mmr_proof, leaf),
+            proof.header_hash,
+            proof.elements_index,
+            proof.elements_count,
+            &proof.path,
+            &proof.peaks,
+            proof.root,
         )
-        .map_err(map_mmr_error)?;
+    }
 
-        if !valid {
+    /// Verifies that every leaf of a multi-leaf proof is committed in `proof.root`.
+    ///
+    /// The leaves and the stored nodes are hashed bottom-up once, each reached peak is compared
+    /// with the shared peaks, and the peaks are checked against the root once.
+    pub fn verify_mmr_multi_proof(proof: &MmrMultiProof) -> Result<(), VerifyError> {
+        if proof.leaves.is_empty() {
             return Err(VerifyError::InvalidMmrProof);
         }
+        let computed_peaks = proof.compute_peaks().ok_or(VerifyError::InvalidMmrProof)?;
+
+        let peak_positions =
+            peak_positions(proof.elements_count).ok_or(VerifyError::InvalidMmrTree)?;
+        if peak_positions.len() != proof.peaks.len() {
+            return Err(VerifyError::InvalidMmrTree);
+        }
+        for (position, hash) in computed_peaks {
+            let peak = peak_positions
+                .iter()
+                .position(|peak_position| *peak_position == position)
+                .ok_or(VerifyError::InvalidMmrProof)?;
+            if proof.peaks[peak] != hash {
+                return Err(VerifyError::InvalidMmrProof);
+            }
+        }
 
-        // ensure the peaks create the expected root
-        let computed_root = with_hasher(
+        let peaks: Vec<[u8; 32]> = proof.peaks.iter().map(|hash| hash.0).collect();
+        verify_peaks(
             proof.hashing_function,
-            |hasher| {
-                mmr::calculate_root_hash(hasher, proof.elements_count, &mmr_proof.peaks_hashes)
-            },
-            |hasher| {
-                mmr::calculate_root_hash(hasher, proof.elements_count, &mmr_proof.peaks_hashes)
-            },
+            proof.elements_count,
+            &peaks,
+            proof.root,
         )
-        .map_err(map_mmr_error)?;
+    }
 
-        if computed_root != proof.root.0 {
-            return Err(VerifyError::InvalidMmrRoot);
-        }
+    /// Verifies that a Bankai block hash is committed in a reference block's Bankai MMR.
+    ///
+    /// This only checks the inclusion proof against `proof.root`. Callers must still bind
+    /// `proof.root` to a trusted Bankai block, see [`crate::bankai::history`].
+    pub fn verify_bankai_mmr_proof(proof: &BankaiMmrProof) -> Result<(), VerifyError> {
+        verify_inclusion(
+            proof.hashing_function,
+            proof.block_hash,
+            proof.elements_index,
+            proof.elements_count,
+            &proof.path,
+            &proof.peaks,
+            proof.root,
+        )
+    }
+}
+
+fn verify_inclusion(
+    hashing_function: HashingFunction,
+    element: FixedBytes<32>,
+    elements_index: u64,
+    elements_count: u64,
+    path: &[FixedBytes<32>],
+    peaks: &[FixedBytes<32>],
+    root: FixedBytes<32>,
+) -> Result<(), VerifyError> {
+    let peaks: Vec<[u8; 32]> = peaks.iter().map(|hash| hash.0).collect();
+    verify_path(
+        hashing_function,
+        element,
+        elements_index,
+        elements_count,
+        path,
+        &peaks,
+    )?;
+    verify_peaks(hashing_function, elements_count, &peaks, root)
+}
+
+/// Ensures the merkle path recreates one of the peaks.
+fn verify_path(
+    hashing_function: HashingFunction,
+    element: FixedBytes<32>,
+    elements_index: u64,
+    elements_count: u64,
+    path: &[FixedBytes<32>],
+    peaks: &[[u8; 32]],
+) -> Result<(), VerifyError> {
+    let leaf = hash_to_leaf(element, &hashing_function).0;
+    let mmr_proof = mmr::Proof {
+        element_index: elements_index,
+        element_hash: leaf,
+        siblings_hashes: path.iter().map(|hash| hash.0).collect(),
+        peaks_hashes: peaks.to_vec(),
+        elements_count,
+    };
 
-        Ok(())
+    let valid = with_hasher(
+        hashing_function,
+        |hasher| mmr::verify_proof_stateless(hasher, &mmr_proof, leaf),
+        |hasher| mmr::verify_proof_stateless(hasher, &mmr_proof, leaf),
+    )
+    .map_err(map_mmr_error)?;
+
+    if !valid {
+        return Err(VerifyError::InvalidMmrProof);
+    }
+    Ok(())
+}
+
+/// Ensures the peaks create the expected root.
+fn verify_peaks(
+    hashing_function: HashingFunction,
+    elements_count: u64,
+    peaks: &[[u8; 32]],
+    root: FixedBytes<32>,
+) -> Result<(), VerifyError> {
+    let computed_root = with_hasher(
+        hashing_function,
+        |hasher| mmr::calculate_root_hash(hasher, elements_count, peaks),
+        |hasher| mmr::calculate_root_hash(hasher, elements_count, peaks),
+    )
+    .map_err(map_mmr_error)?;
+
+    if computed_root != root.0 {
+        return Err(VerifyError::InvalidMmrRoot);
     }
+
+    Ok(())
 }
 
 fn with_hasher<T, FKeccak, FPoseidon>(
@@ -79,8 +183,7 @@ fn map_mmr_error(error: mmr::MmrError) -> VerifyError {
 #[cfg(test)]
 mod tests {
     use ::mmr as external_mmr;
-    use alloy_primitives::FixedBytes;
-    use bankai_types::utils::mmr::hash_to_leaf;
+    use bankai_types::utils::mmr::hash_to_parent;
 
     use super::*;
 
@@ -206,4 +309,161 @@ mod tests {
             Err(VerifyError::InvalidMmrProof)
         );
     }
+
+    /// Builds a Keccak MMR of 5 leaves and merges the proofs of the leaves at `positions`.
+    async fn multi_proof(positions: &[u64]) -> MmrMultiProof {
+        let store = external_mmr::InMemoryStore::new();
+        let hasher = std::sync::Arc::new(external_mmr::KeccakHasher::new());
+        let mut tree = external_mmr::Mmr::new(store, hasher, None).unwrap();
+        let header_hashes: Vec<FixedBytes<32>> =
+            (1u8..=5).map(|byte| FixedBytes::from([byte; 32])).collect();
+        for header_hash in &header_hashes {
+            tree.append(hash_to_leaf(*header_hash, &HashingFunction::Keccak).0)
+                .await
+                .unwrap();
+        }
+        let root = tree.get_root_hash().await.unwrap().unwrap();
+
+        // Leaf positions of an MMR with 5 leaves
+        let leaf_positions = [1u64, 2, 4, 5, 8];
+        let mut proofs = Vec::new();
+        for &position in positions {
+            let leaf = leaf_positions.iter().position(|p| *p == position).unwrap();
+            let generated = tree.get_proof(position, None).await.unwrap();
+            proofs.push(MmrProof {
+                network_id: 1,
+                block_number: leaf as u64,
+                hashing_function: HashingFunction::Keccak,
+                header_hash: header_hashes[leaf],
+                root: FixedBytes::from(root),
+                elements_index: generated.element_index,
+                elements_count: generated.elements_count,
+                path: generated
+                    .siblings_hashes
+                    .into_iter()
+                    .map(FixedBytes::from)
+                    .collect(),
+                peaks: generated
+                    .peaks_hashes
+                    .into_iter()
+                    .map(FixedBytes::from)
+                    .collect(),
+            });
+        }
+        MmrMultiProof::from_proofs(&proofs).unwrap()
+    }
+
+    #[tokio::test]
+    async fn verifies_valid_mmr_multi_proof() {
+        let proof = multi_proof(&[1, 2, 4, 5, 8]).await;
+        // Every node is hashed from the leaves, none are stored
+        assert!(proof.nodes.is_empty());
+        assert_eq!(MmrVerifier::verify_mmr_multi_proof(&proof), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn tampered_mmr_multi_proof_fails() {
+        let mut proof = multi_proof(&[2, 4]).await;
+        // Nodes 3 and 6 are hashed from the leaves, the sibling leaves 1 and 5 are stored
+        let positions: Vec<u64> = proof.nodes.iter().map(|node| node.elements_index).collect();
+        assert_eq!(positions, vec![1, 5]);
+        proof.nodes[0].hash = FixedBytes::ZERO;
+        assert_eq!(
+            MmrVerifier::verify_mmr_multi_proof(&proof),
+            Err(VerifyError::InvalidMmrProof)
+        );
+
+        let mut proof = multi_proof(&[2, 4]).await;
+        proof.leaves[1].header_hash = FixedBytes::from([9u8; 32]);
+        assert_eq!(
+            MmrVerifier::verify_mmr_multi_proof(&proof),
+            Err(VerifyError::InvalidMmrProof)
+        );
+
+        let mut proof = multi_proof(&[5]).await;
+        proof.root = FixedBytes::from([9u8; 32]);
+        assert_eq!(
+            MmrVerifier::verify_mmr_multi_proof(&proof),
+            Err(VerifyError::InvalidMmrRoot)
+        );
+
+        let mut proof = multi_proof(&[5]).await;
+        proof.leaves.clear();
+        assert_eq!(
+            MmrVerifier::verify_mmr_multi_proof(&proof),
+            Err(VerifyError::InvalidMmrProof)
+        );
+    }
+
+    #[tokio::test]
+    async fn mmr_multi_proof_with_out_of_range_positions_fails() {
+        for (elements_index, elements_count) in [(0, 8), (u64::MAX, u64::MAX), (1, u64::MAX)] {
+            let mut proof = multi_proof(&[1]).await;
+            proof.leaves[0].elements_index = elements_index;
+            proof.elements_count = elements_count;
+            assert_eq!(
+                MmrVerifier::verify_mmr_multi_proof(&proof),
+                Err(VerifyError::InvalidMmrProof)
+            );
+        }
+    }
+
+    #[test]
+    fn parent_hashes_match_mmr_proofs() {
+        for hashing_function in [HashingFunction::Keccak, HashingFunction::Poseidon] {
+            let left = hash_to_leaf(FixedBytes::from([1u8; 32]), &hashing_function);
+            let right = hash_to_leaf(FixedBytes::from([2u8; 32]), &hashing_function);
+            let parent = hash_to_parent(left, right, &hashing_function);
+            let mmr_proof = mmr::Proof {
+                element_index: 1,
+                element_hash: left.0,
+                siblings_hashes: vec![right.0],
+                peaks_hashes: vec![parent.0],
+                elements_count: 3,
+            };
+
+            let valid = with_hasher(
+                hashing_function,
+                |hasher| mmr::verify_proof_stateless(hasher, &mmr_proof, left.0),
+                |hasher| mmr::verify_proof_stateless(hasher, &mmr_proof, left.0),
+            );
+            assert!(matches!(valid, Ok(true)), "{hashing_function:?}");
+        }
+    }
+
+    #[test]
+    fn verifies_valid_bankai_mmr_proof() {
+        let mmr_proof = base_proof(HashingFunction::Poseidon);
+        let proof = BankaiMmrProof {
+            reference_block_number: 2,
+            target_block_number: 1,
+            hashing_function: mmr_proof.hashing_function,
+            block_hash: mmr_proof.header_hash,
+            root: mmr_proof.root,
+            elements_index: mmr_proof.elements_index,
+            elements_count: mmr_proof.elements_count,
+            path: mmr_proof.path,
+            peaks: mmr_proof.peaks,
+        };
+
+        assert_eq!(MmrVerifier::verify_bankai_mmr_proof(&proof), Ok(()));
+    }
+
+    #[test]
+    fn bankai_mmr_proof_with_wrong_block_hash_fails() {
+        let mmr_proof = base_proof(HashingFunction::Keccak);
+        let proof = BankaiMmrProof {
+            reference_block_number: 2,
+            target_block_number: 1,
+            hashing_function: mmr_proof.hashing_function,
+            block_hash: FixedBytes::from([8u8; 32]),
+            root: mmr_proof.root,
+            elements_index: mmr_proof.elements_index,
+            elements_count: mmr_proof.elements_count,
+            path: mmr_proof.path,
+            peaks: mmr_proof.peaks,
+        };
+
+        assert!(MmrVerifier::verify_bankai_mmr_proof(&proof).is_err());
+    }
 }
@@ -3,6 +3,12 @@
 //! This module provides the core verification functions for Bankai's stateless light client:
 //! - STWO zero-knowledge proof verification
 //! - MMR (Merkle Mountain Range) inclusion proof verification
+//! - Historical Bankai block verification against a newer block's Bankai MMR
+
+/// Historical Bankai block verification
+///
+/// Functions for proving that an older Bankai block is committed in a trusted reference block.
+pub mod history;
 
 /// MMR (Merkle Mountain Range) proof verification
 ///
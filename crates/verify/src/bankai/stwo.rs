@@ -1,9 +1,47 @@
+use alloy_primitives::FixedBytes;
 use bankai_types::block::{BankaiBlock, BankaiBlockHashOutput};
 pub use cairo_air::{utils::get_verification_output, CairoProof, PreProcessedTraceVariant};
 pub use stwo::core::vcs::blake2_merkle::{Blake2sMerkleChannel, Blake2sMerkleHasher};
 
 use crate::VerifyError;
 
+/// A Bankai block whose STWO proof has been verified.
+///
+/// Can only be obtained from [`verify_block_proof`], so holding one proves the block was
+/// verified. Pass it to [`crate::verify_bundle_with_trusted_block`] to verify further bundles
+/// for the same block without re-running the STWO verifier.
+#[derive(Debug, Clone)]
+pub struct TrustedBankaiBlock {
+    block: BankaiBlock,
+    block_hash: FixedBytes<32>,
+}
+
+impl TrustedBankaiBlock {
+    /// Returns the verified block.
+    pub fn block(&self) -> &BankaiBlock {
+        &self.block
+    }
+
+    /// Returns the Keccak block hash committed by the STWO proof.
+    pub fn block_hash(&self) -> FixedBytes<32> {
+        self.block_hash
+    }
+
+    /// Trusts `block` without an STWO proof.
+    #[cfg(test)]
+    pub(crate) fn new_unchecked(block: BankaiBlock) -> Self {
+        Self {
+            block_hash: block.compute_block_hash_keccak(),
+            block,
+        }
+    }
+
+    /// Consumes the token and returns the verified block.
+    pub fn into_block(self) -> BankaiBlock {
+        self.block
+    }
+}
+
 pub fn verify_stwo_proof(
     proof: CairoProof<Blake2sMerkleHasher>,
 ) -> Result<BankaiBlockHashOutput, VerifyError> {
@@ -19,14 +57,23 @@ pub fn verify_stwo_proof(
         .ok_or(VerifyError::InvalidStwoProof)
 }
 
+/// Verifies a Bankai block against its STWO proof.
+///
+/// # Errors
+///
+/// - `InvalidStwoProof` - The STWO proof is invalid or has no Bankai block hash output
+/// - `InvalidBlockHash` - `block` does not hash to the proven block hash
 pub fn verify_block_proof(
     proof: CairoProof<Blake2sMerkleHasher>,
     block: &BankaiBlock,
-) -> Result<(), VerifyError> {
+) -> Result<TrustedBankaiBlock, VerifyError> {
     let hash_output = verify_stwo_proof(proof)?;
     let expected_hash = block.compute_block_hash_keccak();
     if hash_output.block_hash != expected_hash {
         return Err(VerifyError::InvalidBlockHash);
     }
-    Ok(())
+    Ok(TrustedBankaiBlock {
+        block: block.clone(),
+        block_hash: expected_hash,
+    })
 }
@@ -1,25 +1,41 @@
 extern crate alloc;
+use alloc::borrow::Cow;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use core::slice;
+use core::sync::atomic::{AtomicBool, Ordering};
 
-use alloy_primitives::U256;
-use bankai_types::common::HashingFunction;
-use bankai_types::inputs::evm::execution::{AccountProof, ReceiptProof, StorageSlotProof, TxProof};
+use alloy_primitives::{Bytes, FixedBytes, Log, U256};
+use bankai_types::block::BankaiBlock;
+use bankai_types::common::{Finality, HashingFunction};
+use bankai_types::inputs::evm::execution::{
+    AccountProof, CodeProof, LogProof, ReceiptProof, StorageSlotProof, TxProof,
+};
+use bankai_types::inputs::evm::node_pool::{NodePool, PooledNodes};
+use bankai_types::inputs::evm::op_stack::OpStackProofs;
+use bankai_types::inputs::evm::EvmProofs;
 use bankai_types::inputs::ProofBundle;
-use bankai_types::results::evm::execution::{ReceiptEnvelope, TrieAccount, TxEnvelope};
-use bankai_types::results::evm::op_stack::OpStackResults;
+use bankai_types::results::evm::execution::{
+    ExecutionHeader, ReceiptEnvelope, TrieAccount, TxEnvelope,
+};
+use bankai_types::results::evm::op_stack::{
+    OpReceiptEnvelope, OpTxEnvelope, VerifiedOpReceipt, VerifiedOpTransaction,
+};
 use bankai_types::results::evm::{
-    BlockRef, EvmResults, VerifiedAccount, VerifiedReceipt, VerifiedStorageSlots,
-    VerifiedTransaction,
+    BlockRef, VerifiedAccount, VerifiedCode, VerifiedExecutionPayload, VerifiedLog,
+    VerifiedReceipt, VerifiedStorageSlots, VerifiedTransaction, VerifiedValidator,
 };
 use bankai_types::results::BatchResults;
 
-use crate::bankai::stwo::verify_block_proof;
+use crate::bankai::stwo::{
+    verify_block_proof, Blake2sMerkleHasher, CairoProof, TrustedBankaiBlock,
+};
 use crate::evm::beacon::BeaconVerifier;
+use crate::evm::beacon_state::BeaconStateVerifier;
 use crate::evm::execution::ExecutionVerifier;
 use crate::evm::op_stack::OpStackVerifier;
-use crate::VerifyError;
+use crate::report::{BatchReport, ItemResult};
+use crate::{VerifierConfig, VerifyError};
 
 /// Verifies an entire proof bundle and returns verified Ethereum and OP Stack results.
 ///
@@ -27,213 +43,886 @@ use crate::VerifyError;
 ///
 /// Verification covers:
 ///
-/// 1. the Bankai block proof
-/// 2. header inclusion proofs
-/// 3. account, storage, transaction, and receipt proofs that depend on those headers
+/// 1. the Bankai block's program hash and version, and the proofs' network, against `config`
+/// 2. the Bankai block proof
+/// 3. header inclusion proofs, and header finality if `config` requires it
+/// 4. execution payload and validator proofs against verified beacon headers
+/// 5. account, storage, transaction, receipt, and code proofs that depend on those headers
+///
+/// Verification stops at the first invalid proof, and no later proof is verified. With the
+/// `parallel` feature, the proofs of one kind, such as all account proofs, are verified
+/// concurrently and proofs already in flight finish. The kinds are still verified one after
+/// another, as later kinds depend on the headers and transactions verified before them.
+/// Results keep the order of the proofs in the bundle.
 ///
 /// # Example
 ///
 /// ```no_run
-/// use bankai_verify::verify_batch_proof;
+/// use alloy_primitives::FixedBytes;
+/// use bankai_types::common::Network;
 /// use bankai_types::inputs::ProofBundle;
+/// use bankai_verify::{verify_batch_proof, VerifierConfig};
 ///
-/// # fn example(proof_bundle: ProofBundle) -> Result<(), Box<dyn std::error::Error>> {
-/// let results = verify_batch_proof(proof_bundle)?;
+/// # fn example(proof_bundle: ProofBundle, program_hash: FixedBytes<32>) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = VerifierConfig::new(Network::Sepolia).with_program_hash(program_hash);
+/// let results = verify_batch_proof(proof_bundle, &config)?;
 ///
 /// println!("Verified {} execution headers", results.evm.execution_header.len());
 /// println!("Verified {} OP Stack headers", results.op_stack.header.len());
 /// # Ok(())
 /// # }
 /// ```
-pub fn verify_batch_proof(wrapper: ProofBundle) -> Result<BatchResults, VerifyError> {
-    verify_block_proof(wrapper.block_proof, &wrapper.block)?;
-    let bankai_block = &wrapper.block;
+pub fn verify_batch_proof(
+    wrapper: ProofBundle,
+    config: &VerifierConfig,
+) -> Result<BatchResults, VerifyError> {
+    config.check_block(&wrapper.block)?;
+    check_networks(config, wrapper.evm_proofs.as_ref())?;
+    let trusted = verify_block_proof(wrapper.block_proof, &wrapper.block)?;
 
-    let exec_root = select_root(
+    verify_items(
+        &trusted,
+        config,
+        ItemVerifier::fail_fast(),
+        wrapper.hashing_function,
+        wrapper.evm_proofs.as_ref(),
+        wrapper.op_stack_proofs.as_ref(),
+    )
+    .into_results()
+}
+
+/// Verifies a proof bundle item by item and returns a result for every proof.
+///
+/// The Bankai block is still checked against `config` and its STWO proof first, and any
+/// failure there is returned as an error since nothing in the bundle can be trusted without
+/// it. Every other proof is verified independently: a failed proof is recorded in the report
+/// and verification continues. Proofs that depend on a failed header fail with the usual
+/// "header not found" error.
+///
+/// Headers above the finality required by `config` are reported as `NonFinalHeader`
+/// failures, which flags non-final data without discarding the rest of the bundle.
+///
+/// # Example
+///
+/// ```no_run
+/// use alloy_primitives::FixedBytes;
+/// use bankai_types::common::Network;
+/// use bankai_types::inputs::ProofBundle;
+/// use bankai_verify::{verify_batch_report, VerifierConfig};
+///
+/// # fn example(proof_bundle: ProofBundle, program_hash: FixedBytes<32>) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = VerifierConfig::new(Network::Sepolia).with_program_hash(program_hash);
+/// let report = verify_batch_report(proof_bundle, &config)?;
+///
+/// for failure in report.failures() {
+///     println!("{:?} #{} failed: {}", failure.category, failure.index, failure.error);
+/// }
+/// let results = report.into_verified();
+/// println!("Verified {} accounts", results.evm.account.len());
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_batch_report(
+    wrapper: ProofBundle,
+    config: &VerifierConfig,
+) -> Result<BatchReport, VerifyError> {
+    config.check_block(&wrapper.block)?;
+    check_networks(config, wrapper.evm_proofs.as_ref())?;
+    let trusted = verify_block_proof(wrapper.block_proof, &wrapper.block)?;
+
+    Ok(verify_items(
+        &trusted,
+        config,
+        ItemVerifier::exhaustive(),
         wrapper.hashing_function,
+        wrapper.evm_proofs.as_ref(),
+        wrapper.op_stack_proofs.as_ref(),
+    ))
+}
+
+/// Verifies a proof bundle against a Bankai block whose STWO proof was already verified.
+///
+/// The bundle's own block proof is ignored. The bundle's block must hash to the trusted
+/// block hash, and the trusted block must still be accepted by `config`. Results are the same
+/// as [`verify_batch_proof`].
+///
+/// # Errors
+///
+/// - `UntrustedProgram` - The trusted block's program is not accepted by `config`
+/// - `NetworkMismatch` - A proof targets another network than `config`
+/// - `InvalidBlockHash` - The bundle was built for a different Bankai block
+/// - Any error returned by [`verify_batch_proof`] for the bundle's proofs
+pub fn verify_bundle_with_trusted_block(
+    wrapper: ProofBundle,
+    trusted: &TrustedBankaiBlock,
+    config: &VerifierConfig,
+) -> Result<BatchResults, VerifyError> {
+    let (_, body) = BundleBody::split(wrapper);
+    body.verify_with_trusted_block(trusted, config)
+}
+
+/// Verifies several proof bundles, running the STWO verifier once per distinct Bankai block.
+///
+/// Results are returned in bundle order. Verification stops at the first invalid bundle.
+///
+/// # Example
+///
+/// ```no_run
+/// use alloy_primitives::FixedBytes;
+/// use bankai_types::common::Network;
+/// use bankai_types::inputs::ProofBundle;
+/// use bankai_verify::{verify_many, VerifierConfig};
+///
+/// # fn example(bundles: Vec<ProofBundle>, program_hash: FixedBytes<32>) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = VerifierConfig::new(Network::Sepolia).with_program_hash(program_hash);
+/// let results = verify_many(bundles, &config)?;
+/// println!("Verified {} bundles", results.len());
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_many(
+    bundles: impl IntoIterator<Item = ProofBundle>,
+    config: &VerifierConfig,
+) -> Result<Vec<BatchResults>, VerifyError> {
+    verify_many_with(
+        bundles.into_iter().map(BundleBody::split),
+        config,
+        verify_block_proof,
+    )
+}
+
+/// A proof bundle without its STWO block proof.
+struct BundleBody {
+    hashing_function: HashingFunction,
+    block: BankaiBlock,
+    evm_proofs: Option<EvmProofs>,
+    op_stack_proofs: Option<OpStackProofs>,
+}
+
+impl BundleBody {
+    /// Splits a bundle into its block proof and everything verified against the block.
+    fn split(bundle: ProofBundle) -> (CairoProof<Blake2sMerkleHasher>, Self) {
+        let body = Self {
+            hashing_function: bundle.hashing_function,
+            block: bundle.block,
+            evm_proofs: bundle.evm_proofs,
+            op_stack_proofs: bundle.op_stack_proofs,
+        };
+        (bundle.block_proof, body)
+    }
+
+    /// Verifies the bundle's proofs against `trusted`, which must be the bundle's block.
+    fn verify_with_trusted_block(
+        &self,
+        trusted: &TrustedBankaiBlock,
+        config: &VerifierConfig,
+    ) -> Result<BatchResults, VerifyError> {
+        config.check_block(trusted.block())?;
+        check_networks(config, self.evm_proofs.as_ref())?;
+        if self.block.compute_block_hash_keccak() != trusted.block_hash() {
+            return Err(VerifyError::InvalidBlockHash);
+        }
+
+        verify_items(
+            trusted,
+            config,
+            ItemVerifier::fail_fast(),
+            self.hashing_function,
+            self.evm_proofs.as_ref(),
+            self.op_stack_proofs.as_ref(),
+        )
+        .into_results()
+    }
+}
+
+/// Verifies bundle bodies, calling `verify_block` once per distinct Bankai block.
+///
+/// Blocks rejected by `config` are never passed to `verify_block`.
+fn verify_many_with<P>(
+    bundles: impl IntoIterator<Item = (P, BundleBody)>,
+    config: &VerifierConfig,
+    mut verify_block: impl FnMut(P, &BankaiBlock) -> Result<TrustedBankaiBlock, VerifyError>,
+) -> Result<Vec<BatchResults>, VerifyError> {
+    let mut trusted_blocks: Vec<TrustedBankaiBlock> = Vec::new();
+
+    bundles
+        .into_iter()
+        .map(|(block_proof, body)| {
+            config.check_block(&body.block)?;
+            let block_hash = body.block.compute_block_hash_keccak();
+            let trusted = match trusted_blocks
+                .iter()
+                .position(|trusted| trusted.block_hash() == block_hash)
+            {
+                Some(index) => &trusted_blocks[index],
+                None => {
+                    trusted_blocks.push(verify_block(block_proof, &body.block)?);
+                    &trusted_blocks[trusted_blocks.len() - 1]
+                }
+            };
+
+            body.verify_with_trusted_block(trusted, config)
+        })
+        .collect()
+}
+
+/// Checks that the Ethereum proofs of a bundle target the network of `config`.
+fn check_networks(
+    config: &VerifierConfig,
+    evm_proofs: Option<&EvmProofs>,
+) -> Result<(), VerifyError> {
+    evm_proofs.map_or(Ok(()), |evm| config.check_evm_proofs(evm))
+}
+
+/// Verifies every header and data proof of a bundle against a trusted Bankai block.
+///
+/// Headers less final than `config` requires fail with `NonFinalHeader`. With a fail-fast
+/// `items`, no proof is verified after the first failure.
+fn verify_items(
+    trusted: &TrustedBankaiBlock,
+    config: &VerifierConfig,
+    items: ItemVerifier,
+    hashing_function: HashingFunction,
+    evm_proofs: Option<&EvmProofs>,
+    op_stack_proofs: Option<&OpStackProofs>,
+) -> BatchReport {
+    let bankai_block = trusted.block();
+    let exec_root = select_root(
+        hashing_function,
         bankai_block.execution.mmr_root_keccak,
         bankai_block.execution.mmr_root_poseidon,
     );
     let beacon_root = select_root(
-        wrapper.hashing_function,
+        hashing_function,
         bankai_block.beacon.mmr_root_keccak,
         bankai_block.beacon.mmr_root_poseidon,
     );
 
-    let mut batch_results = BatchResults {
-        evm: EvmResults {
-            execution_header: Vec::new(),
-            beacon_header: Vec::new(),
-            account: Vec::new(),
-            tx: Vec::new(),
-            receipt: Vec::new(),
-            storage_slot: Vec::new(),
-        },
-        op_stack: OpStackResults {
-            header: Vec::new(),
-            account: Vec::new(),
-            tx: Vec::new(),
-            receipt: Vec::new(),
-            storage_slot: Vec::new(),
-        },
-    };
+    let mut report = BatchReport::default();
 
-    if let Some(evm) = &wrapper.evm_proofs {
-        for proof in &evm.execution_header_proof {
-            let result = ExecutionVerifier::verify_header_proof(proof, exec_root)?;
-            batch_results.evm.execution_header.push(result);
-        }
+    if let Some(evm) = evm_proofs {
+        let report = &mut report.evm;
 
-        for proof in &evm.beacon_header_proof {
-            let result = BeaconVerifier::verify_header_proof(proof, beacon_root)?;
-            batch_results.evm.beacon_header.push(result);
+        report.execution_header = items.each(&evm.execution_header_proof, |proof| {
+            let header = ExecutionVerifier::verify_header_proof(proof, exec_root)?;
+            config.check_finality(bankai_block.execution_finality(header.number))?;
+            Ok(header)
+        });
+        report.execution_header_batch = items.each(&evm.execution_header_batch_proof, |proof| {
+            let headers = ExecutionVerifier::verify_header_batch_proof(proof, exec_root)?;
+            for header in &headers {
+                config.check_finality(bankai_block.execution_finality(header.number))?;
+            }
+            Ok(headers)
+        });
+        report.execution_header_range = items.each(&evm.execution_header_range_proof, |proof| {
+            let range = ExecutionVerifier::verify_header_range_proof(proof, exec_root)?;
+            for header in &range.headers {
+                config.check_finality(bankai_block.execution_finality(header.number))?;
+            }
+            Ok(range)
+        });
+        let mut execution_headers = verified(&report.execution_header);
+        execution_headers.extend(
+            verified(&report.execution_header_batch)
+                .into_iter()
+                .flatten(),
+        );
+        execution_headers.extend(
+            verified(&report.execution_header_range)
+                .into_iter()
+                .flat_map(|range| range.headers),
+        );
+        let mut execution_refs = BTreeMap::new();
+        for (proof, header) in evm
+            .execution_header_proof
+            .iter()
+            .zip(&report.execution_header)
+        {
+            if let Ok(header) = header {
+                execution_refs.entry(header.number).or_insert_with(|| {
+                    header_ref(
+                        trusted,
+                        proof.mmr_proof.network_id,
+                        header,
+                        proof.mmr_proof.header_hash,
+                        bankai_block.execution_finality(header.number),
+                    )
+                });
+            }
+        }
+        let batch_headers = evm
+            .execution_header_batch_proof
+            .iter()
+            .zip(&report.execution_header_batch)
+            .filter_map(|(proof, headers)| Some((&proof.mmr_proof, headers.as_ref().ok()?)));
+        let range_headers = evm
+            .execution_header_range_proof
+            .iter()
+            .zip(&report.execution_header_range)
+            .filter_map(|(proof, range)| Some((&proof.mmr_proof, &range.as_ref().ok()?.headers)));
+        for (mmr_proof, headers) in batch_headers.chain(range_headers) {
+            for (leaf, header) in mmr_proof.leaves.iter().zip(headers) {
+                execution_refs.entry(header.number).or_insert_with(|| {
+                    header_ref(
+                        trusted,
+                        mmr_proof.network_id,
+                        header,
+                        leaf.header_hash,
+                        bankai_block.execution_finality(header.number),
+                    )
+                });
+            }
         }
 
-        for account in &evm.account_proof {
-            let result = ExecutionVerifier::verify_account_proof(
-                account,
-                &batch_results.evm.execution_header,
+        report.beacon_header = items.each(&evm.beacon_header_proof, |proof| {
+            let header = BeaconVerifier::verify_header_proof(proof, beacon_root)?;
+            config.check_finality(bankai_block.beacon_finality(header.slot))?;
+            Ok(header)
+        });
+        report.beacon_header_batch = items.each(&evm.beacon_header_batch_proof, |proof| {
+            let headers = BeaconVerifier::verify_header_batch_proof(proof, beacon_root)?;
+            for header in &headers {
+                config.check_finality(bankai_block.beacon_finality(header.slot))?;
+            }
+            Ok(headers)
+        });
+        report.beacon_header_range = items.each(&evm.beacon_header_range_proof, |proof| {
+            let range = BeaconVerifier::verify_header_range_proof(proof, beacon_root)?;
+            for header in &range.headers {
+                config.check_finality(bankai_block.beacon_finality(header.slot))?;
+            }
+            Ok(range)
+        });
+        let mut beacon_headers = verified(&report.beacon_header);
+        beacon_headers.extend(verified(&report.beacon_header_batch).into_iter().flatten());
+        beacon_headers.extend(
+            verified(&report.beacon_header_range)
+                .into_iter()
+                .flat_map(|range| range.headers),
+        );
+
+        report.execution_payload = items.each(&evm.execution_payload_proof, |proof| {
+            let payload = BeaconVerifier::verify_execution_payload_proof(proof, &beacon_headers)?;
+            Ok(VerifiedExecutionPayload {
+                block: anchored_beacon_ref(trusted, payload.block.clone()),
+                ..payload
+            })
+        });
+
+        report.validator = items.each(&evm.beacon_validator_proof, |proof| {
+            let validator = BeaconStateVerifier::verify_validator_proof(proof, &beacon_headers)?;
+            Ok(VerifiedValidator {
+                block: anchored_beacon_ref(trusted, validator.block.clone()),
+                ..validator
+            })
+        });
+
+        report.account = items.each(&evm.account_proof, |proof| {
+            let resolved = with_nodes(
+                proof,
+                evm.node_pool.as_ref(),
+                VerifyError::InvalidAccountProof,
             )?;
-            batch_results
-                .evm
-                .account
-                .push(verified_account(account, result));
-        }
+            let proof = &*resolved;
+            let block = select_header_ref(&execution_refs, &proof.block_number)?;
+            ExecutionVerifier::verify_account_existence(proof, &execution_headers)
+                .map(|result| verified_account(proof, block, result))
+        });
 
-        for proof in &evm.storage_slot_proof {
-            let result = ExecutionVerifier::verify_storage_slot_proof(
+        report.storage_slot = items.each(&evm.storage_slot_proof, |proof| {
+            let resolved = with_nodes(
                 proof,
-                &batch_results.evm.execution_header,
+                evm.node_pool.as_ref(),
+                VerifyError::InvalidStorageProof,
             )?;
-            batch_results
-                .evm
-                .storage_slot
-                .push(verified_storage_slots(proof, result));
-        }
+            let proof = &*resolved;
+            let block = select_header_ref(&execution_refs, &proof.block_number)?;
+            ExecutionVerifier::verify_storage_slot_proof(proof, &execution_headers)
+                .map(|result| verified_storage_slots(proof, block, result))
+        });
 
-        for proof in &evm.tx_proof {
-            let result =
-                ExecutionVerifier::verify_tx_proof(proof, &batch_results.evm.execution_header)?;
-            batch_results
-                .evm
-                .tx
-                .push(verified_transaction(proof, result));
-        }
+        report.tx = items.each(&evm.tx_proof, |proof| {
+            let block = select_header_ref(&execution_refs, &proof.block_number)?;
+            ExecutionVerifier::verify_tx_proof(proof, &execution_headers)
+                .map(|result| verified_transaction(proof, block, result))
+        });
 
-        for proof in &evm.receipt_proof {
-            let result = ExecutionVerifier::verify_receipt_proof(
+        report.receipt = items.each(&evm.receipt_proof, |proof| {
+            let block = select_header_ref(&execution_refs, &proof.block_number)?;
+            let result = ExecutionVerifier::verify_receipt_proof(proof, &execution_headers)?;
+            let tx_hash = bound_receipt_tx_hash(
                 proof,
-                &batch_results.evm.execution_header,
+                report
+                    .tx
+                    .iter()
+                    .flatten()
+                    .map(|tx| (&tx.block, tx.tx_index, tx.tx_hash)),
             )?;
-            batch_results
-                .evm
-                .receipt
-                .push(verified_receipt(proof, result));
-        }
+            Ok(verified_receipt(proof, block, result, tx_hash))
+        });
+
+        report.code = items.each(&evm.code_proof, |proof| {
+            let resolved = with_nodes(
+                proof,
+                evm.node_pool.as_ref(),
+                VerifyError::InvalidAccountProof,
+            )?;
+            let proof = &*resolved;
+            let block = select_header_ref(&execution_refs, &proof.block_number)?;
+            ExecutionVerifier::verify_code_proof(proof, &execution_headers)
+                .map(|result| verified_code(proof, block, result))
+        });
+        report.log = items.each(&evm.log_proof, |proof| {
+            check_log_binding(proof)?;
+            let block = select_header_ref(&execution_refs, &proof.receipt.block_number)?;
+            ExecutionVerifier::verify_tx_proof(&proof.tx, &execution_headers)?;
+            let receipt =
+                ExecutionVerifier::verify_receipt_proof(&proof.receipt, &execution_headers)?;
+            Ok(verified_logs(proof, block, receipt.logs()))
+        });
     }
 
-    if let Some(op_stack) = &wrapper.op_stack_proofs {
-        let mut verified_op_headers = BTreeMap::new();
+    if let Some(op_stack) = op_stack_proofs {
+        let report = &mut report.op_stack;
 
-        for proof in &op_stack.header_proof {
+        report.header = items.each(&op_stack.header_proof, |proof| {
             let header = OpStackVerifier::verify_header_proof(
                 proof,
                 bankai_block.op_chains.root,
-                wrapper.hashing_function,
+                hashing_function,
+            )?;
+            config.check_finality(bankai_block.op_finality(&proof.snapshot))?;
+            Ok(header)
+        });
+        report.header_batch = items.each(&op_stack.header_batch_proof, |proof| {
+            let headers = OpStackVerifier::verify_header_batch_proof(
+                proof,
+                bankai_block.op_chains.root,
+                hashing_function,
             )?;
-            verified_op_headers.insert((proof.snapshot.chain_id, header.number), header.clone());
-            batch_results.op_stack.header.push(header);
+            config.check_finality(bankai_block.op_finality(&proof.snapshot))?;
+            Ok(headers)
+        });
+        report.header_range = items.each(&op_stack.header_range_proof, |proof| {
+            let range = OpStackVerifier::verify_header_range_proof(
+                proof,
+                bankai_block.op_chains.root,
+                hashing_function,
+            )?;
+            config.check_finality(bankai_block.op_finality(&proof.snapshot))?;
+            Ok(range)
+        });
+        let mut verified_op_headers: BTreeMap<_, _> = op_stack
+            .header_proof
+            .iter()
+            .zip(&report.header)
+            .filter_map(|(proof, header)| {
+                let header = header.as_ref().ok()?;
+                Some(((proof.snapshot.chain_id, header.number), header.clone()))
+            })
+            .collect();
+        let mut op_refs: BTreeMap<_, _> = op_stack
+            .header_proof
+            .iter()
+            .zip(&report.header)
+            .filter_map(|(proof, header)| {
+                let header = header.as_ref().ok()?;
+                let block = header_ref(
+                    trusted,
+                    proof.snapshot.chain_id,
+                    header,
+                    proof.mmr_proof.header_hash,
+                    bankai_block.op_finality(&proof.snapshot),
+                );
+                Some(((proof.snapshot.chain_id, header.number), block))
+            })
+            .collect();
+        let batch_headers = op_stack
+            .header_batch_proof
+            .iter()
+            .zip(&report.header_batch)
+            .filter_map(|(proof, headers)| Some((proof, headers.as_ref().ok()?)));
+        let range_headers = op_stack
+            .header_range_proof
+            .iter()
+            .zip(&report.header_range)
+            .filter_map(|(proof, range)| Some((proof, &range.as_ref().ok()?.headers)));
+        for (proof, headers) in batch_headers.chain(range_headers) {
+            let chain_id = proof.snapshot.chain_id;
+            for (leaf, header) in proof.mmr_proof.leaves.iter().zip(headers) {
+                verified_op_headers
+                    .entry((chain_id, header.number))
+                    .or_insert_with(|| header.clone());
+                op_refs.entry((chain_id, header.number)).or_insert_with(|| {
+                    header_ref(
+                        trusted,
+                        chain_id,
+                        header,
+                        leaf.header_hash,
+                        bankai_block.op_finality(&proof.snapshot),
+                    )
+                });
+            }
         }
 
-        for proof in &op_stack.account_proof {
+        report.account = items.each(&op_stack.account_proof, |proof| {
+            let resolved = with_nodes(
+                proof,
+                op_stack.node_pool.as_ref(),
+                VerifyError::InvalidAccountProof,
+            )?;
+            let proof = &*resolved;
             let header =
                 select_op_header(&verified_op_headers, proof.network_id, proof.block_number)?;
-            let result = OpStackVerifier::verify_account_proof(proof, slice::from_ref(header))?;
-            batch_results
-                .op_stack
-                .account
-                .push(verified_account(proof, result));
-        }
+            let block = select_header_ref(&op_refs, &(proof.network_id, proof.block_number))?;
+            OpStackVerifier::verify_account_existence(proof, slice::from_ref(header))
+                .map(|result| verified_account(proof, block, result))
+        });
 
-        for proof in &op_stack.storage_slot_proof {
+        report.storage_slot = items.each(&op_stack.storage_slot_proof, |proof| {
+            let resolved = with_nodes(
+                proof,
+                op_stack.node_pool.as_ref(),
+                VerifyError::InvalidStorageProof,
+            )?;
+            let proof = &*resolved;
             let header =
                 select_op_header(&verified_op_headers, proof.network_id, proof.block_number)?;
-            let result =
-                OpStackVerifier::verify_storage_slot_proof(proof, slice::from_ref(header))?;
-            batch_results
-                .op_stack
-                .storage_slot
-                .push(verified_storage_slots(proof, result));
-        }
+            let block = select_header_ref(&op_refs, &(proof.network_id, proof.block_number))?;
+            OpStackVerifier::verify_storage_slot_proof(proof, slice::from_ref(header))
+                .map(|result| verified_storage_slots(proof, block, result))
+        });
 
-        for proof in &op_stack.tx_proof {
+        report.tx = items.each(&op_stack.tx_proof, |proof| {
             let header =
                 select_op_header(&verified_op_headers, proof.network_id, proof.block_number)?;
-            let result = OpStackVerifier::verify_tx_proof(proof, slice::from_ref(header))?;
-            batch_results
-                .op_stack
-                .tx
-                .push(verified_transaction(proof, result));
-        }
+            let block = select_header_ref(&op_refs, &(proof.network_id, proof.block_number))?;
+            OpStackVerifier::verify_tx_proof(proof, slice::from_ref(header))
+                .map(|result| verified_op_transaction(proof, block, result))
+        });
 
-        for proof in &op_stack.receipt_proof {
+        report.receipt = items.each(&op_stack.receipt_proof, |proof| {
             let header =
                 select_op_header(&verified_op_headers, proof.network_id, proof.block_number)?;
+            let block = select_header_ref(&op_refs, &(proof.network_id, proof.block_number))?;
             let result = OpStackVerifier::verify_receipt_proof(proof, slice::from_ref(header))?;
-            batch_results
-                .op_stack
-                .receipt
-                .push(verified_receipt(proof, result));
+            let tx_hash = bound_receipt_tx_hash(
+                proof,
+                report
+                    .tx
+                    .iter()
+                    .flatten()
+                    .map(|tx| (&tx.block, tx.tx_index, tx.tx_hash)),
+            )?;
+            Ok(verified_op_receipt(proof, block, result, tx_hash))
+        });
+
+        report.code = items.each(&op_stack.code_proof, |proof| {
+            let resolved = with_nodes(
+                proof,
+                op_stack.node_pool.as_ref(),
+                VerifyError::InvalidAccountProof,
+            )?;
+            let proof = &*resolved;
+            let header =
+                select_op_header(&verified_op_headers, proof.network_id, proof.block_number)?;
+            let block = select_header_ref(&op_refs, &(proof.network_id, proof.block_number))?;
+            OpStackVerifier::verify_code_proof(proof, slice::from_ref(header))
+                .map(|result| verified_code(proof, block, result))
+        });
+        report.log = items.each(&op_stack.log_proof, |proof| {
+            check_log_binding(proof)?;
+            let header = select_op_header(
+                &verified_op_headers,
+                proof.receipt.network_id,
+                proof.receipt.block_number,
+            )?;
+            let block = select_header_ref(
+                &op_refs,
+                &(proof.receipt.network_id, proof.receipt.block_number),
+            )?;
+            OpStackVerifier::verify_tx_proof(&proof.tx, slice::from_ref(header))?;
+            let receipt =
+                OpStackVerifier::verify_receipt_proof(&proof.receipt, slice::from_ref(header))?;
+            Ok(verified_logs(proof, block, receipt.logs()))
+        });
+    }
+
+    report
+}
+
+/// Verifies the proof lists of a bundle, optionally stopping at the first failure.
+struct ItemVerifier {
+    fail_fast: bool,
+    failed: AtomicBool,
+}
+
+impl ItemVerifier {
+    /// Verifies every proof, whatever the outcome of the others.
+    fn exhaustive() -> Self {
+        Self {
+            fail_fast: false,
+            failed: AtomicBool::new(false),
+        }
+    }
+
+    /// Skips every remaining proof once one fails.
+    fn fail_fast() -> Self {
+        Self {
+            fail_fast: true,
+            failed: AtomicBool::new(false),
         }
     }
 
-    Ok(batch_results)
+    /// Verifies one proof, or fails with `Skipped` if an earlier failure stopped verification.
+    fn verify_one<P, T>(&self, proof: &P, verify: impl Fn(&P) -> ItemResult<T>) -> ItemResult<T> {
+        if self.failed.load(Ordering::Relaxed) {
+            return Err(VerifyError::Skipped);
+        }
+        let result = verify(proof);
+        if self.fail_fast && result.is_err() {
+            self.failed.store(true, Ordering::Relaxed);
+        }
+        result
+    }
+
+    /// Verifies every proof of a list, keeping the results in proof order.
+    ///
+    /// The results line up with `proofs`. In fail-fast mode, proofs after the first failure
+    /// fail with `Skipped`.
+    #[cfg(not(feature = "parallel"))]
+    fn each<P, T>(&self, proofs: &[P], verify: impl Fn(&P) -> ItemResult<T>) -> Vec<ItemResult<T>> {
+        proofs
+            .iter()
+            .map(|proof| self.verify_one(proof, &verify))
+            .collect()
+    }
+
+    /// Verifies every proof of a list concurrently, keeping the results in proof order.
+    ///
+    /// Only the proofs of this one list run in parallel; callers verify the lists in turn.
+    /// The results line up with `proofs`. In fail-fast mode, proofs not yet started when one
+    /// fails are `Skipped`.
+    #[cfg(feature = "parallel")]
+    fn each<P: Sync, T: Send>(
+        &self,
+        proofs: &[P],
+        verify: impl Fn(&P) -> ItemResult<T> + Sync + Send,
+    ) -> Vec<ItemResult<T>> {
+        use rayon::prelude::*;
+
+        proofs
+            .par_iter()
+            .map(|proof| self.verify_one(proof, &verify))
+            .collect()
+    }
+}
+
+/// Returns `proof` with its MPT nodes resolved from the bundle's node pool, if it has one.
+///
+/// Fails with `error` if the pool is missing a referenced node.
+fn with_nodes<'a, P: PooledNodes + Clone>(
+    proof: &'a P,
+    node_pool: Option<&NodePool>,
+    error: VerifyError,
+) -> Result<Cow<'a, P>, VerifyError> {
+    match node_pool {
+        Some(pool) => proof.resolve_nodes(pool).map(Cow::Owned).ok_or(error),
+        None => Ok(Cow::Borrowed(proof)),
+    }
+}
+
+/// Clones the successfully verified items of a report list.
+fn verified<T: Clone>(items: &[ItemResult<T>]) -> Vec<T> {
+    items.iter().flatten().cloned().collect()
+}
+
+/// Builds the reference shared by every result proven against a verified header.
+fn header_ref(
+    trusted: &TrustedBankaiBlock,
+    network_id: u64,
+    header: &ExecutionHeader,
+    block_hash: FixedBytes<32>,
+    finality: Finality,
+) -> BlockRef {
+    BlockRef {
+        network_id,
+        block_number: header.number,
+        block_hash,
+        timestamp: header.timestamp,
+        state_root: header.state_root,
+        finality,
+        bankai_block_number: trusted.block().block_number,
+        bankai_block_hash: trusted.block_hash(),
+    }
+}
+
+/// Adds the beacon finality and Bankai anchor of `trusted` to a beacon block reference.
+fn anchored_beacon_ref(trusted: &TrustedBankaiBlock, block: BlockRef) -> BlockRef {
+    BlockRef {
+        finality: trusted.block().beacon_finality(block.block_number),
+        bankai_block_number: trusted.block().block_number,
+        bankai_block_hash: trusted.block_hash(),
+        ..block
+    }
+}
+
+/// Returns the reference of the verified header a proof depends on.
+fn select_header_ref<'a, K: Ord>(
+    refs: &'a BTreeMap<K, BlockRef>,
+    key: &K,
+) -> Result<&'a BlockRef, VerifyError> {
+    refs.get(key)
+        .ok_or(VerifyError::InvalidExecutionHeaderProof)
 }
 
-fn block_ref(network_id: u64, block_number: u64) -> BlockRef {
+/// Returns `header` with the network and block number requested by a proof.
+fn block_ref(header: &BlockRef, network_id: u64, block_number: u64) -> BlockRef {
     BlockRef {
         network_id,
         block_number,
+        ..header.clone()
     }
 }
 
-fn verified_account(proof: &AccountProof, account: TrieAccount) -> VerifiedAccount {
+fn verified_account(
+    proof: &AccountProof,
+    header: &BlockRef,
+    account: Option<TrieAccount>,
+) -> VerifiedAccount {
     VerifiedAccount {
-        block: block_ref(proof.network_id, proof.block_number),
+        block: block_ref(header, proof.network_id, proof.block_number),
         address: proof.address,
-        account,
+        exists: account.is_some(),
+        account: account.unwrap_or_else(AccountProof::empty_account),
     }
 }
 
 fn verified_storage_slots(
     proof: &StorageSlotProof,
+    header: &BlockRef,
     slots: Vec<(U256, U256)>,
 ) -> VerifiedStorageSlots {
     VerifiedStorageSlots {
-        block: block_ref(proof.network_id, proof.block_number),
+        block: block_ref(header, proof.network_id, proof.block_number),
         address: proof.address,
         slots,
     }
 }
 
-fn verified_transaction(proof: &TxProof, tx: TxEnvelope) -> VerifiedTransaction {
+fn verified_code(proof: &CodeProof, header: &BlockRef, code: Bytes) -> VerifiedCode {
+    VerifiedCode {
+        block: block_ref(header, proof.network_id, proof.block_number),
+        address: proof.address,
+        code_hash: proof.account.code_hash,
+        code,
+    }
+}
+
+fn verified_transaction(proof: &TxProof, header: &BlockRef, tx: TxEnvelope) -> VerifiedTransaction {
     VerifiedTransaction {
-        block: block_ref(proof.network_id, proof.block_number),
+        block: block_ref(header, proof.network_id, proof.block_number),
         tx_hash: proof.tx_hash,
         tx_index: proof.tx_index,
         tx,
     }
 }
 
-fn verified_receipt(proof: &ReceiptProof, receipt: ReceiptEnvelope) -> VerifiedReceipt {
+fn verified_receipt(
+    proof: &ReceiptProof,
+    header: &BlockRef,
+    receipt: ReceiptEnvelope,
+    tx_hash: Option<FixedBytes<32>>,
+) -> VerifiedReceipt {
     VerifiedReceipt {
-        block: block_ref(proof.network_id, proof.block_number),
+        block: block_ref(header, proof.network_id, proof.block_number),
+        tx_hash,
+        tx_index: proof.tx_index,
+        receipt,
+    }
+}
+
+fn verified_op_transaction(
+    proof: &TxProof,
+    header: &BlockRef,
+    tx: OpTxEnvelope,
+) -> VerifiedOpTransaction {
+    VerifiedOpTransaction {
+        block: block_ref(header, proof.network_id, proof.block_number),
         tx_hash: proof.tx_hash,
         tx_index: proof.tx_index,
+        tx,
+    }
+}
+
+fn verified_op_receipt(
+    proof: &ReceiptProof,
+    header: &BlockRef,
+    receipt: OpReceiptEnvelope,
+    tx_hash: Option<FixedBytes<32>>,
+) -> VerifiedOpReceipt {
+    VerifiedOpReceipt {
+        block: block_ref(header, proof.network_id, proof.block_number),
+        tx_hash,
+        tx_index: proof.tx_index,
         receipt,
     }
 }
 
+/// Checks that the transaction and receipt of a log proof sit at the same position.
+fn check_log_binding(proof: &LogProof) -> Result<(), VerifyError> {
+    let (tx, receipt) = (&proof.tx, &proof.receipt);
+    if tx.network_id != receipt.network_id
+        || tx.block_number != receipt.block_number
+        || tx.tx_index != receipt.tx_index
+        || tx.tx_hash != receipt.tx_hash
+    {
+        return Err(VerifyError::InvalidTxHash);
+    }
+    Ok(())
+}
+
+/// Returns the receipt logs that match the proof's filter, keeping their receipt position.
+fn verified_logs(proof: &LogProof, header: &BlockRef, logs: &[Log]) -> Vec<VerifiedLog> {
+    logs.iter()
+        .enumerate()
+        .filter(|(_, log)| proof.filter.matches(&log.address, log.topics()))
+        .map(|(log_index, log)| VerifiedLog {
+            block: block_ref(header, proof.receipt.network_id, proof.receipt.block_number),
+            tx_hash: proof.tx.tx_hash,
+            tx_index: proof.tx.tx_index,
+            log_index: log_index as u64,
+            address: log.address,
+            topics: log.topics().to_vec(),
+            data: log.data.data.clone(),
+            filter: proof.filter.clone(),
+        })
+        .collect()
+}
+
+/// Binds a receipt to the transaction hash verified at the same index, if any.
+///
+/// Receipt proofs are keyed by index only, so the claimed hash is kept only when a
+/// transaction proof for the same block and index was verified and agrees with it.
+fn bound_receipt_tx_hash<'a>(
+    proof: &ReceiptProof,
+    verified_txs: impl IntoIterator<Item = (&'a BlockRef, u64, FixedBytes<32>)>,
+) -> Result<Option<FixedBytes<32>>, VerifyError> {
+    let Some((_, _, tx_hash)) = verified_txs.into_iter().find(|(block, tx_index, _)| {
+        block.network_id == proof.network_id
+            && block.block_number == proof.block_number
+            && *tx_index == proof.tx_index
+    }) else {
+        return Ok(None);
+    };
+
+    if tx_hash != proof.tx_hash {
+        return Err(VerifyError::InvalidTxHash);
+    }
+    Ok(Some(tx_hash))
+}
+
 fn select_root<T: Copy>(hashing_function: HashingFunction, keccak: T, poseidon: T) -> T {
     match hashing_function {
         HashingFunction::Keccak => keccak,
@@ -254,20 +943,168 @@ fn select_op_header(
 #[cfg(test)]
 mod tests {
     use super::{
-        select_op_header, verified_account, verified_receipt, verified_storage_slots,
-        verified_transaction,
+        bound_receipt_tx_hash, check_log_binding, select_op_header, verified_account,
+        verified_code, verified_logs, verified_receipt, verified_storage_slots,
+        verified_transaction, verify_many_with, with_nodes, BlockRef, BundleBody, ItemVerifier,
     };
+    use crate::{TrustedBankaiBlock, VerifierConfig, VerifyError};
     use alloy_consensus::{
         Receipt, ReceiptEnvelope, ReceiptWithBloom, Signed, TxEnvelope, TxLegacy,
     };
-    use alloy_primitives::{Address, Bloom, Bytes, FixedBytes, Signature, TxKind, B256, U256};
+    use alloy_primitives::{Address, Bloom, Bytes, FixedBytes, Log, Signature, TxKind, B256, U256};
+    use bankai_types::block::BankaiBlock;
+    use bankai_types::common::{Finality, HashingFunction, Network};
     use bankai_types::inputs::evm::execution::{
-        AccountProof, ReceiptProof, StorageSlotEntry, StorageSlotProof, TxProof,
+        AccountProof, CodeProof, LogFilter, LogProof, ReceiptProof, StorageSlotEntry,
+        StorageSlotProof, TxProof,
     };
+    use bankai_types::inputs::evm::node_pool::{NodePool, PooledNodes};
     use bankai_types::results::evm::execution::ExecutionHeader;
     use bankai_types::results::evm::execution::TrieAccount;
     use std::collections::BTreeMap;
 
+    fn bankai_block(block_number: u64) -> BankaiBlock {
+        BankaiBlock {
+            program_hash: FixedBytes::from([0x11; 32]),
+            version: 1,
+            block_number,
+            ..Default::default()
+        }
+    }
+
+    fn body(block: BankaiBlock) -> BundleBody {
+        BundleBody {
+            hashing_function: HashingFunction::Keccak,
+            block,
+            evm_proofs: None,
+            op_stack_proofs: None,
+        }
+    }
+
+    fn trusting_config() -> VerifierConfig {
+        VerifierConfig::new(Network::Sepolia).with_program_hash(FixedBytes::from([0x11; 32]))
+    }
+
+    #[test]
+    fn verifies_bundle_against_its_trusted_block() {
+        let trusted = TrustedBankaiBlock::new_unchecked(bankai_block(7));
+
+        let results = body(bankai_block(7))
+            .verify_with_trusted_block(&trusted, &trusting_config())
+            .unwrap();
+
+        assert!(results.evm.execution_header.is_empty());
+        assert!(results.op_stack.header.is_empty());
+    }
+
+    #[test]
+    fn rejects_bundle_for_other_trusted_block() {
+        let trusted = TrustedBankaiBlock::new_unchecked(bankai_block(7));
+
+        assert_eq!(
+            body(bankai_block(8))
+                .verify_with_trusted_block(&trusted, &trusting_config())
+                .unwrap_err(),
+            VerifyError::InvalidBlockHash
+        );
+    }
+
+    #[test]
+    fn rejects_trusted_block_not_accepted_by_config() {
+        let trusted = TrustedBankaiBlock::new_unchecked(bankai_block(7));
+        let config = VerifierConfig::new(Network::Sepolia);
+
+        assert_eq!(
+            body(bankai_block(7))
+                .verify_with_trusted_block(&trusted, &config)
+                .unwrap_err(),
+            VerifyError::UntrustedProgram
+        );
+    }
+
+    #[test]
+    fn verify_many_checks_each_block_once() {
+        let bundles = [7, 8, 7].map(|number| ((), body(bankai_block(number))));
+        let mut verified_blocks = Vec::new();
+
+        let results = verify_many_with(bundles, &trusting_config(), |(), block| {
+            verified_blocks.push(block.block_number);
+            Ok(TrustedBankaiBlock::new_unchecked(block.clone()))
+        })
+        .unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(verified_blocks, vec![7, 8]);
+    }
+
+    #[test]
+    fn verify_many_skips_block_proofs_of_untrusted_programs() {
+        let bundles = [((), body(bankai_block(7)))];
+        let config = VerifierConfig::new(Network::Sepolia);
+
+        let result = verify_many_with(bundles, &config, |(), _| -> Result<_, VerifyError> {
+            panic!("untrusted block proofs must not be verified")
+        });
+
+        assert_eq!(result.unwrap_err(), VerifyError::UntrustedProgram);
+    }
+
+    #[test]
+    fn verify_many_stops_at_failed_block_proof() {
+        let bundles = [7, 8].map(|number| ((), body(bankai_block(number))));
+
+        let result = verify_many_with(bundles, &trusting_config(), |(), _| {
+            Err(VerifyError::InvalidStwoProof)
+        });
+
+        assert_eq!(result.unwrap_err(), VerifyError::InvalidStwoProof);
+    }
+
+    #[test]
+    fn verify_each_keeps_proof_order() {
+        let proofs: Vec<u64> = (0..1_000).collect();
+        let results = ItemVerifier::exhaustive().each(&proofs, |proof| {
+            if proof % 7 == 0 {
+                Err(VerifyError::InvalidMerkleProof)
+            } else {
+                Ok(proof * 2)
+            }
+        });
+
+        assert_eq!(results.len(), proofs.len());
+        for (proof, result) in proofs.iter().zip(results) {
+            match result {
+                Ok(value) => assert_eq!(value, proof * 2),
+                Err(error) => {
+                    assert_eq!(proof % 7, 0);
+                    assert_eq!(error, VerifyError::InvalidMerkleProof);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fail_fast_verification_stops_after_first_failure() {
+        let items = ItemVerifier::fail_fast();
+        let proofs: Vec<u64> = (0..10).collect();
+
+        let first = items.each(&proofs, |proof| {
+            if *proof == 0 {
+                Err(VerifyError::InvalidMerkleProof)
+            } else {
+                Ok(*proof)
+            }
+        });
+        let second = items.each(&proofs, |proof| Ok(*proof));
+
+        assert_eq!(first.len(), proofs.len());
+        assert!(first.contains(&Err(VerifyError::InvalidMerkleProof)));
+        assert_eq!(second.len(), proofs.len());
+        assert!(second
+            .iter()
+            .all(|result| *result == Err(VerifyError::Skipped)));
+    }
+
     #[test]
     fn select_op_header_uses_network_id_and_block_number() {
         let mut headers = BTreeMap::new();
@@ -307,12 +1144,13 @@ mod tests {
                 state_root: FixedBytes::ZERO,
                 mpt_proof: vec![],
             },
-            TrieAccount {
+            &BlockRef::default(),
+            Some(TrieAccount {
                 nonce: 1,
                 balance: U256::from(10u64),
                 storage_root: FixedBytes::ZERO,
                 code_hash: FixedBytes::ZERO,
-            },
+            }),
         );
         let second = verified_account(
             &AccountProof {
@@ -323,21 +1161,58 @@ mod tests {
                 state_root: FixedBytes::ZERO,
                 mpt_proof: vec![],
             },
-            TrieAccount {
+            &BlockRef::default(),
+            Some(TrieAccount {
                 nonce: 2,
                 balance: U256::from(20u64),
                 storage_root: FixedBytes::ZERO,
                 code_hash: FixedBytes::ZERO,
-            },
+            }),
         );
 
         assert_eq!(first.block.network_id, 1);
         assert_eq!(first.block.block_number, 7);
         assert_eq!(first.address, Address::repeat_byte(0x11));
+        assert!(first.exists);
         assert_eq!(first.account.balance, U256::from(10u64));
         assert_eq!(second.block.block_number, 8);
     }
 
+    #[test]
+    fn verified_results_carry_header_context() {
+        let header = BlockRef {
+            network_id: 1,
+            block_number: 7,
+            block_hash: FixedBytes::from([0x70; 32]),
+            timestamp: 1_700_000_000,
+            state_root: FixedBytes::from([0x71; 32]),
+            finality: Finality::Finalized,
+            bankai_block_number: 42,
+            bankai_block_hash: FixedBytes::from([0x42; 32]),
+        };
+        let result = verified_code(
+            &CodeProof {
+                account: AccountProof::empty_account(),
+                address: Address::repeat_byte(0x34),
+                network_id: 11155111,
+                block_number: 7,
+                state_root: FixedBytes::ZERO,
+                account_mpt_proof: vec![],
+                code: Bytes::new(),
+            },
+            &header,
+            Bytes::new(),
+        );
+
+        assert_eq!(
+            result.block,
+            BlockRef {
+                network_id: 11155111,
+                ..header
+            }
+        );
+    }
+
     #[test]
     fn verified_op_stack_account_distinguishes_same_block_across_chains() {
         let first = verified_account(
@@ -349,12 +1224,13 @@ mod tests {
                 state_root: FixedBytes::ZERO,
                 mpt_proof: vec![],
             },
-            TrieAccount {
+            &BlockRef::default(),
+            Some(TrieAccount {
                 nonce: 1,
                 balance: U256::from(5u64),
                 storage_root: FixedBytes::ZERO,
                 code_hash: FixedBytes::ZERO,
-            },
+            }),
         );
         let second = verified_account(
             &AccountProof {
@@ -365,12 +1241,13 @@ mod tests {
                 state_root: FixedBytes::ZERO,
                 mpt_proof: vec![],
             },
-            TrieAccount {
+            &BlockRef::default(),
+            Some(TrieAccount {
                 nonce: 1,
                 balance: U256::from(6u64),
                 storage_root: FixedBytes::ZERO,
                 code_hash: FixedBytes::ZERO,
-            },
+            }),
         );
 
         assert_eq!(first.block.block_number, second.block.block_number);
@@ -378,6 +1255,25 @@ mod tests {
         assert_eq!(second.block.network_id, 84532);
     }
 
+    #[test]
+    fn verified_absent_account_reports_empty_account() {
+        let result = verified_account(
+            &AccountProof {
+                account: AccountProof::empty_account(),
+                address: Address::repeat_byte(0x23),
+                network_id: 1,
+                block_number: 7,
+                state_root: FixedBytes::ZERO,
+                mpt_proof: vec![],
+            },
+            &BlockRef::default(),
+            None,
+        );
+
+        assert!(!result.exists);
+        assert_eq!(result.account, AccountProof::empty_account());
+    }
+
     #[test]
     fn verified_storage_slots_keep_block_and_address_identity() {
         let result = verified_storage_slots(
@@ -394,6 +1290,7 @@ mod tests {
                     storage_mpt_proof: vec![],
                 }],
             },
+            &BlockRef::default(),
             vec![(U256::from(1u64), U256::from(2u64))],
         );
 
@@ -403,6 +1300,34 @@ mod tests {
         assert_eq!(result.slots, vec![(U256::from(1u64), U256::from(2u64))]);
     }
 
+    #[test]
+    fn verified_code_keeps_block_and_address_identity() {
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let code_hash = FixedBytes::from([0x44; 32]);
+        let result = verified_code(
+            &CodeProof {
+                account: TrieAccount {
+                    code_hash,
+                    ..AccountProof::empty_account()
+                },
+                address: Address::repeat_byte(0x34),
+                network_id: 10,
+                block_number: 8,
+                state_root: FixedBytes::ZERO,
+                account_mpt_proof: vec![],
+                code: code.clone(),
+            },
+            &BlockRef::default(),
+            code.clone(),
+        );
+
+        assert_eq!(result.block.network_id, 10);
+        assert_eq!(result.block.block_number, 8);
+        assert_eq!(result.address, Address::repeat_byte(0x34));
+        assert_eq!(result.code_hash, code_hash);
+        assert_eq!(result.code, code);
+    }
+
     #[test]
     fn verified_transaction_keeps_block_and_tx_identity() {
         let tx = TxEnvelope::Legacy(Signed::new_unchecked(
@@ -427,6 +1352,7 @@ mod tests {
                 proof: vec![],
                 encoded_tx: vec![],
             },
+            &BlockRef::default(),
             tx,
         );
 
@@ -456,13 +1382,170 @@ mod tests {
                 proof: vec![],
                 encoded_receipt: vec![],
             },
+            &BlockRef::default(),
             receipt,
+            Some(FixedBytes::from([8u8; 32])),
         );
 
         assert_eq!(result.block.network_id, 11155111);
         assert_eq!(result.block.block_number, 55);
-        assert_eq!(result.tx_hash, FixedBytes::from([8u8; 32]));
+        assert_eq!(result.tx_hash, Some(FixedBytes::from([8u8; 32])));
         assert_eq!(result.tx_index, 3);
         assert!(result.receipt.status());
     }
+
+    fn legacy_tx() -> TxEnvelope {
+        TxEnvelope::Legacy(Signed::new_unchecked(
+            TxLegacy::default(),
+            Signature::new(U256::from(1u64), U256::from(2u64), false),
+            B256::with_last_byte(0x55),
+        ))
+    }
+
+    fn receipt_proof(tx_hash: FixedBytes<32>, tx_index: u64) -> ReceiptProof {
+        ReceiptProof {
+            network_id: 11155111,
+            block_number: 55,
+            tx_hash,
+            tx_index,
+            proof: vec![],
+            encoded_receipt: vec![],
+        }
+    }
+
+    fn verified_tx(tx_hash: FixedBytes<32>, tx_index: u64) -> super::VerifiedTransaction {
+        verified_transaction(
+            &TxProof {
+                network_id: 11155111,
+                block_number: 55,
+                tx_hash,
+                tx_index,
+                proof: vec![],
+                encoded_tx: vec![],
+            },
+            &BlockRef::default(),
+            legacy_tx(),
+        )
+    }
+
+    fn tx_refs(
+        txs: &[super::VerifiedTransaction],
+    ) -> impl Iterator<Item = (&super::BlockRef, u64, FixedBytes<32>)> {
+        txs.iter().map(|tx| (&tx.block, tx.tx_index, tx.tx_hash))
+    }
+
+    #[test]
+    fn receipt_without_matching_tx_is_index_only() {
+        let txs = vec![verified_tx(FixedBytes::from([1u8; 32]), 0)];
+        let proof = receipt_proof(FixedBytes::from([2u8; 32]), 1);
+
+        assert_eq!(bound_receipt_tx_hash(&proof, tx_refs(&txs)), Ok(None));
+    }
+
+    #[test]
+    fn receipt_is_bound_to_tx_at_same_index() {
+        let tx_hash = FixedBytes::from([1u8; 32]);
+        let txs = vec![verified_tx(tx_hash, 3)];
+        let proof = receipt_proof(tx_hash, 3);
+
+        assert_eq!(
+            bound_receipt_tx_hash(&proof, tx_refs(&txs)),
+            Ok(Some(tx_hash))
+        );
+    }
+
+    #[test]
+    fn receipt_claiming_other_tx_hash_is_rejected() {
+        let txs = vec![verified_tx(FixedBytes::from([1u8; 32]), 3)];
+        let proof = receipt_proof(FixedBytes::from([2u8; 32]), 3);
+
+        assert_eq!(
+            bound_receipt_tx_hash(&proof, tx_refs(&txs)),
+            Err(VerifyError::InvalidTxHash)
+        );
+    }
+
+    fn log_proof(filter: LogFilter) -> LogProof {
+        let tx_hash = FixedBytes::from([4u8; 32]);
+        LogProof {
+            tx: TxProof {
+                network_id: 11155111,
+                block_number: 55,
+                tx_hash,
+                tx_index: 2,
+                proof: vec![],
+                encoded_tx: vec![],
+            },
+            receipt: receipt_proof(tx_hash, 2),
+            filter,
+        }
+    }
+
+    fn log(address: Address, topic: FixedBytes<32>, data: u8) -> Log {
+        Log::new_unchecked(address, vec![topic], Bytes::from(vec![data]))
+    }
+
+    #[test]
+    fn verified_logs_keep_matching_logs_and_receipt_position() {
+        let token = Address::repeat_byte(0x11);
+        let transfer = FixedBytes::from([0xdd; 32]);
+        let logs = vec![
+            log(token, FixedBytes::from([0xaa; 32]), 0),
+            log(Address::repeat_byte(0x22), transfer, 1),
+            log(token, transfer, 2),
+        ];
+        let proof = log_proof(LogFilter::new().address(token).event(transfer));
+
+        let result = verified_logs(&proof, &BlockRef::default(), &logs);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].block.block_number, 55);
+        assert_eq!(result[0].tx_hash, FixedBytes::from([4u8; 32]));
+        assert_eq!(result[0].tx_index, 2);
+        assert_eq!(result[0].log_index, 2);
+        assert_eq!(result[0].address, token);
+        assert_eq!(result[0].topics, vec![transfer]);
+        assert_eq!(result[0].data, Bytes::from(vec![2u8]));
+        assert_eq!(result[0].filter, proof.filter);
+    }
+
+    #[test]
+    fn log_proof_requires_tx_and_receipt_at_same_index() {
+        let mut proof = log_proof(LogFilter::new());
+        assert_eq!(check_log_binding(&proof), Ok(()));
+
+        proof.receipt.tx_index = 3;
+        assert_eq!(check_log_binding(&proof), Err(VerifyError::InvalidTxHash));
+    }
+
+    #[test]
+    fn with_nodes_resolves_pooled_proofs() {
+        let node = Bytes::from(vec![0xf8; 40]);
+        let mut proof = AccountProof {
+            account: Default::default(),
+            address: Address::repeat_byte(0x11),
+            network_id: 1,
+            block_number: 7,
+            state_root: FixedBytes::ZERO,
+            mpt_proof: vec![node.clone()],
+        };
+
+        let borrowed = with_nodes(&proof, None, VerifyError::InvalidAccountProof).unwrap();
+        assert_eq!(borrowed.mpt_proof, vec![node.clone()]);
+
+        let mut pool = NodePool::default();
+        proof.pool_nodes(&mut pool);
+        let resolved = with_nodes(&proof, Some(&pool), VerifyError::InvalidAccountProof).unwrap();
+        assert_eq!(resolved.mpt_proof, vec![node]);
+
+        assert_eq!(
+            with_nodes(
+                &proof,
+                Some(&NodePool::default()),
+                VerifyError::InvalidAccountProof
+            )
+            .unwrap_err(),
+            VerifyError::InvalidAccountProof
+        );
+    }
 }
@@ -0,0 +1,311 @@
+extern crate alloc;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use alloy_primitives::FixedBytes;
+use bankai_types::block::BankaiBlock;
+use bankai_types::common::{Finality, Network};
+use bankai_types::inputs::evm::EvmProofs;
+
+use crate::VerifyError;
+
+/// Trust policy applied to Bankai blocks before their proofs are accepted.
+///
+/// The STWO proof only shows that *some* Cairo program emitted the block hash. The policy pins
+/// which Bankai programs (`program_hash`) and versions are accepted for a [`Network`].
+///
+/// A new config accepts no program until at least one program hash is allowlisted.
+///
+/// Ethereum proofs must target the execution chain and beacon network of the config's
+/// [`Network`].
+///
+/// The policy can also require a minimum [`Finality`] for every verified header. By default
+/// headers up to the latest height committed in the Bankai block are accepted.
+///
+/// # Example
+///
+/// ```
+/// use alloy_primitives::FixedBytes;
+/// use bankai_types::common::{Finality, Network};
+/// use bankai_verify::VerifierConfig;
+///
+/// let config = VerifierConfig::new(Network::Sepolia)
+///     .with_program_hash(FixedBytes::from([0x11; 32]))
+///     .with_versions(1..=2)
+///     .with_finality(Finality::Finalized);
+/// assert_eq!(config.network(), Network::Sepolia);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifierConfig {
+    network: Network,
+    program_hashes: Vec<FixedBytes<32>>,
+    versions: RangeInclusive<u64>,
+    finality: Finality,
+}
+
+impl VerifierConfig {
+    /// Creates an empty policy for `network` that accepts any version and no program hash.
+    pub fn new(network: Network) -> Self {
+        Self {
+            network,
+            program_hashes: Vec::new(),
+            versions: 0..=u64::MAX,
+            finality: Finality::Latest,
+        }
+    }
+
+    /// Adds an accepted Bankai program hash.
+    pub fn with_program_hash(mut self, program_hash: FixedBytes<32>) -> Self {
+        if !self.program_hashes.contains(&program_hash) {
+            self.program_hashes.push(program_hash);
+        }
+        self
+    }
+
+    /// Adds several accepted Bankai program hashes.
+    pub fn with_program_hashes(
+        self,
+        program_hashes: impl IntoIterator<Item = FixedBytes<32>>,
+    ) -> Self {
+        program_hashes
+            .into_iter()
+            .fold(self, |config, hash| config.with_program_hash(hash))
+    }
+
+    /// Restricts the accepted Bankai block versions.
+    pub fn with_versions(mut self, versions: RangeInclusive<u64>) -> Self {
+        self.versions = versions;
+        self
+    }
+
+    /// Requires every verified header to be at least `finality`.
+    ///
+    /// Use [`Finality::Finalized`] to only accept headers at or below the finalized heights
+    /// committed in the Bankai block.
+    pub fn with_finality(mut self, finality: Finality) -> Self {
+        self.finality = finality;
+        self
+    }
+
+    /// Returns the network this policy applies to.
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Returns the accepted program hashes.
+    pub fn program_hashes(&self) -> &[FixedBytes<32>] {
+        &self.program_hashes
+    }
+
+    /// Returns the accepted version range.
+    pub fn versions(&self) -> &RangeInclusive<u64> {
+        &self.versions
+    }
+
+    /// Returns the required header finality.
+    pub fn finality(&self) -> Finality {
+        self.finality
+    }
+
+    /// Checks a header's finality against this policy.
+    ///
+    /// # Errors
+    ///
+    /// - `NonFinalHeader` - The header is less final than required
+    pub fn check_finality(&self, finality: Finality) -> Result<(), VerifyError> {
+        if finality > self.finality {
+            return Err(VerifyError::NonFinalHeader);
+        }
+        Ok(())
+    }
+
+    /// Checks a Bankai block's `program_hash` and `version` against this policy.
+    ///
+    /// # Errors
+    ///
+    /// - `UntrustedProgram` - The program hash is not allowlisted or the version is out of range
+    pub fn check_block(&self, block: &BankaiBlock) -> Result<(), VerifyError> {
+        if !self.program_hashes.contains(&block.program_hash)
+            || !self.versions.contains(&block.version)
+        {
+            return Err(VerifyError::UntrustedProgram);
+        }
+        Ok(())
+    }
+
+    /// Checks that every Ethereum proof of a bundle targets this policy's network.
+    ///
+    /// Execution proofs must use the network's execution chain ID and beacon proofs its
+    /// beacon network ID.
+    ///
+    /// # Errors
+    ///
+    /// - `NetworkMismatch` - A proof targets another execution chain or beacon network
+    pub fn check_evm_proofs(&self, evm: &EvmProofs) -> Result<(), VerifyError> {
+        let execution = self.network.execution_network_id();
+        let beacon = self.network.beacon_network_id();
+
+        let mut execution_ids = evm
+            .execution_header_proof
+            .iter()
+            .map(|proof| proof.mmr_proof.network_id)
+            .chain(
+                evm.execution_header_batch_proof
+                    .iter()
+                    .chain(&evm.execution_header_range_proof)
+                    .map(|proof| proof.mmr_proof.network_id),
+            )
+            .chain(evm.account_proof.iter().map(|proof| proof.network_id))
+            .chain(evm.storage_slot_proof.iter().map(|proof| proof.network_id))
+            .chain(evm.tx_proof.iter().map(|proof| proof.network_id))
+            .chain(evm.receipt_proof.iter().map(|proof| proof.network_id))
+            .chain(evm.code_proof.iter().map(|proof| proof.network_id))
+            .chain(
+                evm.log_proof
+                    .iter()
+                    .flat_map(|proof| [proof.tx.network_id, proof.receipt.network_id]),
+            );
+        let mut beacon_ids = evm
+            .beacon_header_proof
+            .iter()
+            .map(|proof| proof.mmr_proof.network_id)
+            .chain(
+                evm.beacon_header_batch_proof
+                    .iter()
+                    .chain(&evm.beacon_header_range_proof)
+                    .map(|proof| proof.mmr_proof.network_id),
+            )
+            .chain(
+                evm.execution_payload_proof
+                    .iter()
+                    .map(|proof| proof.network_id),
+            )
+            .chain(
+                evm.beacon_validator_proof
+                    .iter()
+                    .map(|proof| proof.network_id),
+            );
+
+        if execution_ids.any(|id| id != execution) || beacon_ids.any(|id| id != beacon) {
+            return Err(VerifyError::NetworkMismatch);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+    use bankai_types::inputs::evm::execution::AccountProof;
+
+    use super::*;
+
+    fn block(program_hash: FixedBytes<32>, version: u64) -> BankaiBlock {
+        BankaiBlock {
+            program_hash,
+            version,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty_config_rejects_every_program() {
+        let config = VerifierConfig::new(Network::Sepolia);
+        assert_eq!(
+            config.check_block(&block(FixedBytes::ZERO, 1)),
+            Err(VerifyError::UntrustedProgram)
+        );
+    }
+
+    #[test]
+    fn accepts_allowlisted_program_in_version_range() {
+        let hash = FixedBytes::from([0x11; 32]);
+        let config = VerifierConfig::new(Network::Sepolia)
+            .with_program_hash(hash)
+            .with_versions(1..=2);
+
+        assert_eq!(config.check_block(&block(hash, 1)), Ok(()));
+        assert_eq!(config.check_block(&block(hash, 2)), Ok(()));
+    }
+
+    #[test]
+    fn rejects_unknown_program_hash() {
+        let config = VerifierConfig::new(Network::Sepolia)
+            .with_program_hashes([FixedBytes::from([0x11; 32]), FixedBytes::from([0x22; 32])]);
+
+        assert_eq!(
+            config.check_block(&block(FixedBytes::from([0x33; 32]), 1)),
+            Err(VerifyError::UntrustedProgram)
+        );
+    }
+
+    #[test]
+    fn rejects_version_outside_range() {
+        let hash = FixedBytes::from([0x11; 32]);
+        let config = VerifierConfig::new(Network::Sepolia)
+            .with_program_hash(hash)
+            .with_versions(2..=3);
+
+        assert_eq!(
+            config.check_block(&block(hash, 1)),
+            Err(VerifyError::UntrustedProgram)
+        );
+        assert_eq!(
+            config.check_block(&block(hash, 4)),
+            Err(VerifyError::UntrustedProgram)
+        );
+    }
+
+    #[test]
+    fn deduplicates_program_hashes() {
+        let hash = FixedBytes::from([0x11; 32]);
+        let config = VerifierConfig::new(Network::Local)
+            .with_program_hash(hash)
+            .with_program_hash(hash);
+
+        assert_eq!(config.program_hashes(), &[hash]);
+    }
+
+    #[test]
+    fn rejects_proofs_for_other_network() {
+        let config = VerifierConfig::new(Network::Sepolia);
+        let account = |network_id| AccountProof {
+            account: AccountProof::empty_account(),
+            address: Address::ZERO,
+            network_id,
+            block_number: 1,
+            state_root: FixedBytes::ZERO,
+            mpt_proof: Vec::new(),
+        };
+
+        let mut evm = EvmProofs::default();
+        evm.account_proof
+            .push(account(Network::Sepolia.execution_network_id()));
+        assert_eq!(config.check_evm_proofs(&evm), Ok(()));
+
+        evm.account_proof.push(account(1));
+        assert_eq!(
+            config.check_evm_proofs(&evm),
+            Err(VerifyError::NetworkMismatch)
+        );
+    }
+
+    #[test]
+    fn accepts_any_finality_by_default() {
+        let config = VerifierConfig::new(Network::Sepolia);
+
+        assert_eq!(config.check_finality(Finality::Latest), Ok(()));
+    }
+
+    #[test]
+    fn rejects_headers_less_final_than_required() {
+        let config = VerifierConfig::new(Network::Sepolia).with_finality(Finality::Justified);
+
+        assert_eq!(config.check_finality(Finality::Finalized), Ok(()));
+        assert_eq!(config.check_finality(Finality::Justified), Ok(()));
+        assert_eq!(
+            config.check_finality(Finality::Latest),
+            Err(VerifyError::NonFinalHeader)
+        );
+    }
+}
@@ -1,13 +1,25 @@
 extern crate alloc;
 
+use alloc::vec::Vec;
+
 use alloy_primitives::FixedBytes;
-use bankai_types::inputs::evm::beacon::BeaconHeaderProof;
+use bankai_core::ssz;
+use bankai_types::inputs::evm::beacon::{
+    BeaconHeaderBatchProof, BeaconHeaderProof, ExecutionPayloadProof,
+};
 use bankai_types::results::evm::beacon::BeaconHeader;
+use bankai_types::results::evm::{BlockRef, VerifiedExecutionPayload, VerifiedHeaderRange};
 use tree_hash::TreeHash;
 
 use crate::bankai::mmr::MmrVerifier;
 use crate::VerifyError;
 
+/// `ExecutionPayloadHeader` field indices (stable from Bellatrix onwards).
+const PAYLOAD_STATE_ROOT_INDEX: usize = 2;
+const PAYLOAD_BLOCK_NUMBER_INDEX: usize = 6;
+const PAYLOAD_TIMESTAMP_INDEX: usize = 9;
+const PAYLOAD_BLOCK_HASH_INDEX: usize = 12;
+
 /// Verifier for EVM beacon chain (consensus layer) proofs
 ///
 /// Provides methods to verify beacon chain headers against trusted MMR roots.
@@ -78,4 +90,251 @@ impl BeaconVerifier {
 
         Ok(header)
     }
+
+    /// Verifies beacon headers sharing one multi-leaf MMR proof
+    ///
+    /// Same checks as [`BeaconVerifier::verify_header_proof`], with the MMR paths and peaks
+    /// verified once for all headers. The headers line up with the proof leaves.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `InvalidMmrRoot`: The MMR root in the proof doesn't match the expected root
+    /// - `InvalidMmrProof`: A leaf of the MMR proof is not committed in the root
+    /// - `InvalidHeaderHash`: A header's tree hash root doesn't match its leaf, or the counts
+    ///   differ
+    pub fn verify_header_batch_proof(
+        proof: &BeaconHeaderBatchProof,
+        root: FixedBytes<32>,
+    ) -> Result<Vec<BeaconHeader>, VerifyError> {
+        if proof.mmr_proof.root != root {
+            return Err(VerifyError::InvalidMmrRoot);
+        }
+
+        MmrVerifier::verify_mmr_multi_proof(&proof.mmr_proof)?;
+
+        if proof.headers.len() != proof.mmr_proof.leaves.len() {
+            return Err(VerifyError::InvalidHeaderHash);
+        }
+        proof
+            .headers
+            .iter()
+            .zip(&proof.mmr_proof.leaves)
+            .map(|(header, leaf)| {
+                let header = BeaconHeader::from(header.clone());
+                if header.tree_hash_root() != leaf.header_hash {
+                    return Err(VerifyError::InvalidHeaderHash);
+                }
+                Ok(header)
+            })
+            .collect()
+    }
+
+    /// Verifies a range of beacon headers sharing one multi-leaf MMR proof
+    ///
+    /// Runs [`BeaconVerifier::verify_header_batch_proof`], then checks that the slots increase
+    /// and that every header's parent root is the previous header's root. Missed slots have
+    /// no header, so slots may skip.
+    ///
+    /// The range bounds are the slots of the first and last proven header. Callers must check
+    /// that they lie within the slots they requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`BeaconVerifier::verify_header_batch_proof`], or
+    /// `BrokenHeaderChain` if the headers are not a linked chain.
+    pub fn verify_header_range_proof(
+        proof: &BeaconHeaderBatchProof,
+        root: FixedBytes<32>,
+    ) -> Result<VerifiedHeaderRange<BeaconHeader>, VerifyError> {
+        let headers = Self::verify_header_batch_proof(proof, root)?;
+        let (Some(first), Some(last)) = (headers.first(), headers.last()) else {
+            return Err(VerifyError::BrokenHeaderChain);
+        };
+        let linked = headers
+            .windows(2)
+            .zip(&proof.mmr_proof.leaves)
+            .all(|(pair, parent)| {
+                pair[1].slot > pair[0].slot && pair[1].parent_root == parent.header_hash
+            });
+        if !linked {
+            return Err(VerifyError::BrokenHeaderChain);
+        }
+        Ok(VerifiedHeaderRange {
+            network_id: proof.mmr_proof.network_id,
+            start: first.slot,
+            end: last.slot,
+            headers,
+        })
+    }
+
+    /// Verifies the execution payload carried by a beacon block using an SSZ Merkle proof
+    ///
+    /// The payload header fields are merkleized into the payload header root, which is then
+    /// verified against the `body_root` of a previously verified beacon header. This links a
+    /// beacon slot to its execution block without an execution MMR proof.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The payload header field roots and the branch to the block body root
+    /// * `headers` - List of previously verified beacon headers. Must contain the header for
+    ///   the slot referenced in the proof
+    ///
+    /// # Returns
+    ///
+    /// Returns the verified execution block number, block hash, state root and timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `InvalidBeaconHeaderProof`: The referenced header is not in the verified headers list
+    /// - `InvalidSszProof`: The payload fields or branch don't match the header's body root
+    pub fn verify_execution_payload_proof(
+        proof: &ExecutionPayloadProof,
+        headers: &[BeaconHeader],
+    ) -> Result<VerifiedExecutionPayload, VerifyError> {
+        let header = headers
+            .iter()
+            .find(|header| header.slot == proof.slot)
+            .ok_or(VerifyError::InvalidBeaconHeaderProof)?;
+
+        let fields = &proof.payload_fields;
+        if fields.len() <= PAYLOAD_BLOCK_HASH_INDEX
+            || fields.len() as u64 > ExecutionPayloadProof::MAX_PAYLOAD_FIELDS
+            || proof.body_branch.len() != ExecutionPayloadProof::BODY_TREE_DEPTH
+        {
+            return Err(VerifyError::InvalidSszProof);
+        }
+
+        let payload_root = ssz::merkleize(fields, ExecutionPayloadProof::MAX_PAYLOAD_FIELDS);
+        if !ssz::verify_branch(
+            payload_root,
+            &proof.body_branch,
+            ExecutionPayloadProof::BODY_FIELD_INDEX,
+            header.body_root,
+        ) {
+            return Err(VerifyError::InvalidSszProof);
+        }
+
+        let block_number = ssz::leaf_u64(&fields[PAYLOAD_BLOCK_NUMBER_INDEX])
+            .ok_or(VerifyError::InvalidSszProof)?;
+        let timestamp =
+            ssz::leaf_u64(&fields[PAYLOAD_TIMESTAMP_INDEX]).ok_or(VerifyError::InvalidSszProof)?;
+
+        Ok(VerifiedExecutionPayload {
+            block: beacon_block_ref(proof.network_id, header),
+            network_id: proof.network_id,
+            slot: proof.slot,
+            block_number,
+            block_hash: fields[PAYLOAD_BLOCK_HASH_INDEX],
+            state_root: fields[PAYLOAD_STATE_ROOT_INDEX],
+            timestamp,
+        })
+    }
+}
+
+/// Returns the reference of a verified beacon header, without its Bankai anchor.
+pub(crate) fn beacon_block_ref(network_id: u64, header: &BeaconHeader) -> BlockRef {
+    BlockRef {
+        network_id,
+        block_number: header.slot,
+        block_hash: header.tree_hash_root(),
+        state_root: header.state_root,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn payload_fields() -> Vec<FixedBytes<32>> {
+        let mut fields = vec![FixedBytes::from([0xaa; 32]); 17];
+        fields[PAYLOAD_STATE_ROOT_INDEX] = FixedBytes::from([0x22; 32]);
+        fields[PAYLOAD_BLOCK_NUMBER_INDEX] = ssz::u64_leaf(21_000_000);
+        fields[PAYLOAD_TIMESTAMP_INDEX] = ssz::u64_leaf(1_700_000_000);
+        fields[PAYLOAD_BLOCK_HASH_INDEX] = FixedBytes::from([0x12; 32]);
+        fields
+    }
+
+    fn fixture() -> (ExecutionPayloadProof, BeaconHeader) {
+        let payload_fields = payload_fields();
+        let payload_root =
+            ssz::merkleize(&payload_fields, ExecutionPayloadProof::MAX_PAYLOAD_FIELDS);
+        let mut body_fields = vec![FixedBytes::from([0x01; 32]); 12];
+        body_fields[ExecutionPayloadProof::BODY_FIELD_INDEX as usize] = payload_root;
+        let body_root = ssz::merkleize(&body_fields, 16);
+        let body_branch =
+            ssz::generate_branch(&body_fields, 16, ExecutionPayloadProof::BODY_FIELD_INDEX);
+
+        let header = BeaconHeader {
+            slot: 100,
+            proposer_index: 7,
+            parent_root: FixedBytes::ZERO,
+            state_root: FixedBytes::ZERO,
+            body_root,
+        };
+        let proof = ExecutionPayloadProof {
+            network_id: 0,
+            slot: 100,
+            payload_fields,
+            body_branch,
+        };
+        (proof, header)
+    }
+
+    #[test]
+    fn ssz_merkleization_matches_tree_hash() {
+        let (_, header) = fixture();
+        let leaves = [
+            ssz::u64_leaf(header.slot),
+            ssz::u64_leaf(header.proposer_index),
+            header.parent_root,
+            header.state_root,
+            header.body_root,
+        ];
+
+        assert_eq!(ssz::merkleize(&leaves, 8), header.tree_hash_root());
+    }
+
+    #[test]
+    fn verifies_execution_payload_against_body_root() {
+        let (proof, header) = fixture();
+        let header_root = header.tree_hash_root();
+        let payload =
+            BeaconVerifier::verify_execution_payload_proof(&proof, &[header]).expect("valid");
+
+        assert_eq!(payload.slot, 100);
+        assert_eq!(payload.block_number, 21_000_000);
+        assert_eq!(payload.timestamp, 1_700_000_000);
+        assert_eq!(payload.block_hash, FixedBytes::from([0x12; 32]));
+        assert_eq!(payload.state_root, FixedBytes::from([0x22; 32]));
+        assert_eq!(payload.block.block_number, 100);
+        assert_eq!(payload.block.block_hash, header_root);
+    }
+
+    #[test]
+    fn rejects_tampered_payload_field() {
+        let (mut proof, header) = fixture();
+        proof.payload_fields[PAYLOAD_BLOCK_HASH_INDEX] = FixedBytes::from([0x13; 32]);
+
+        assert_eq!(
+            BeaconVerifier::verify_execution_payload_proof(&proof, &[header]).unwrap_err(),
+            VerifyError::InvalidSszProof
+        );
+    }
+
+    #[test]
+    fn rejects_payload_for_unverified_slot() {
+        let (mut proof, header) = fixture();
+        proof.slot = 101;
+
+        assert_eq!(
+            BeaconVerifier::verify_execution_payload_proof(&proof, &[header]).unwrap_err(),
+            VerifyError::InvalidBeaconHeaderProof
+        );
+    }
 }
@@ -0,0 +1,258 @@
+extern crate alloc;
+
+use alloy_primitives::FixedBytes;
+use bankai_core::ssz;
+use bankai_types::inputs::evm::beacon::BeaconValidatorProof;
+use bankai_types::results::evm::beacon::BeaconHeader;
+use bankai_types::results::evm::VerifiedValidator;
+
+use crate::evm::beacon::beacon_block_ref;
+use crate::VerifyError;
+
+/// Verifier for beacon state data proven against verified beacon headers
+///
+/// Beacon state fields are proven with SSZ Merkle branches against the `state_root` of a
+/// previously verified beacon header, so no beacon node has to be trusted.
+pub struct BeaconStateVerifier;
+
+impl BeaconStateVerifier {
+    /// Verifies a validator record and its balance against a beacon state root
+    ///
+    /// The validator record is hashed and proven in the `validators` list, and the balance is
+    /// read from the proven `balances` chunk. Both lists must contain `validator_index`.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The validator record, its balance chunk and both SSZ branches
+    /// * `headers` - List of previously verified beacon headers. Must contain the header for
+    ///   the slot referenced in the proof
+    ///
+    /// # Returns
+    ///
+    /// Returns the verified validator record and its balance in Gwei.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `InvalidBeaconHeaderProof`: The referenced header is not in the verified headers list
+    /// - `InvalidSszProof`: A branch doesn't match the header's state root, or the validator
+    ///   index is beyond the proven list lengths
+    pub fn verify_validator_proof(
+        proof: &BeaconValidatorProof,
+        headers: &[BeaconHeader],
+    ) -> Result<VerifiedValidator, VerifyError> {
+        let header = headers
+            .iter()
+            .find(|header| header.slot == proof.slot)
+            .ok_or(VerifyError::InvalidBeaconHeaderProof)?;
+        let state_depth = proof.fork.state_tree_depth();
+
+        let validators_len = verify_list_leaf(
+            proof.validator.tree_hash_root(),
+            &proof.validator_branch,
+            proof.validator_index,
+            BeaconValidatorProof::VALIDATORS_FIELD_INDEX,
+            BeaconValidatorProof::VALIDATORS_TREE_DEPTH,
+            state_depth,
+            header.state_root,
+        )?;
+        let balances_len = verify_list_leaf(
+            proof.balance_chunk,
+            &proof.balance_branch,
+            proof.validator_index / BeaconValidatorProof::BALANCES_PER_CHUNK,
+            BeaconValidatorProof::BALANCES_FIELD_INDEX,
+            BeaconValidatorProof::BALANCES_TREE_DEPTH,
+            state_depth,
+            header.state_root,
+        )?;
+        if proof.validator_index >= validators_len || proof.validator_index >= balances_len {
+            return Err(VerifyError::InvalidSszProof);
+        }
+
+        let offset =
+            (proof.validator_index % BeaconValidatorProof::BALANCES_PER_CHUNK) as usize * 8;
+        let mut balance = [0u8; 8];
+        balance.copy_from_slice(&proof.balance_chunk[offset..offset + 8]);
+
+        Ok(VerifiedValidator {
+            block: beacon_block_ref(proof.network_id, header),
+            network_id: proof.network_id,
+            slot: proof.slot,
+            validator_index: proof.validator_index,
+            validator: proof.validator.clone(),
+            balance: u64::from_le_bytes(balance),
+        })
+    }
+}
+
+/// Verifies a leaf of a beacon state list field and returns the proven list length.
+///
+/// The branch runs through the list data tree, the mixed-in length and the state field tree.
+fn verify_list_leaf(
+    leaf: FixedBytes<32>,
+    branch: &[FixedBytes<32>],
+    leaf_index: u64,
+    field_index: u64,
+    list_depth: usize,
+    state_depth: usize,
+    state_root: FixedBytes<32>,
+) -> Result<u64, VerifyError> {
+    if branch.len() != list_depth + 1 + state_depth || leaf_index >> list_depth != 0 {
+        return Err(VerifyError::InvalidSszProof);
+    }
+
+    let length = ssz::leaf_u64(&branch[list_depth]).ok_or(VerifyError::InvalidSszProof)?;
+    let index = leaf_index | (field_index << (list_depth + 1));
+    if !ssz::verify_branch(leaf, branch, index, state_root) {
+        return Err(VerifyError::InvalidSszProof);
+    }
+
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::slice;
+
+    use bankai_types::inputs::evm::beacon::BeaconFork;
+    use bankai_types::results::evm::beacon::Validator;
+
+    use super::*;
+
+    const LENGTH: u64 = 6;
+
+    fn validator(index: u64) -> Validator {
+        Validator {
+            pubkey: FixedBytes::from([index as u8; 48]),
+            withdrawal_credentials: FixedBytes::from([0x01; 32]),
+            effective_balance: 32_000_000_000,
+            slashed: false,
+            activation_eligibility_epoch: 1,
+            activation_epoch: 2,
+            exit_epoch: u64::MAX,
+            withdrawable_epoch: u64::MAX,
+        }
+    }
+
+    /// Builds the branch of `leaf_index` in a list field of a state with `state_fields` roots.
+    fn list_branch(
+        leaves: &[FixedBytes<32>],
+        list_depth: usize,
+        length: u64,
+        leaf_index: u64,
+        state_fields: &[FixedBytes<32>],
+        field_index: u64,
+    ) -> Vec<FixedBytes<32>> {
+        let mut branch = ssz::generate_branch(leaves, 1 << list_depth, leaf_index);
+        branch.push(ssz::u64_leaf(length));
+        branch.extend(ssz::generate_branch(state_fields, 32, field_index));
+        branch
+    }
+
+    fn fixture(index: u64, validators_len: u64) -> (BeaconValidatorProof, BeaconHeader) {
+        let validators: Vec<_> = (0..LENGTH).map(validator).collect();
+        let validator_roots: Vec<_> = validators.iter().map(Validator::tree_hash_root).collect();
+        let balances: Vec<u8> = (0..LENGTH)
+            .flat_map(|i| (31_000_000_000 + i).to_le_bytes())
+            .collect();
+        let balance_chunks = ssz::pack(&balances);
+
+        let mut state_fields = vec![FixedBytes::from([0x05; 32]); 28];
+        state_fields[11] = ssz::mix_in_length(
+            ssz::merkleize(
+                &validator_roots,
+                1 << BeaconValidatorProof::VALIDATORS_TREE_DEPTH,
+            ),
+            validators_len,
+        );
+        state_fields[12] = ssz::mix_in_length(
+            ssz::merkleize(
+                &balance_chunks,
+                1 << BeaconValidatorProof::BALANCES_TREE_DEPTH,
+            ),
+            LENGTH,
+        );
+
+        let header = BeaconHeader {
+            slot: 64,
+            proposer_index: 1,
+            parent_root: FixedBytes::ZERO,
+            state_root: ssz::merkleize(&state_fields, 32),
+            body_root: FixedBytes::ZERO,
+        };
+        let chunk_index = index / BeaconValidatorProof::BALANCES_PER_CHUNK;
+        let proof = BeaconValidatorProof {
+            network_id: 0,
+            slot: 64,
+            fork: BeaconFork::Deneb,
+            validator_index: index,
+            validator: validators[index as usize].clone(),
+            validator_branch: list_branch(
+                &validator_roots,
+                BeaconValidatorProof::VALIDATORS_TREE_DEPTH,
+                validators_len,
+                index,
+                &state_fields,
+                11,
+            ),
+            balance_chunk: balance_chunks[chunk_index as usize],
+            balance_branch: list_branch(
+                &balance_chunks,
+                BeaconValidatorProof::BALANCES_TREE_DEPTH,
+                LENGTH,
+                chunk_index,
+                &state_fields,
+                12,
+            ),
+        };
+        (proof, header)
+    }
+
+    #[test]
+    fn verifies_validator_and_balance() {
+        let (proof, header) = fixture(5, LENGTH);
+        let result = BeaconStateVerifier::verify_validator_proof(&proof, slice::from_ref(&header))
+            .expect("valid proof");
+
+        assert_eq!(result.block.block_number, header.slot);
+        assert_eq!(result.block.state_root, header.state_root);
+        assert_eq!(result.validator_index, 5);
+        assert_eq!(result.validator, validator(5));
+        assert_eq!(result.balance, 31_000_000_005);
+        assert!(result.validator.is_active(2));
+    }
+
+    #[test]
+    fn rejects_tampered_validator() {
+        let (mut proof, header) = fixture(2, LENGTH);
+        proof.validator.slashed = true;
+
+        assert_eq!(
+            BeaconStateVerifier::verify_validator_proof(&proof, &[header]).unwrap_err(),
+            VerifyError::InvalidSszProof
+        );
+    }
+
+    #[test]
+    fn rejects_index_beyond_list_length() {
+        let (proof, header) = fixture(5, 5);
+
+        assert_eq!(
+            BeaconStateVerifier::verify_validator_proof(&proof, &[header]).unwrap_err(),
+            VerifyError::InvalidSszProof
+        );
+    }
+
+    #[test]
+    fn rejects_branch_for_other_fork() {
+        let (mut proof, header) = fixture(1, LENGTH);
+        proof.fork = BeaconFork::Electra;
+
+        assert_eq!(
+            BeaconStateVerifier::verify_validator_proof(&proof, &[header]).unwrap_err(),
+            VerifyError::InvalidSszProof
+        );
+    }
+}
@@ -3,13 +3,14 @@ use alloc::vec::Vec;
 
 use alloy_rlp::{Decodable, Encodable};
 use bankai_types::inputs::evm::execution::{
-    AccountProof, ExecutionHeaderProof, ReceiptProof, TxProof,
+    AccountProof, CodeProof, ExecutionHeaderBatchProof, ExecutionHeaderProof, ReceiptProof, TxProof,
 };
 use bankai_types::results::evm::execution::{
     ExecutionHeader, ReceiptEnvelope, TrieAccount, TxEnvelope,
 };
+use bankai_types::results::evm::VerifiedHeaderRange;
 
-use alloy_primitives::{keccak256, FixedBytes};
+use alloy_primitives::{keccak256, Address, Bytes, FixedBytes};
 use alloy_rlp::encode as rlp_encode;
 use alloy_trie::{proof::verify_proof as mpt_verify, Nibbles};
 
@@ -82,6 +83,64 @@ impl ExecutionVerifier {
         Ok(proof.header.clone().into())
     }
 
+    /// Verifies execution headers sharing one multi-leaf MMR proof
+    ///
+    /// Same checks as [`ExecutionVerifier::verify_header_proof`], with the MMR paths and
+    /// peaks verified once for all headers. The headers line up with the proof leaves.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `InvalidMmrRoot`: The MMR root in the proof doesn't match the expected root
+    /// - `InvalidMmrProof`: A leaf of the MMR proof is not committed in the root
+    /// - `InvalidHeaderHash`: A header hash doesn't match its leaf, or the counts differ
+    pub fn verify_header_batch_proof(
+        proof: &ExecutionHeaderBatchProof,
+        root: FixedBytes<32>,
+    ) -> Result<Vec<ExecutionHeader>, VerifyError> {
+        if proof.mmr_proof.root != root {
+            return Err(VerifyError::InvalidMmrRoot);
+        }
+
+        MmrVerifier::verify_mmr_multi_proof(&proof.mmr_proof)?;
+
+        if proof.headers.len() != proof.mmr_proof.leaves.len() {
+            return Err(VerifyError::InvalidHeaderHash);
+        }
+        proof
+            .headers
+            .iter()
+            .zip(&proof.mmr_proof.leaves)
+            .map(|(header, leaf)| {
+                if header.hash_slow() != leaf.header_hash {
+                    return Err(VerifyError::InvalidHeaderHash);
+                }
+                Ok(header.clone().into())
+            })
+            .collect()
+    }
+
+    /// Verifies a contiguous range of execution headers sharing one multi-leaf MMR proof
+    ///
+    /// Runs [`ExecutionVerifier::verify_header_batch_proof`], then checks that the block
+    /// numbers increase by one and that every header's parent hash is the previous header's
+    /// hash.
+    ///
+    /// The range bounds come from the proven headers. Callers must compare `start` and `end`
+    /// with the range they requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`ExecutionVerifier::verify_header_batch_proof`], or
+    /// `BrokenHeaderChain` if the headers are not a linked chain.
+    pub fn verify_header_range_proof(
+        proof: &ExecutionHeaderBatchProof,
+        root: FixedBytes<32>,
+    ) -> Result<VerifiedHeaderRange<ExecutionHeader>, VerifyError> {
+        let headers = Self::verify_header_batch_proof(proof, root)?;
+        header_range(proof.mmr_proof.network_id, headers)
+    }
+
     /// Verifies an account's state using a Merkle Patricia Trie proof
     ///
     /// This method verifies an account's state (balance, nonce, code hash, storage root)
@@ -130,24 +189,122 @@ impl ExecutionVerifier {
         account_proof: &AccountProof,
         headers: &[ExecutionHeader],
     ) -> Result<TrieAccount, VerifyError> {
+        Self::verify_account_existence(account_proof, headers)?
+            .ok_or(VerifyError::InvalidAccountProof)
+    }
+
+    /// Verifies an account proof that may prove the address is absent from the state trie
+    ///
+    /// Works like [`Self::verify_account_proof`], but also accepts exclusion proofs. An exclusion
+    /// proof is only accepted when the proof carries the empty account
+    /// ([`AccountProof::empty_account`]).
+    ///
+    /// # Returns
+    ///
+    /// - `Some(account)` if the account is included in the state trie
+    /// - `None` if the address verifiably does not exist (never used or self-destructed)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `InvalidExecutionHeaderProof`: The referenced header is not in the verified headers list
+    /// - `InvalidStateRoot`: The state root in the proof doesn't match the header's state root
+    /// - `InvalidAccountProof`: The proof neither proves inclusion nor exclusion
+    pub fn verify_account_existence(
+        account_proof: &AccountProof,
+        headers: &[ExecutionHeader],
+    ) -> Result<Option<TrieAccount>, VerifyError> {
         let header = Self::header_for_block(headers, account_proof.block_number)?;
 
         if header.state_root != account_proof.state_root {
             return Err(VerifyError::InvalidStateRoot);
         }
 
-        let expected_value = rlp_encode(account_proof.account).to_vec();
-        let key = Nibbles::unpack(keccak256(account_proof.address));
+        let exists = Self::verify_account_in_state(
+            header.state_root,
+            account_proof.address,
+            account_proof.account,
+            &account_proof.mpt_proof,
+        )?;
 
-        mpt_verify(
+        Ok(exists.then_some(account_proof.account))
+    }
+
+    /// Verifies contract bytecode against the account's code hash
+    ///
+    /// The account is first verified against the header's state root, then `keccak256(code)`
+    /// must equal the account's `code_hash`. Addresses without code verify with empty `code`,
+    /// including addresses absent from the state trie.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The code proof containing the bytecode, account state and account MPT proof
+    /// * `headers` - List of previously verified execution headers. Must contain the header
+    ///   for the block number referenced in the code proof
+    ///
+    /// # Returns
+    ///
+    /// Returns the verified bytecode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `InvalidExecutionHeaderProof`: The referenced header is not in the verified headers list
+    /// - `InvalidStateRoot`: The state root in the proof doesn't match the header's state root
+    /// - `InvalidAccountProof`: The account MPT proof verification failed
+    /// - `InvalidCodeHash`: The bytecode doesn't hash to the account's code hash
+    pub fn verify_code_proof(
+        proof: &CodeProof,
+        headers: &[ExecutionHeader],
+    ) -> Result<Bytes, VerifyError> {
+        let header = Self::header_for_block(headers, proof.block_number)?;
+
+        if header.state_root != proof.state_root {
+            return Err(VerifyError::InvalidStateRoot);
+        }
+
+        Self::verify_account_in_state(
             header.state_root,
-            key,
-            Some(expected_value),
-            account_proof.mpt_proof.iter(),
+            proof.address,
+            proof.account,
+            &proof.account_mpt_proof,
+        )?;
+
+        if keccak256(&proof.code) != proof.account.code_hash {
+            return Err(VerifyError::InvalidCodeHash);
+        }
+
+        Ok(proof.code.clone())
+    }
+
+    /// Internal helper returning whether `account` is included in, or the empty account is
+    /// excluded from, the state trie
+    fn verify_account_in_state(
+        state_root: FixedBytes<32>,
+        address: Address,
+        account: TrieAccount,
+        mpt_proof: &[Bytes],
+    ) -> Result<bool, VerifyError> {
+        let key = Nibbles::unpack(keccak256(address));
+
+        if mpt_verify(
+            state_root,
+            key.clone(),
+            Some(rlp_encode(account).to_vec()),
+            mpt_proof.iter(),
         )
-        .map_err(|_| VerifyError::InvalidAccountProof)?;
+        .is_ok()
+        {
+            return Ok(true);
+        }
+
+        if account == AccountProof::empty_account()
+            && mpt_verify(state_root, key, None, mpt_proof.iter()).is_ok()
+        {
+            return Ok(false);
+        }
 
-        Ok(account_proof.account)
+        Err(VerifyError::InvalidAccountProof)
     }
 
     /// Verifies one or more storage slots from the same contract using Merkle Patricia Trie proofs.
@@ -284,6 +441,7 @@ impl ExecutionVerifier {
     /// - `InvalidExecutionHeaderProof`: The referenced header is not in the verified headers list
     /// - `InvalidTxProof`: The MPT proof verification failed
     /// - `InvalidRlpDecode`: The transaction data could not be decoded
+    /// - `InvalidTxHash`: The claimed transaction hash doesn't match the decoded transaction
     ///
     /// # Example
     ///
@@ -305,23 +463,16 @@ impl ExecutionVerifier {
         proof: &TxProof,
         headers: &[ExecutionHeader],
     ) -> Result<TxEnvelope, VerifyError> {
-        let header = Self::header_for_block(headers, proof.block_number)?;
-
-        let mut rlp_tx_index = Vec::new();
-        proof.tx_index.encode(&mut rlp_tx_index);
-        let key = Nibbles::unpack(&rlp_tx_index);
-
-        mpt_verify(
-            header.transactions_root,
-            key,
-            Some(proof.encoded_tx.clone()),
-            proof.proof.iter(),
-        )
-        .map_err(|_| VerifyError::InvalidTxProof)?;
+        Self::verify_tx_inclusion(proof, headers)?;
 
         let tx = TxEnvelope::decode(&mut proof.encoded_tx.as_slice())
             .map_err(|_| VerifyError::InvalidRlpDecode)?;
 
+        // The claimed hash is unverified input, bind it to the decoded payload
+        if *tx.tx_hash() != proof.tx_hash {
+            return Err(VerifyError::InvalidTxHash);
+        }
+
         Ok(tx)
     }
 
@@ -329,24 +480,44 @@ impl ExecutionVerifier {
         proof: &ReceiptProof,
         headers: &[ExecutionHeader],
     ) -> Result<ReceiptEnvelope, VerifyError> {
+        Self::verify_receipt_inclusion(proof, headers)?;
+
+        let receipt = ReceiptEnvelope::decode(&mut proof.encoded_receipt.as_slice())
+            .map_err(|_| VerifyError::InvalidRlpDecode)?;
+
+        Ok(receipt)
+    }
+
+    /// Verifies the encoded transaction against the header's transactions root without decoding it.
+    pub(crate) fn verify_tx_inclusion(
+        proof: &TxProof,
+        headers: &[ExecutionHeader],
+    ) -> Result<(), VerifyError> {
         let header = Self::header_for_block(headers, proof.block_number)?;
 
-        let mut rlp_tx_index = Vec::new();
-        proof.tx_index.encode(&mut rlp_tx_index);
-        let key = Nibbles::unpack(&rlp_tx_index);
+        mpt_verify(
+            header.transactions_root,
+            index_key(proof.tx_index),
+            Some(proof.encoded_tx.clone()),
+            proof.proof.iter(),
+        )
+        .map_err(|_| VerifyError::InvalidTxProof)
+    }
+
+    /// Verifies the encoded receipt against the header's receipts root without decoding it.
+    pub(crate) fn verify_receipt_inclusion(
+        proof: &ReceiptProof,
+        headers: &[ExecutionHeader],
+    ) -> Result<(), VerifyError> {
+        let header = Self::header_for_block(headers, proof.block_number)?;
 
         mpt_verify(
             header.receipts_root,
-            key,
+            index_key(proof.tx_index),
             Some(proof.encoded_receipt.clone()),
             proof.proof.iter(),
         )
-        .map_err(|_| VerifyError::InvalidReceiptProof)?;
-
-        let receipt = ReceiptEnvelope::decode(&mut proof.encoded_receipt.as_slice())
-            .map_err(|_| VerifyError::InvalidRlpDecode)?;
-
-        Ok(receipt)
+        .map_err(|_| VerifyError::InvalidReceiptProof)
     }
 
     fn header_for_block(
@@ -360,16 +531,220 @@ impl ExecutionVerifier {
     }
 }
 
+/// Trie key for the transaction and receipt tries: `rlp(tx_index)`.
+fn index_key(tx_index: u64) -> Nibbles {
+    let mut rlp_tx_index = Vec::new();
+    tx_index.encode(&mut rlp_tx_index);
+    Nibbles::unpack(&rlp_tx_index)
+}
+
+/// Checks that verified headers form a chain linked by parent hash, in block order.
+pub(crate) fn header_range(
+    network_id: u64,
+    headers: Vec<ExecutionHeader>,
+) -> Result<VerifiedHeaderRange<ExecutionHeader>, VerifyError> {
+    let (Some(first), Some(last)) = (headers.first(), headers.last()) else {
+        return Err(VerifyError::BrokenHeaderChain);
+    };
+    let linked = headers.windows(2).all(|pair| {
+        pair[0].number.checked_add(1) == Some(pair[1].number)
+            && pair[1].parent_hash == pair[0].hash_slow()
+    });
+    if !linked {
+        return Err(VerifyError::BrokenHeaderChain);
+    }
+    Ok(VerifiedHeaderRange {
+        network_id,
+        start: first.number,
+        end: last.number,
+        headers,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_consensus::{
         proofs::calculate_receipt_root, Receipt, ReceiptEnvelope, ReceiptWithBloom,
     };
-    use alloy_primitives::{Bloom, FixedBytes};
+    use alloy_primitives::{Address, Bloom, Bytes, FixedBytes, U256};
+    use alloy_trie::{proof::ProofRetainer, HashBuilder, EMPTY_ROOT_HASH};
     use mpt_generate::build_receipt_proof_from_items;
 
     use super::*;
 
+    /// Builds a state trie containing only `present` and a proof for the `target` key.
+    fn state_proof_for(present: Address, target: Address) -> (FixedBytes<32>, Vec<Bytes>) {
+        let account = TrieAccount {
+            nonce: 1,
+            balance: U256::from(5u64),
+            storage_root: EMPTY_ROOT_HASH,
+            code_hash: keccak256([]),
+        };
+        state_proof_with_account(present, account, target)
+    }
+
+    fn state_proof_with_account(
+        present: Address,
+        account: TrieAccount,
+        target: Address,
+    ) -> (FixedBytes<32>, Vec<Bytes>) {
+        let retainer = ProofRetainer::from_iter([Nibbles::unpack(keccak256(target))]);
+        let mut hash_builder = HashBuilder::default().with_proof_retainer(retainer);
+        hash_builder.add_leaf(Nibbles::unpack(keccak256(present)), &rlp_encode(account));
+        let state_root = hash_builder.root();
+        let proof = hash_builder
+            .take_proof_nodes()
+            .into_nodes_sorted()
+            .into_iter()
+            .map(|(_, node)| node)
+            .collect();
+        (state_root, proof)
+    }
+
+    fn absent_account_proof(account: TrieAccount) -> (AccountProof, ExecutionHeader) {
+        let target = Address::repeat_byte(0x22);
+        let (state_root, mpt_proof) = state_proof_for(Address::repeat_byte(0x11), target);
+        let proof = AccountProof {
+            account,
+            address: target,
+            network_id: 1,
+            block_number: 7,
+            state_root,
+            mpt_proof,
+        };
+        let header = ExecutionHeader {
+            number: 7,
+            state_root,
+            ..Default::default()
+        };
+        (proof, header)
+    }
+
+    #[test]
+    fn verifies_account_exclusion_proof() {
+        let (proof, header) = absent_account_proof(AccountProof::empty_account());
+
+        assert_eq!(
+            ExecutionVerifier::verify_account_existence(&proof, &[header.clone()]),
+            Ok(None)
+        );
+        assert_eq!(
+            ExecutionVerifier::verify_account_proof(&proof, &[header]),
+            Err(VerifyError::InvalidAccountProof)
+        );
+    }
+
+    #[test]
+    fn verifies_included_account_with_existence_check() {
+        let address = Address::repeat_byte(0x11);
+        let (state_root, mpt_proof) = state_proof_for(address, address);
+        let account = TrieAccount {
+            nonce: 1,
+            balance: U256::from(5u64),
+            storage_root: EMPTY_ROOT_HASH,
+            code_hash: keccak256([]),
+        };
+        let proof = AccountProof {
+            account,
+            address,
+            network_id: 1,
+            block_number: 7,
+            state_root,
+            mpt_proof,
+        };
+        let header = ExecutionHeader {
+            number: 7,
+            state_root,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            ExecutionVerifier::verify_account_existence(&proof, &[header]),
+            Ok(Some(account))
+        );
+    }
+
+    #[test]
+    fn rejects_exclusion_proof_claiming_non_empty_account() {
+        let (proof, header) = absent_account_proof(TrieAccount {
+            nonce: 3,
+            ..AccountProof::empty_account()
+        });
+
+        assert_eq!(
+            ExecutionVerifier::verify_account_existence(&proof, &[header]),
+            Err(VerifyError::InvalidAccountProof)
+        );
+    }
+
+    fn contract_code_proof(code: Bytes) -> (CodeProof, ExecutionHeader) {
+        let address = Address::repeat_byte(0x33);
+        let account = TrieAccount {
+            nonce: 1,
+            balance: U256::ZERO,
+            storage_root: EMPTY_ROOT_HASH,
+            code_hash: keccak256(&code),
+        };
+        let (state_root, account_mpt_proof) = state_proof_with_account(address, account, address);
+        let proof = CodeProof {
+            account,
+            address,
+            network_id: 1,
+            block_number: 7,
+            state_root,
+            account_mpt_proof,
+            code,
+        };
+        let header = ExecutionHeader {
+            number: 7,
+            state_root,
+            ..Default::default()
+        };
+        (proof, header)
+    }
+
+    #[test]
+    fn verifies_contract_code_against_code_hash() {
+        let code = Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xf3]);
+        let (proof, header) = contract_code_proof(code.clone());
+
+        assert_eq!(
+            ExecutionVerifier::verify_code_proof(&proof, &[header]),
+            Ok(code)
+        );
+    }
+
+    #[test]
+    fn rejects_code_not_matching_code_hash() {
+        let (mut proof, header) =
+            contract_code_proof(Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xf3]));
+        proof.code = Bytes::from_static(&[0x00]);
+
+        assert_eq!(
+            ExecutionVerifier::verify_code_proof(&proof, &[header]),
+            Err(VerifyError::InvalidCodeHash)
+        );
+    }
+
+    #[test]
+    fn verifies_empty_code_for_absent_account() {
+        let (account_proof, header) = absent_account_proof(AccountProof::empty_account());
+        let proof = CodeProof {
+            account: account_proof.account,
+            address: account_proof.address,
+            network_id: account_proof.network_id,
+            block_number: account_proof.block_number,
+            state_root: account_proof.state_root,
+            account_mpt_proof: account_proof.mpt_proof,
+            code: Bytes::new(),
+        };
+
+        assert_eq!(
+            ExecutionVerifier::verify_code_proof(&proof, &[header]),
+            Ok(Bytes::new())
+        );
+    }
+
     #[test]
     fn verifies_receipt_proof_against_receipts_root() {
         let receipt = ReceiptEnvelope::Eip1559(ReceiptWithBloom {
@@ -415,4 +790,38 @@ mod tests {
             other => panic!("expected EIP-1559 receipt, got {other:?}"),
         }
     }
+
+    #[test]
+    fn header_range_requires_linked_headers() {
+        let mut headers: Vec<ExecutionHeader> = Vec::new();
+        for number in 10..13 {
+            let parent_hash = headers.last().map_or(FixedBytes::ZERO, |h| h.hash_slow());
+            headers.push(ExecutionHeader {
+                number,
+                parent_hash,
+                ..Default::default()
+            });
+        }
+
+        let range = header_range(1, headers.clone()).unwrap();
+        assert_eq!((range.start, range.end), (10, 12));
+        assert_eq!(range.headers.len(), 3);
+
+        let mut gap = headers.clone();
+        gap.remove(1);
+        assert_eq!(
+            header_range(1, gap).err(),
+            Some(VerifyError::BrokenHeaderChain)
+        );
+
+        headers[2].parent_hash = FixedBytes::ZERO;
+        assert_eq!(
+            header_range(1, headers).err(),
+            Some(VerifyError::BrokenHeaderChain)
+        );
+        assert_eq!(
+            header_range(1, Vec::new()).err(),
+            Some(VerifyError::BrokenHeaderChain)
+        );
+    }
 }
@@ -1,3 +1,4 @@
 pub mod beacon;
+pub mod beacon_state;
 pub mod execution;
 pub mod op_stack;
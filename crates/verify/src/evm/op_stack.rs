@@ -1,16 +1,22 @@
 use alloc::vec::Vec;
 
-use alloy_primitives::{FixedBytes, U256};
+use alloy_primitives::{Bytes, FixedBytes, U256};
+use alloy_rlp::Decodable;
 use bankai_core::merkle::op_stack;
+use bankai_types::block::OpChainClient;
 use bankai_types::common::HashingFunction;
-use bankai_types::inputs::evm::execution::{AccountProof, ReceiptProof, StorageSlotProof, TxProof};
-use bankai_types::inputs::evm::op_stack::{OpStackHeaderProof, OpStackMerkleProof};
-use bankai_types::results::evm::execution::{
-    ExecutionHeader, ReceiptEnvelope, TrieAccount, TxEnvelope,
+use bankai_types::inputs::evm::execution::{
+    AccountProof, CodeProof, ReceiptProof, StorageSlotProof, TxProof,
 };
+use bankai_types::inputs::evm::op_stack::{
+    OpStackHeaderBatchProof, OpStackHeaderProof, OpStackMerkleProof,
+};
+use bankai_types::results::evm::execution::{ExecutionHeader, TrieAccount};
+use bankai_types::results::evm::op_stack::{OpReceiptEnvelope, OpTxEnvelope};
+use bankai_types::results::evm::VerifiedHeaderRange;
 
 use crate::bankai::mmr::MmrVerifier;
-use crate::evm::execution::ExecutionVerifier;
+use crate::evm::execution::{header_range, ExecutionVerifier};
 use crate::VerifyError;
 
 pub struct OpStackVerifier;
@@ -41,19 +47,12 @@ impl OpStackVerifier {
         op_chains_root: FixedBytes<32>,
         hashing_function: HashingFunction,
     ) -> Result<ExecutionHeader, VerifyError> {
-        let computed_leaf = proof.snapshot.commitment_leaf_hash();
-        if computed_leaf != proof.merkle_proof.leaf_hash {
-            return Err(VerifyError::InvalidMerkleProof);
-        }
-
-        // verify the snapshopt via merkle proof
-        Self::verify_merkle_proof(&proof.merkle_proof, op_chains_root)?;
-
-        // select the correct mmr root based on the hashing function
-        let mmr_root = match hashing_function {
-            HashingFunction::Keccak => proof.snapshot.mmr_root_keccak,
-            HashingFunction::Poseidon => proof.snapshot.mmr_root_poseidon,
-        };
+        let mmr_root = Self::verify_snapshot(
+            &proof.snapshot,
+            &proof.merkle_proof,
+            op_chains_root,
+            hashing_function,
+        )?;
 
         // ensure the mmr proof, uses the correct root
         if proof.mmr_proof.root != mmr_root {
@@ -72,6 +71,81 @@ impl OpStackVerifier {
         Ok(proof.header.clone().into())
     }
 
+    /// Verifies headers of one chain sharing a snapshot and a multi-leaf MMR proof.
+    ///
+    /// The headers line up with the proof leaves.
+    pub fn verify_header_batch_proof(
+        proof: &OpStackHeaderBatchProof,
+        op_chains_root: FixedBytes<32>,
+        hashing_function: HashingFunction,
+    ) -> Result<Vec<ExecutionHeader>, VerifyError> {
+        let mmr_root = Self::verify_snapshot(
+            &proof.snapshot,
+            &proof.merkle_proof,
+            op_chains_root,
+            hashing_function,
+        )?;
+
+        if proof.mmr_proof.root != mmr_root {
+            return Err(VerifyError::InvalidMmrRoot);
+        }
+
+        MmrVerifier::verify_mmr_multi_proof(&proof.mmr_proof)?;
+
+        if proof.headers.len() != proof.mmr_proof.leaves.len() {
+            return Err(VerifyError::InvalidHeaderHash);
+        }
+        proof
+            .headers
+            .iter()
+            .zip(&proof.mmr_proof.leaves)
+            .map(|(header, leaf)| {
+                if header.hash_slow() != leaf.header_hash {
+                    return Err(VerifyError::InvalidHeaderHash);
+                }
+                Ok(header.clone().into())
+            })
+            .collect()
+    }
+
+    /// Verifies a contiguous range of headers of one chain sharing a snapshot and a
+    /// multi-leaf MMR proof.
+    ///
+    /// The headers must increase by one block and link to the previous header by parent hash.
+    /// The range bounds come from the proven headers, so callers must compare `start` and
+    /// `end` with the range they requested.
+    pub fn verify_header_range_proof(
+        proof: &OpStackHeaderBatchProof,
+        op_chains_root: FixedBytes<32>,
+        hashing_function: HashingFunction,
+    ) -> Result<VerifiedHeaderRange<ExecutionHeader>, VerifyError> {
+        let headers = Self::verify_header_batch_proof(proof, op_chains_root, hashing_function)?;
+        header_range(proof.snapshot.chain_id, headers)
+    }
+
+    /// Verifies a chain snapshot against the OP chains root and returns its MMR root for
+    /// `hashing_function`.
+    fn verify_snapshot(
+        snapshot: &OpChainClient,
+        merkle_proof: &OpStackMerkleProof,
+        op_chains_root: FixedBytes<32>,
+        hashing_function: HashingFunction,
+    ) -> Result<FixedBytes<32>, VerifyError> {
+        let computed_leaf = snapshot.commitment_leaf_hash();
+        if computed_leaf != merkle_proof.leaf_hash {
+            return Err(VerifyError::InvalidMerkleProof);
+        }
+
+        // verify the snapshopt via merkle proof
+        Self::verify_merkle_proof(merkle_proof, op_chains_root)?;
+
+        // select the correct mmr root based on the hashing function
+        Ok(match hashing_function {
+            HashingFunction::Keccak => snapshot.mmr_root_keccak,
+            HashingFunction::Poseidon => snapshot.mmr_root_poseidon,
+        })
+    }
+
     pub fn verify_account_proof(
         proof: &AccountProof,
         headers: &[ExecutionHeader],
@@ -79,6 +153,13 @@ impl OpStackVerifier {
         ExecutionVerifier::verify_account_proof(proof, headers)
     }
 
+    pub fn verify_account_existence(
+        proof: &AccountProof,
+        headers: &[ExecutionHeader],
+    ) -> Result<Option<TrieAccount>, VerifyError> {
+        ExecutionVerifier::verify_account_existence(proof, headers)
+    }
+
     pub fn verify_storage_slot_proof(
         proof: &StorageSlotProof,
         headers: &[ExecutionHeader],
@@ -86,18 +167,52 @@ impl OpStackVerifier {
         ExecutionVerifier::verify_storage_slot_proof(proof, headers)
     }
 
+    pub fn verify_code_proof(
+        proof: &CodeProof,
+        headers: &[ExecutionHeader],
+    ) -> Result<Bytes, VerifyError> {
+        ExecutionVerifier::verify_code_proof(proof, headers)
+    }
+
+    /// Verifies an OP Stack transaction, including deposit transactions (type `0x7e`).
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidExecutionHeaderProof` - The referenced header is not in the verified headers list
+    /// - `InvalidTxProof` - The MPT proof verification failed
+    /// - `InvalidRlpDecode` - The transaction is not a valid OP Stack transaction
+    /// - `InvalidTxHash` - The claimed transaction hash doesn't match the decoded transaction
     pub fn verify_tx_proof(
         proof: &TxProof,
         headers: &[ExecutionHeader],
-    ) -> Result<TxEnvelope, VerifyError> {
-        ExecutionVerifier::verify_tx_proof(proof, headers)
+    ) -> Result<OpTxEnvelope, VerifyError> {
+        ExecutionVerifier::verify_tx_inclusion(proof, headers)?;
+
+        let tx = OpTxEnvelope::decode(&mut proof.encoded_tx.as_slice())
+            .map_err(|_| VerifyError::InvalidRlpDecode)?;
+
+        if tx.tx_hash() != proof.tx_hash {
+            return Err(VerifyError::InvalidTxHash);
+        }
+
+        Ok(tx)
     }
 
+    /// Verifies an OP Stack receipt, including deposit receipts.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidExecutionHeaderProof` - The referenced header is not in the verified headers list
+    /// - `InvalidReceiptProof` - The MPT proof verification failed
+    /// - `InvalidRlpDecode` - The receipt is not a valid OP Stack receipt
     pub fn verify_receipt_proof(
         proof: &ReceiptProof,
         headers: &[ExecutionHeader],
-    ) -> Result<ReceiptEnvelope, VerifyError> {
-        ExecutionVerifier::verify_receipt_proof(proof, headers)
+    ) -> Result<OpReceiptEnvelope, VerifyError> {
+        ExecutionVerifier::verify_receipt_inclusion(proof, headers)?;
+
+        OpReceiptEnvelope::decode(&mut proof.encoded_receipt.as_slice())
+            .map_err(|_| VerifyError::InvalidRlpDecode)
     }
 }
 
@@ -105,7 +220,7 @@ impl OpStackVerifier {
 mod tests {
     use alloy_consensus::{
         proofs::{calculate_receipt_root, calculate_transaction_root},
-        Receipt, ReceiptEnvelope, ReceiptWithBloom, Signed, TxEnvelope, TxLegacy,
+        Receipt, ReceiptEnvelope, ReceiptWithBloom, SignableTransaction, TxLegacy,
     };
     use alloy_primitives::Sealable;
     use alloy_primitives::{keccak256, Address, Bloom, Bytes, Signature, TxKind, B256, U256};
@@ -117,10 +232,13 @@ mod tests {
     use bankai_types::inputs::evm::execution::{
         AccountProof, ReceiptProof, StorageSlotEntry, StorageSlotProof, TxProof,
     };
-    use bankai_types::inputs::evm::op_stack::{OpStackHeaderProof, OpStackMerkleProof};
-    use bankai_types::inputs::evm::MmrProof;
+    use bankai_types::inputs::evm::op_stack::{
+        OpStackHeaderBatchProof, OpStackHeaderProof, OpStackMerkleProof,
+    };
+    use bankai_types::inputs::evm::{MmrMultiProof, MmrProof};
     use bankai_types::utils::mmr::hash_to_leaf;
     use mpt_generate::{build_receipt_proof_from_items, build_tx_proof_from_items};
+    use op_alloy_consensus::{OpTxType, TxDeposit};
 
     use super::*;
 
@@ -196,6 +314,54 @@ mod tests {
         assert_eq!(verified.number, snapshot.block_number);
     }
 
+    #[test]
+    fn verifies_header_batch_proof() {
+        let mut snapshot = snapshot();
+        let header: alloy_rpc_types_eth::Header<alloy_consensus::Header> =
+            alloy_rpc_types_eth::Header::from_consensus(
+                alloy_consensus::Header::default().seal_slow(),
+                None,
+                None,
+            );
+        let mmr_proof = single_leaf_mmr_proof(header.hash_slow(), HashingFunction::Poseidon);
+        snapshot.mmr_root_poseidon = mmr_proof.root;
+        let leaf_hash = snapshot.commitment_leaf_hash();
+        let mut proof = OpStackHeaderBatchProof {
+            headers: vec![header],
+            snapshot: snapshot.clone(),
+            merkle_proof: OpStackMerkleProof {
+                chain_id: snapshot.chain_id,
+                merkle_leaf_index: 0,
+                leaf_hash,
+                root: leaf_hash,
+                path: vec![],
+            },
+            mmr_proof: MmrMultiProof::from_proofs(&[mmr_proof]).unwrap(),
+        };
+
+        let verified = OpStackVerifier::verify_header_batch_proof(
+            &proof,
+            leaf_hash,
+            HashingFunction::Poseidon,
+        )
+        .unwrap();
+        assert_eq!(verified.len(), 1);
+        assert_eq!(
+            OpStackVerifier::verify_header_batch_proof(&proof, leaf_hash, HashingFunction::Keccak),
+            Err(VerifyError::InvalidMmrRoot)
+        );
+
+        proof.headers[0].inner.number = 1;
+        assert_eq!(
+            OpStackVerifier::verify_header_batch_proof(
+                &proof,
+                leaf_hash,
+                HashingFunction::Poseidon
+            ),
+            Err(VerifyError::InvalidHeaderHash)
+        );
+    }
+
     #[test]
     fn rejects_wrong_merkle_root() {
         let snapshot = snapshot();
@@ -293,7 +459,7 @@ mod tests {
     fn verifies_tx_proof_against_transactions_root() {
         let block_number = 9;
         let signature = Signature::new(U256::from(1u64), U256::from(2u64), false);
-        let tx = TxEnvelope::Legacy(Signed::new_unchecked(
+        let tx = OpTxEnvelope::Legacy(
             TxLegacy {
                 chain_id: Some(84532),
                 nonce: 3,
@@ -302,17 +468,130 @@ mod tests {
                 to: TxKind::Call(Address::repeat_byte(0x22)),
                 value: U256::from(99u64),
                 input: Bytes::new(),
-            },
-            signature,
-            B256::with_last_byte(0x44),
-        ));
+            }
+            .into_signed(signature),
+        );
+        let tx_root = calculate_transaction_root(&[tx.clone()]);
+        let built =
+            build_tx_proof_from_items(84532, block_number, tx.tx_hash(), 0, &[tx.clone()], tx_root)
+                .unwrap();
+        let proof = TxProof {
+            network_id: built.network_id,
+            block_number: built.block_number,
+            tx_hash: built.tx_hash,
+            tx_index: built.tx_index,
+            proof: built.proof,
+            encoded_tx: built.encoded_tx,
+        };
+        let header = ExecutionHeader {
+            number: block_number,
+            transactions_root: tx_root,
+            ..Default::default()
+        };
+
+        let verified = OpStackVerifier::verify_tx_proof(&proof, &[header]).unwrap();
+
+        assert_eq!(verified.tx_type(), tx.tx_type());
+        assert!(matches!(verified, OpTxEnvelope::Legacy(_)));
+    }
+
+    #[test]
+    fn verifies_deposit_tx_proof() {
+        let block_number = 12;
+        let tx = OpTxEnvelope::from(TxDeposit {
+            source_hash: B256::repeat_byte(0xbb),
+            from: Address::repeat_byte(0x33),
+            to: TxKind::Call(Address::repeat_byte(0x44)),
+            mint: 5,
+            value: U256::from(7_u64),
+            gas_limit: 50_000,
+            is_system_transaction: false,
+            input: Bytes::new(),
+        });
+        let tx_root = calculate_transaction_root(&[tx.clone()]);
+        let built =
+            build_tx_proof_from_items(10, block_number, tx.tx_hash(), 0, &[tx.clone()], tx_root)
+                .unwrap();
+        let proof = TxProof {
+            network_id: built.network_id,
+            block_number: built.block_number,
+            tx_hash: built.tx_hash,
+            tx_index: built.tx_index,
+            proof: built.proof,
+            encoded_tx: built.encoded_tx,
+        };
+        let header = ExecutionHeader {
+            number: block_number,
+            transactions_root: tx_root,
+            ..Default::default()
+        };
+
+        let verified = OpStackVerifier::verify_tx_proof(&proof, &[header]).unwrap();
+
+        match verified {
+            OpTxEnvelope::Deposit(deposit) => {
+                assert_eq!(deposit.source_hash, B256::repeat_byte(0xbb));
+                assert_eq!(deposit.mint, 5);
+                assert!(!deposit.is_system_transaction);
+            }
+            other => panic!("expected deposit transaction, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn verifies_deposit_receipt_proof() {
+        let block_number = 12;
+        let receipt = OpReceiptEnvelope::from_parts(
+            true,
+            21_000,
+            [].iter(),
+            OpTxType::Deposit,
+            Some(1),
+            Some(2),
+        );
+        let receipts_root = calculate_receipt_root(&[receipt.clone()]);
+        let built = build_receipt_proof_from_items(
+            10,
+            block_number,
+            FixedBytes::ZERO,
+            0,
+            &[receipt],
+            receipts_root,
+        )
+        .unwrap();
+        let proof = ReceiptProof {
+            network_id: built.network_id,
+            block_number: built.block_number,
+            tx_hash: built.tx_hash,
+            tx_index: built.tx_index,
+            proof: built.proof,
+            encoded_receipt: built.encoded_receipt,
+        };
+        let header = ExecutionHeader {
+            number: block_number,
+            receipts_root,
+            ..Default::default()
+        };
+
+        let verified = OpStackVerifier::verify_receipt_proof(&proof, &[header]).unwrap();
+
+        assert_eq!(verified.tx_type(), OpTxType::Deposit);
+        assert_eq!(verified.deposit_nonce(), Some(1));
+        assert_eq!(verified.deposit_receipt_version(), Some(2));
+    }
+
+    #[test]
+    fn rejects_tx_proof_with_mismatched_tx_hash() {
+        let block_number = 9;
+        let signature = Signature::new(U256::from(1u64), U256::from(2u64), false);
+        let tx = OpTxEnvelope::Legacy(TxLegacy::default().into_signed(signature));
         let tx_root = calculate_transaction_root(&[tx.clone()]);
         let built = build_tx_proof_from_items(
             84532,
             block_number,
-            *tx.tx_hash(),
+            FixedBytes::from([0x44; 32]),
             0,
-            &[tx.clone()],
+            &[tx],
             tx_root,
         )
         .unwrap();
@@ -330,10 +609,10 @@ mod tests {
             ..Default::default()
         };
 
-        let verified = OpStackVerifier::verify_tx_proof(&proof, &[header]).unwrap();
-
-        assert_eq!(verified.tx_type(), tx.tx_type());
-        assert_eq!(verified.is_legacy(), tx.is_legacy());
+        assert_eq!(
+            OpStackVerifier::verify_tx_proof(&proof, &[header]).unwrap_err(),
+            VerifyError::InvalidTxHash
+        );
     }
 
     #[test]
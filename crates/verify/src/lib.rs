@@ -1,14 +1,27 @@
 //! Bankai verification for proof bundles fetched by `bankai-sdk`.
 //!
 //! In the normal flow, you fetch a [`bankai_types::inputs::ProofBundle`] with `bankai-sdk`,
-//! then call [`verify_batch_proof`] to get verified results.
+//! then call [`verify_batch_proof`] with a [`VerifierConfig`] to get verified results.
+//! The config pins which Bankai programs and versions you trust.
+//!
+//! [`verify_batch_proof`] stops at the first invalid proof and skips the rest of the bundle.
+//! Use [`verify_batch_report`] to verify every proof independently and get a [`BatchReport`]
+//! with one result per proof.
+//!
+//! Enable the `parallel` feature (requires `std`) to verify the proofs of large bundles on
+//! all cores. It parallelises the proofs within each kind, such as all storage proofs, while
+//! the kinds run in sequence. Enable the `sol-types` feature to decode verified logs and
+//! storage slots into `sol!` types, for example with `results.decode_events::<Transfer>()`.
 //!
 //! ```no_run
+//! use alloy_primitives::FixedBytes;
+//! use bankai_types::common::Network;
 //! use bankai_types::inputs::ProofBundle;
-//! use bankai_verify::verify_batch_proof;
+//! use bankai_verify::{verify_batch_proof, VerifierConfig};
 //!
-//! # fn example(proof_bundle: ProofBundle) -> Result<(), Box<dyn std::error::Error>> {
-//! let results = verify_batch_proof(proof_bundle)?;
+//! # fn example(proof_bundle: ProofBundle, program_hash: FixedBytes<32>) -> Result<(), Box<dyn std::error::Error>> {
+//! let config = VerifierConfig::new(Network::Sepolia).with_program_hash(program_hash);
+//! let results = verify_batch_proof(proof_bundle, &config)?;
 //! println!("Verified {} execution headers", results.evm.execution_header.len());
 //! println!("Verified {} OP Stack headers", results.op_stack.header.len());
 //! # Ok(())
@@ -26,6 +39,8 @@ extern crate alloc;
 
 // Keep batch module private
 mod batch;
+mod config;
+mod report;
 
 /// Bankai block proof verification
 ///
@@ -34,6 +49,7 @@ mod batch;
 ///
 /// - [`bankai::stwo`] - Verify STWO zero-knowledge proofs to extract trusted Bankai blocks with MMR roots
 /// - [`bankai::mmr`] - Verify MMR inclusion proofs against trusted MMR roots
+/// - [`bankai::history`] - Verify historical Bankai blocks against a trusted reference block
 pub mod bankai;
 
 /// EVM-specific verification components
@@ -49,6 +65,23 @@ pub mod evm;
 /// Verifies a proof bundle returned by `bankai-sdk` and returns trusted results.
 pub use crate::batch::verify_batch_proof;
 
+/// Verifies a proof bundle item by item and reports the outcome of every proof.
+pub use crate::batch::verify_batch_report;
+
+/// Verifies bundles against already-verified Bankai blocks, skipping repeated STWO checks.
+pub use crate::batch::{verify_bundle_with_trusted_block, verify_many};
+
+/// A Bankai block whose STWO proof has been verified.
+pub use crate::bankai::stwo::TrustedBankaiBlock;
+
+/// Per-item verification report types.
+pub use crate::report::{
+    BatchReport, EvmReport, ItemResult, OpStackReport, ProofCategory, ReportFailure,
+};
+
+/// Trust policy for Bankai program hashes and versions.
+pub use crate::config::VerifierConfig;
+
 // Re-export common types from bankai_types for convenience
 pub use bankai_types::results::{evm::EvmResults, BatchResults};
 
@@ -104,6 +137,33 @@ pub enum VerifyError {
 
     /// Failed to decode RLP-encoded data
     InvalidRlpDecode,
+
+    /// A transaction hash doesn't match the verified transaction payload
+    InvalidTxHash,
+
+    /// Contract bytecode doesn't hash to the verified account's code hash
+    InvalidCodeHash,
+
+    /// Referenced beacon header not found in the verified headers list
+    InvalidBeaconHeaderProof,
+
+    /// An SSZ Merkle proof failed verification against a beacon root
+    InvalidSszProof,
+
+    /// The Bankai block was produced by a program hash or version not accepted by the config
+    UntrustedProgram,
+
+    /// A header is above the finalized or justified height required by the config
+    NonFinalHeader,
+
+    /// Headers of a range proof are not ordered or not linked by parent hash
+    BrokenHeaderChain,
+
+    /// A proof targets another execution chain or beacon network than the config
+    NetworkMismatch,
+
+    /// The proof was not verified because another proof failed first in fail-fast mode
+    Skipped,
 }
 
 impl core::fmt::Display for VerifyError {
@@ -123,6 +183,15 @@ impl core::fmt::Display for VerifyError {
             Self::InvalidExecutionHeaderProof => write!(f, "Invalid execution header proof"),
             Self::InvalidStateRoot => write!(f, "Invalid state root"),
             Self::InvalidRlpDecode => write!(f, "Invalid RLP decode"),
+            Self::InvalidTxHash => write!(f, "Invalid transaction hash"),
+            Self::InvalidCodeHash => write!(f, "Invalid code hash"),
+            Self::InvalidBeaconHeaderProof => write!(f, "Invalid beacon header proof"),
+            Self::InvalidSszProof => write!(f, "Invalid SSZ proof"),
+            Self::UntrustedProgram => write!(f, "Untrusted Bankai program"),
+            Self::NonFinalHeader => write!(f, "Header is not final"),
+            Self::BrokenHeaderChain => write!(f, "Broken header chain"),
+            Self::NetworkMismatch => write!(f, "Proof is for another network"),
+            Self::Skipped => write!(f, "Proof skipped after an earlier failure"),
         }
     }
 }
@@ -0,0 +1,414 @@
+extern crate alloc;
+use alloc::vec::Vec;
+
+use bankai_types::results::evm::beacon::BeaconHeader;
+use bankai_types::results::evm::execution::ExecutionHeader;
+use bankai_types::results::evm::op_stack::{
+    OpStackResults, VerifiedOpReceipt, VerifiedOpTransaction,
+};
+use bankai_types::results::evm::{
+    EvmResults, VerifiedAccount, VerifiedCode, VerifiedExecutionPayload, VerifiedHeaderRange,
+    VerifiedLog, VerifiedReceipt, VerifiedStorageSlots, VerifiedTransaction, VerifiedValidator,
+};
+use bankai_types::results::BatchResults;
+
+use crate::VerifyError;
+
+/// Outcome of verifying a single proof.
+pub type ItemResult<T> = Result<T, VerifyError>;
+
+/// Per-item verification results for the Ethereum proofs of a bundle.
+///
+/// Each list is aligned with the matching proof list of
+/// [`bankai_types::inputs::evm::EvmProofs`]: entry `i` is the outcome of proof `i`.
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Default)]
+pub struct EvmReport {
+    pub execution_header: Vec<ItemResult<ExecutionHeader>>,
+    /// Headers of each execution header batch proof.
+    pub execution_header_batch: Vec<ItemResult<Vec<ExecutionHeader>>>,
+    pub beacon_header: Vec<ItemResult<BeaconHeader>>,
+    /// Headers of each beacon header batch proof.
+    pub beacon_header_batch: Vec<ItemResult<Vec<BeaconHeader>>>,
+    pub execution_header_range: Vec<ItemResult<VerifiedHeaderRange<ExecutionHeader>>>,
+    pub beacon_header_range: Vec<ItemResult<VerifiedHeaderRange<BeaconHeader>>>,
+    pub execution_payload: Vec<ItemResult<VerifiedExecutionPayload>>,
+    pub validator: Vec<ItemResult<VerifiedValidator>>,
+    pub account: Vec<ItemResult<VerifiedAccount>>,
+    pub storage_slot: Vec<ItemResult<VerifiedStorageSlots>>,
+    pub tx: Vec<ItemResult<VerifiedTransaction>>,
+    pub receipt: Vec<ItemResult<VerifiedReceipt>>,
+    pub code: Vec<ItemResult<VerifiedCode>>,
+    /// Matching logs of each log proof.
+    pub log: Vec<ItemResult<Vec<VerifiedLog>>>,
+}
+
+/// Per-item verification results for the OP Stack proofs of a bundle.
+///
+/// Each list is aligned with the matching proof list of
+/// [`bankai_types::inputs::evm::op_stack::OpStackProofs`].
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Default)]
+pub struct OpStackReport {
+    pub header: Vec<ItemResult<ExecutionHeader>>,
+    /// Headers of each header batch proof.
+    pub header_batch: Vec<ItemResult<Vec<ExecutionHeader>>>,
+    pub header_range: Vec<ItemResult<VerifiedHeaderRange<ExecutionHeader>>>,
+    pub account: Vec<ItemResult<VerifiedAccount>>,
+    pub storage_slot: Vec<ItemResult<VerifiedStorageSlots>>,
+    pub tx: Vec<ItemResult<VerifiedOpTransaction>>,
+    pub receipt: Vec<ItemResult<VerifiedOpReceipt>>,
+    pub code: Vec<ItemResult<VerifiedCode>>,
+    /// Matching logs of each log proof.
+    pub log: Vec<ItemResult<Vec<VerifiedLog>>>,
+}
+
+/// Per-item verification report returned by [`crate::verify_batch_report`].
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Default)]
+pub struct BatchReport {
+    pub evm: EvmReport,
+    pub op_stack: OpStackReport,
+}
+
+/// Proof list a report entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProofCategory {
+    ExecutionHeader,
+    ExecutionHeaderBatch,
+    BeaconHeader,
+    BeaconHeaderBatch,
+    ExecutionHeaderRange,
+    BeaconHeaderRange,
+    ExecutionPayload,
+    BeaconValidator,
+    Account,
+    StorageSlot,
+    Tx,
+    Receipt,
+    Code,
+    Log,
+    OpStackHeader,
+    OpStackHeaderBatch,
+    OpStackHeaderRange,
+    OpStackAccount,
+    OpStackStorageSlot,
+    OpStackTx,
+    OpStackReceipt,
+    OpStackCode,
+    OpStackLog,
+}
+
+/// A proof that failed verification, identified by its list and position in the bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportFailure {
+    pub category: ProofCategory,
+    pub index: usize,
+    pub error: VerifyError,
+}
+
+impl BatchReport {
+    /// Returns every failed proof, in verification order.
+    pub fn failures(&self) -> Vec<ReportFailure> {
+        let mut failures = Vec::new();
+        let evm = &self.evm;
+        let op = &self.op_stack;
+
+        collect_failures(
+            &mut failures,
+            ProofCategory::ExecutionHeader,
+            &evm.execution_header,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::ExecutionHeaderBatch,
+            &evm.execution_header_batch,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::BeaconHeader,
+            &evm.beacon_header,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::BeaconHeaderBatch,
+            &evm.beacon_header_batch,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::ExecutionHeaderRange,
+            &evm.execution_header_range,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::BeaconHeaderRange,
+            &evm.beacon_header_range,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::ExecutionPayload,
+            &evm.execution_payload,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::BeaconValidator,
+            &evm.validator,
+        );
+        collect_failures(&mut failures, ProofCategory::Account, &evm.account);
+        collect_failures(&mut failures, ProofCategory::StorageSlot, &evm.storage_slot);
+        collect_failures(&mut failures, ProofCategory::Tx, &evm.tx);
+        collect_failures(&mut failures, ProofCategory::Receipt, &evm.receipt);
+        collect_failures(&mut failures, ProofCategory::Code, &evm.code);
+        collect_failures(&mut failures, ProofCategory::Log, &evm.log);
+        collect_failures(&mut failures, ProofCategory::OpStackHeader, &op.header);
+        collect_failures(
+            &mut failures,
+            ProofCategory::OpStackHeaderBatch,
+            &op.header_batch,
+        );
+        collect_failures(
+            &mut failures,
+            ProofCategory::OpStackHeaderRange,
+            &op.header_range,
+        );
+        collect_failures(&mut failures, ProofCategory::OpStackAccount, &op.account);
+        collect_failures(
+            &mut failures,
+            ProofCategory::OpStackStorageSlot,
+            &op.storage_slot,
+        );
+        collect_failures(&mut failures, ProofCategory::OpStackTx, &op.tx);
+        collect_failures(&mut failures, ProofCategory::OpStackReceipt, &op.receipt);
+        collect_failures(&mut failures, ProofCategory::OpStackCode, &op.code);
+        collect_failures(&mut failures, ProofCategory::OpStackLog, &op.log);
+
+        failures
+    }
+
+    /// Returns `true` if every proof verified.
+    pub fn is_complete(&self) -> bool {
+        self.failures().is_empty()
+    }
+
+    /// Returns the verified results, or the first failure in verification order.
+    ///
+    /// This matches the behavior of [`crate::verify_batch_proof`].
+    pub fn into_results(self) -> Result<BatchResults, VerifyError> {
+        // Skipped proofs can come before the failure that skipped them
+        if let Some(failure) = self
+            .failures()
+            .into_iter()
+            .find(|failure| failure.error != VerifyError::Skipped)
+        {
+            return Err(failure.error);
+        }
+
+        let evm = self.evm;
+        let op = self.op_stack;
+
+        // Fields are evaluated in verification order so the first failure wins.
+        let mut execution_header = collect_all(evm.execution_header)?;
+        execution_header.extend(
+            collect_all(evm.execution_header_batch)?
+                .into_iter()
+                .flatten(),
+        );
+        let mut beacon_header = collect_all(evm.beacon_header)?;
+        beacon_header.extend(collect_all(evm.beacon_header_batch)?.into_iter().flatten());
+        let execution_header_range = collect_all(evm.execution_header_range)?;
+        let beacon_header_range = collect_all(evm.beacon_header_range)?;
+        let execution_payload = collect_all(evm.execution_payload)?;
+        let validator = collect_all(evm.validator)?;
+        let account = collect_all(evm.account)?;
+        let storage_slot = collect_all(evm.storage_slot)?;
+        let tx = collect_all(evm.tx)?;
+        let receipt = collect_all(evm.receipt)?;
+        let code = collect_all(evm.code)?;
+        let log = collect_all(evm.log)?.into_iter().flatten().collect();
+
+        // Batch headers follow the individually proven headers.
+        let mut op_header = collect_all(op.header)?;
+        op_header.extend(collect_all(op.header_batch)?.into_iter().flatten());
+        let op_header_range = collect_all(op.header_range)?;
+
+        Ok(BatchResults {
+            evm: EvmResults {
+                execution_header,
+                beacon_header,
+                execution_header_range,
+                beacon_header_range,
+                account,
+                tx,
+                receipt,
+                storage_slot,
+                code,
+                execution_payload,
+                validator,
+                log,
+            },
+            op_stack: OpStackResults {
+                header: op_header,
+                header_range: op_header_range,
+                account: collect_all(op.account)?,
+                storage_slot: collect_all(op.storage_slot)?,
+                tx: collect_all(op.tx)?,
+                receipt: collect_all(op.receipt)?,
+                code: collect_all(op.code)?,
+                log: collect_all(op.log)?.into_iter().flatten().collect(),
+            },
+        })
+    }
+
+    /// Returns the results of the proofs that verified, dropping failures.
+    ///
+    /// Use [`BatchReport::failures`] first to learn which proofs were dropped.
+    pub fn into_verified(self) -> BatchResults {
+        let evm = self.evm;
+        let op = self.op_stack;
+
+        let mut execution_header = collect_ok(evm.execution_header);
+        execution_header.extend(collect_ok(evm.execution_header_batch).into_iter().flatten());
+        let mut beacon_header = collect_ok(evm.beacon_header);
+        beacon_header.extend(collect_ok(evm.beacon_header_batch).into_iter().flatten());
+        let mut op_header = collect_ok(op.header);
+        op_header.extend(collect_ok(op.header_batch).into_iter().flatten());
+
+        BatchResults {
+            evm: EvmResults {
+                execution_header,
+                beacon_header,
+                execution_header_range: collect_ok(evm.execution_header_range),
+                beacon_header_range: collect_ok(evm.beacon_header_range),
+                account: collect_ok(evm.account),
+                tx: collect_ok(evm.tx),
+                receipt: collect_ok(evm.receipt),
+                storage_slot: collect_ok(evm.storage_slot),
+                code: collect_ok(evm.code),
+                execution_payload: collect_ok(evm.execution_payload),
+                validator: collect_ok(evm.validator),
+                log: collect_ok(evm.log).into_iter().flatten().collect(),
+            },
+            op_stack: OpStackResults {
+                header: op_header,
+                header_range: collect_ok(op.header_range),
+                account: collect_ok(op.account),
+                storage_slot: collect_ok(op.storage_slot),
+                tx: collect_ok(op.tx),
+                receipt: collect_ok(op.receipt),
+                code: collect_ok(op.code),
+                log: collect_ok(op.log).into_iter().flatten().collect(),
+            },
+        }
+    }
+}
+
+fn collect_failures<T>(
+    failures: &mut Vec<ReportFailure>,
+    category: ProofCategory,
+    items: &[ItemResult<T>],
+) {
+    failures.extend(items.iter().enumerate().filter_map(|(index, item)| {
+        item.as_ref().err().map(|error| ReportFailure {
+            category,
+            index,
+            error: *error,
+        })
+    }));
+}
+
+fn collect_all<T>(items: Vec<ItemResult<T>>) -> Result<Vec<T>, VerifyError> {
+    items.into_iter().collect()
+}
+
+fn collect_ok<T>(items: Vec<ItemResult<T>>) -> Vec<T> {
+    items.into_iter().filter_map(Result::ok).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn report() -> BatchReport {
+        let mut report = BatchReport::default();
+        report.evm.execution_header = vec![
+            Ok(ExecutionHeader::default()),
+            Err(VerifyError::InvalidMmrProof),
+        ];
+        report.evm.execution_header_batch = vec![Ok(vec![ExecutionHeader::default(); 2])];
+        report.evm.account = vec![Err(VerifyError::InvalidExecutionHeaderProof)];
+        report.op_stack.header = vec![
+            Err(VerifyError::InvalidHeaderHash),
+            Ok(ExecutionHeader::default()),
+        ];
+        report
+    }
+
+    #[test]
+    fn lists_failures_by_category_and_index() {
+        let report = report();
+
+        assert!(!report.is_complete());
+        assert_eq!(
+            report.failures(),
+            vec![
+                ReportFailure {
+                    category: ProofCategory::ExecutionHeader,
+                    index: 1,
+                    error: VerifyError::InvalidMmrProof,
+                },
+                ReportFailure {
+                    category: ProofCategory::Account,
+                    index: 0,
+                    error: VerifyError::InvalidExecutionHeaderProof,
+                },
+                ReportFailure {
+                    category: ProofCategory::OpStackHeader,
+                    index: 0,
+                    error: VerifyError::InvalidHeaderHash,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn into_results_returns_first_failure() {
+        assert_eq!(
+            report().into_results().unwrap_err(),
+            VerifyError::InvalidMmrProof
+        );
+    }
+
+    #[test]
+    fn into_results_returns_failure_before_skipped_proofs() {
+        let mut report = BatchReport::default();
+        report.evm.execution_header = vec![Err(VerifyError::Skipped)];
+        report.evm.account = vec![
+            Err(VerifyError::Skipped),
+            Err(VerifyError::InvalidAccountProof),
+        ];
+
+        assert_eq!(
+            report.into_results().unwrap_err(),
+            VerifyError::InvalidAccountProof
+        );
+    }
+
+    #[test]
+    fn into_verified_keeps_successful_items() {
+        let results = report().into_verified();
+
+        assert_eq!(results.evm.execution_header.len(), 3);
+        assert!(results.evm.account.is_empty());
+        assert_eq!(results.op_stack.header.len(), 1);
+    }
+
+    #[test]
+    fn empty_report_is_complete() {
+        let report = BatchReport::default();
+
+        assert!(report.is_complete());
+        assert!(report.into_results().is_ok());
+    }
+}
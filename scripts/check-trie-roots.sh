@@ -6,4 +6,4 @@ if [[ $# -lt 3 || $# -gt 4 ]]; then
   exit 1
 fi
 
-cargo run -p bankai-core --bin check-trie-roots -- "$@"
+cargo run -p bankai-core --features bin --bin check-trie-roots -- "$@"